@@ -0,0 +1,534 @@
+//! Betweenness centrality of nodes and edges.
+
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+
+use fixedbitset::FixedBitSet;
+
+use super::Measure;
+use crate::{
+    scored::MinScored,
+    visit::{
+        EdgeIndexable, EdgeRef, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+    },
+};
+
+/// Shortest paths from a single source, as explored by Brandes' algorithm.
+///
+/// All vectors are indexed by [`NodeIndexable::to_index`]. Only the entries of nodes in `order`
+/// are in use, which allows the state to be reset in time proportional to the explored part of
+/// the graph.
+struct ShortestPaths<E, K> {
+    /// Distance from the source.
+    dist: Vec<Option<K>>,
+    /// Number of shortest paths from the source.
+    sigma: Vec<f64>,
+    /// Dependency of the source on each node.
+    delta: Vec<f64>,
+    /// Predecessors of each node on shortest paths, with the edge leading to the node.
+    preds: Vec<Vec<(usize, E)>>,
+    /// Reached nodes, in non-decreasing distance from the source.
+    order: Vec<usize>,
+    /// Queue of the breadth-first search.
+    queue: VecDeque<usize>,
+}
+
+impl<E, K> ShortestPaths<E, K>
+where
+    E: Copy,
+    K: Copy,
+{
+    fn new(node_bound: usize) -> Self {
+        ShortestPaths {
+            dist: vec![None; node_bound],
+            sigma: vec![0.; node_bound],
+            delta: vec![0.; node_bound],
+            preds: (0..node_bound).map(|_| Vec::new()).collect(),
+            order: Vec::new(),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Accumulate the dependencies of the last source, in the order opposite to their discovery,
+    /// and reset the state for the next source.
+    fn accumulate<F>(&mut self, source: usize, node_scores: &mut [f64], mut on_edge: F)
+    where
+        F: FnMut(E, f64),
+    {
+        for &w in self.order.iter().rev() {
+            let coefficient = (1. + self.delta[w]) / self.sigma[w];
+            for &(v, edge) in &self.preds[w] {
+                let dependency = self.sigma[v] * coefficient;
+                self.delta[v] += dependency;
+                on_edge(edge, dependency);
+            }
+            if w != source {
+                node_scores[w] += self.delta[w];
+            }
+        }
+
+        for &v in &self.order {
+            self.dist[v] = None;
+            self.sigma[v] = 0.;
+            self.delta[v] = 0.;
+            self.preds[v].clear();
+        }
+        self.order.clear();
+    }
+}
+
+impl<E> ShortestPaths<E, usize>
+where
+    E: EdgeRef,
+{
+    /// Breadth-first search from `source`, counting unweighted shortest paths.
+    fn bfs<G>(&mut self, graph: G, source: usize)
+    where
+        G: IntoEdges<EdgeRef = E, NodeId = E::NodeId> + NodeIndexable,
+    {
+        self.dist[source] = Some(0);
+        self.sigma[source] = 1.;
+        self.queue.push_back(source);
+
+        while let Some(v) = self.queue.pop_front() {
+            self.order.push(v);
+            let next_dist = self.dist[v].unwrap() + 1;
+            for edge in graph.edges(graph.from_index(v)) {
+                let w = graph.to_index(edge.target());
+                if self.dist[w].is_none() {
+                    self.dist[w] = Some(next_dist);
+                    self.queue.push_back(w);
+                }
+                if self.dist[w] == Some(next_dist) {
+                    self.sigma[w] += self.sigma[v];
+                    self.preds[w].push((v, edge));
+                }
+            }
+        }
+    }
+}
+
+impl<E, K> ShortestPaths<E, K>
+where
+    E: EdgeRef,
+    K: Measure + Copy,
+{
+    /// Dijkstra's algorithm from `source`, counting weighted shortest paths.
+    fn dijkstra<G, F>(&mut self, graph: G, source: usize, edge_cost: &mut F)
+    where
+        G: IntoEdges<EdgeRef = E, NodeId = E::NodeId> + NodeIndexable,
+        F: FnMut(E) -> K,
+    {
+        let mut finished = FixedBitSet::with_capacity(graph.node_bound());
+        let mut visit_next = BinaryHeap::new();
+        self.dist[source] = Some(K::default());
+        self.sigma[source] = 1.;
+        visit_next.push(MinScored(K::default(), source));
+
+        while let Some(MinScored(dist, v)) = visit_next.pop() {
+            if finished.put(v) {
+                continue;
+            }
+            self.order.push(v);
+            for edge in graph.edges(graph.from_index(v)) {
+                let w = graph.to_index(edge.target());
+                if finished.contains(w) {
+                    continue;
+                }
+                let next_dist = dist + edge_cost(edge);
+                match self.dist[w] {
+                    Some(w_dist) if w_dist < next_dist => {}
+                    Some(w_dist) if w_dist == next_dist => {
+                        self.sigma[w] += self.sigma[v];
+                        self.preds[w].push((v, edge));
+                    }
+                    _ => {
+                        self.dist[w] = Some(next_dist);
+                        self.sigma[w] = self.sigma[v];
+                        self.preds[w].clear();
+                        self.preds[w].push((v, edge));
+                        visit_next.push(MinScored(next_dist, w));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Indices of the sources of the shortest paths: `sources` if given, otherwise all nodes.
+fn source_indices<G>(graph: G, sources: Option<&[G::NodeId]>) -> Vec<usize>
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+{
+    match sources {
+        Some(sources) => sources.iter().map(|&s| graph.to_index(s)).collect(),
+        None => graph
+            .node_identifiers()
+            .map(|s| graph.to_index(s))
+            .collect(),
+    }
+}
+
+/// Rescale raw betweenness scores, following the conventions of NetworkX.
+///
+/// `pairs` is the number of pairs of nodes used for normalization.
+fn rescale<G>(graph: G, scores: &mut [f64], source_count: usize, normalized: bool, pairs: usize)
+where
+    G: GraphProp + NodeCount,
+{
+    let node_count = graph.node_count();
+    let mut scale = if normalized {
+        if pairs > 0 { 1. / pairs as f64 } else { 1. }
+    } else if graph.is_directed() {
+        1.
+    } else {
+        // Every undirected path is found from both of its ends.
+        0.5
+    };
+    if source_count > 0 && source_count < node_count {
+        scale *= node_count as f64 / source_count as f64;
+    }
+    for score in scores {
+        *score *= scale;
+    }
+}
+
+fn rescale_nodes<G>(graph: G, scores: &mut [f64], source_count: usize, normalized: bool)
+where
+    G: GraphProp + NodeCount,
+{
+    let n = graph.node_count();
+    let pairs = if n > 2 { (n - 1) * (n - 2) } else { 0 };
+    rescale(graph, scores, source_count, normalized, pairs);
+}
+
+fn rescale_edges<G>(graph: G, scores: &mut [f64], source_count: usize, normalized: bool)
+where
+    G: GraphProp + NodeCount,
+{
+    let n = graph.node_count();
+    let pairs = if n > 1 { n * (n - 1) } else { 0 };
+    rescale(graph, scores, source_count, normalized, pairs);
+}
+
+/// Betweenness centrality of every node, using [Brandes' algorithm][1].
+///
+/// The betweenness centrality of a node `v` is the sum, over all pairs of other nodes `s` and
+/// `t`, of the fraction of shortest paths from `s` to `t` that pass through `v`. Every edge has
+/// length one; see [`weighted_betweenness_centrality`] for weighted graphs.
+///
+/// If `normalized` is `true`, the scores are divided by the number of pairs of nodes other than
+/// `v`, that is `(n - 1)(n - 2)` for directed graphs and `(n - 1)(n - 2) / 2` for undirected
+/// graphs, so that they lie between 0 and 1.
+///
+/// If `sources` is not `None`, only shortest paths starting from the given nodes are taken into
+/// account, and the scores are extrapolated to all nodes. Sampling sources uniformly at random
+/// approximates the exact scores at a fraction of the cost.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `normalized`: whether to normalize the scores.
+/// * `sources`: optional subset of nodes to use as sources of shortest paths.
+///
+/// # Returns
+/// * `Vec<f64>`: the betweenness centrality of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|S||E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **|S|** the number
+/// of sources, which is **|V|** by default.
+///
+/// [1]: https://doi.org/10.1080/0022250X.2001.9990249
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::betweenness_centrality, graph::UnGraph};
+///
+/// // A path graph: 0 - 1 - 2 - 3
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+///
+/// let scores = betweenness_centrality(&graph, false, None);
+/// assert_eq!(scores, vec![0., 2., 2., 0.]);
+///
+/// let scores = betweenness_centrality(&graph, true, None);
+/// assert_eq!(scores, vec![0., 2. / 3., 2. / 3., 0.]);
+/// ```
+pub fn betweenness_centrality<G>(
+    graph: G,
+    normalized: bool,
+    sources: Option<&[G::NodeId]>,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+{
+    let sources = source_indices(graph, sources);
+    let mut scores = vec![0.; graph.node_bound()];
+    let mut paths = ShortestPaths::new(graph.node_bound());
+    for &s in &sources {
+        paths.bfs(graph, s);
+        paths.accumulate(s, &mut scores, |_, _| {});
+    }
+    rescale_nodes(graph, &mut scores, sources.len(), normalized);
+    scores
+}
+
+/// Betweenness centrality of every node of a weighted graph, using [Brandes' algorithm][1].
+///
+/// This is the same as [`betweenness_centrality`], except that the length of a path is the sum
+/// of the costs of its edges, as returned by `edge_cost`. Edge costs must be positive.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_cost`: closure that returns the cost of a particular edge.
+/// * `normalized`: whether to normalize the scores.
+/// * `sources`: optional subset of nodes to use as sources of shortest paths.
+///
+/// # Returns
+/// * `Vec<f64>`: the betweenness centrality of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|S|(|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **|S|** the number
+/// of sources, which is **|V|** by default.
+///
+/// [1]: https://doi.org/10.1080/0022250X.2001.9990249
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::weighted_betweenness_centrality, graph::UnGraph};
+///
+/// // A triangle whose direct edge between 0 and 2 is longer than the detour through 1.
+/// let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 3)]);
+///
+/// let scores = weighted_betweenness_centrality(&graph, |e| *e.weight(), false, None);
+/// assert_eq!(scores, vec![0., 1., 0.]);
+/// ```
+pub fn weighted_betweenness_centrality<G, F, K>(
+    graph: G,
+    mut edge_cost: F,
+    normalized: bool,
+    sources: Option<&[G::NodeId]>,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let sources = source_indices(graph, sources);
+    let mut scores = vec![0.; graph.node_bound()];
+    let mut paths = ShortestPaths::new(graph.node_bound());
+    for &s in &sources {
+        paths.dijkstra(graph, s, &mut edge_cost);
+        paths.accumulate(s, &mut scores, |_, _| {});
+    }
+    rescale_nodes(graph, &mut scores, sources.len(), normalized);
+    scores
+}
+
+/// Betweenness centrality of every edge, using [Brandes' algorithm][1].
+///
+/// The betweenness centrality of an edge `e` is the sum, over all pairs of nodes `s` and `t`, of
+/// the fraction of shortest paths from `s` to `t` that use `e`. Every edge has length one; see
+/// [`weighted_edge_betweenness_centrality`] for weighted graphs.
+///
+/// If `normalized` is `true`, the scores are divided by the number of pairs of nodes, that is
+/// `n(n - 1)` for directed graphs and `n(n - 1) / 2` for undirected graphs.
+///
+/// If `sources` is not `None`, only shortest paths starting from the given nodes are taken into
+/// account, and the scores are extrapolated to all nodes.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `normalized`: whether to normalize the scores.
+/// * `sources`: optional subset of nodes to use as sources of shortest paths.
+///
+/// # Returns
+/// * `Vec<f64>`: the betweenness centrality of every edge, indexed by [`EdgeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|S||E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **|S|** the number
+/// of sources, which is **|V|** by default.
+///
+/// [1]: https://doi.org/10.1016/j.socnet.2007.11.001
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::edge_betweenness_centrality, graph::UnGraph};
+///
+/// // Two triangles joined by a bridge between 2 and 3.
+/// let graph =
+///     UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)]);
+///
+/// let scores = edge_betweenness_centrality(&graph, false, None);
+/// // All 9 paths between the two triangles use the bridge.
+/// assert_eq!(scores[3], 9.);
+/// ```
+pub fn edge_betweenness_centrality<G>(
+    graph: G,
+    normalized: bool,
+    sources: Option<&[G::NodeId]>,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + NodeCount + GraphProp,
+{
+    let sources = source_indices(graph, sources);
+    let mut node_scores = vec![0.; graph.node_bound()];
+    let mut scores = vec![0.; graph.edge_bound()];
+    let mut paths = ShortestPaths::new(graph.node_bound());
+    for &s in &sources {
+        paths.bfs(graph, s);
+        paths.accumulate(s, &mut node_scores, |edge, dependency| {
+            scores[EdgeIndexable::to_index(&graph, edge.id())] += dependency;
+        });
+    }
+    rescale_edges(graph, &mut scores, sources.len(), normalized);
+    scores
+}
+
+/// Betweenness centrality of every edge of a weighted graph, using [Brandes' algorithm][1].
+///
+/// This is the same as [`edge_betweenness_centrality`], except that the length of a path is the
+/// sum of the costs of its edges, as returned by `edge_cost`. Edge costs must be positive.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_cost`: closure that returns the cost of a particular edge.
+/// * `normalized`: whether to normalize the scores.
+/// * `sources`: optional subset of nodes to use as sources of shortest paths.
+///
+/// # Returns
+/// * `Vec<f64>`: the betweenness centrality of every edge, indexed by [`EdgeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|S|(|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **|S|** the number
+/// of sources, which is **|V|** by default.
+///
+/// [1]: https://doi.org/10.1016/j.socnet.2007.11.001
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::weighted_edge_betweenness_centrality, graph::UnGraph};
+///
+/// let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 3)]);
+///
+/// let scores = weighted_edge_betweenness_centrality(&graph, |e| *e.weight(), false, None);
+/// assert_eq!(scores, vec![2., 2., 0.]);
+/// ```
+pub fn weighted_edge_betweenness_centrality<G, F, K>(
+    graph: G,
+    mut edge_cost: F,
+    normalized: bool,
+    sources: Option<&[G::NodeId]>,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + NodeCount + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let sources = source_indices(graph, sources);
+    let mut node_scores = vec![0.; graph.node_bound()];
+    let mut scores = vec![0.; graph.edge_bound()];
+    let mut paths = ShortestPaths::new(graph.node_bound());
+    for &s in &sources {
+        paths.dijkstra(graph, s, &mut edge_cost);
+        paths.accumulate(s, &mut node_scores, |edge, dependency| {
+            scores[EdgeIndexable::to_index(&graph, edge.id())] += dependency;
+        });
+    }
+    rescale_edges(graph, &mut scores, sources.len(), normalized);
+    scores
+}
+
+/// Sum the scores of each chunk of sources, computed in parallel.
+#[cfg(feature = "rayon")]
+fn parallel_sum_scores<G, K, F>(graph: G, sources: &[usize], single_source: F) -> Vec<f64>
+where
+    G: IntoEdges + NodeIndexable + Sync,
+    G::EdgeRef: Send,
+    K: Copy + Send,
+    F: Fn(&mut ShortestPaths<G::EdgeRef, K>, usize) + Sync,
+{
+    use rayon::prelude::*;
+
+    let node_bound = graph.node_bound();
+    sources
+        .par_iter()
+        .fold(
+            || (ShortestPaths::new(node_bound), vec![0.; node_bound]),
+            |(mut paths, mut scores), &s| {
+                single_source(&mut paths, s);
+                paths.accumulate(s, &mut scores, |_, _| {});
+                (paths, scores)
+            },
+        )
+        .map(|(_, scores)| scores)
+        .reduce(
+            || vec![0.; node_bound],
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a
+            },
+        )
+}
+
+/// Parallel betweenness centrality of every node.
+///
+/// The shortest paths from different sources are explored in parallel.
+///
+/// See [`betweenness_centrality`].
+#[cfg(feature = "rayon")]
+pub fn parallel_betweenness_centrality<G>(
+    graph: G,
+    normalized: bool,
+    sources: Option<&[G::NodeId]>,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp + Sync,
+    G::EdgeRef: Send,
+{
+    let sources = source_indices(graph, sources);
+    let mut scores = parallel_sum_scores(graph, &sources, |paths, s| {
+        paths.bfs(graph, s);
+    });
+    rescale_nodes(graph, &mut scores, sources.len(), normalized);
+    scores
+}
+
+/// Parallel betweenness centrality of every node of a weighted graph.
+///
+/// The shortest paths from different sources are explored in parallel.
+///
+/// See [`weighted_betweenness_centrality`].
+#[cfg(feature = "rayon")]
+pub fn parallel_weighted_betweenness_centrality<G, F, K>(
+    graph: G,
+    edge_cost: F,
+    normalized: bool,
+    sources: Option<&[G::NodeId]>,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp + Sync,
+    G::EdgeRef: Send,
+    F: Fn(G::EdgeRef) -> K + Sync,
+    K: Measure + Copy + Send,
+{
+    let sources = source_indices(graph, sources);
+    let mut scores = parallel_sum_scores(graph, &sources, |paths, s| {
+        paths.dijkstra(graph, s, &mut &edge_cost);
+    });
+    rescale_nodes(graph, &mut scores, sources.len(), normalized);
+    scores
+}
@@ -0,0 +1,136 @@
+//! Bipartiteness check of a whole graph, with a certificate either way.
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// A two-coloring of the nodes of a bipartite graph: every edge joins a node of `left` to a node
+/// of `right`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bipartition<N> {
+    /// The nodes of the first color, including the first node of every connected component.
+    pub left: Vec<N>,
+    /// The nodes of the second color.
+    pub right: Vec<N>,
+}
+
+/// An algorithm error: a cycle of odd length was found in the graph, which proves that it is not
+/// bipartite.
+///
+/// The nodes are listed in the order of the cycle: every node is adjacent to the next one, and
+/// the last node is adjacent to the first. A self loop is a cycle of a single node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OddCycle<N>(pub Vec<N>);
+
+/// Split the nodes of a graph into two sides such that every edge joins both sides, or find an
+/// odd cycle if that is not possible.
+///
+/// Unlike [`is_bipartite_undirected`](super::is_bipartite_undirected), every connected component
+/// is checked, and the result is a certificate: the two sides, which can be fed to algorithms on
+/// bipartite graphs, or an odd cycle. Each component is two-colored by a breadth-first search;
+/// an edge between two nodes of the same color closes an odd cycle through their closest common
+/// ancestor in the search tree.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+///
+/// # Returns
+/// * `Ok(Bipartition)`: the two sides of the graph, each in the order of
+///   [`IntoNodeIdentifiers::node_identifiers`].
+/// * `Err(OddCycle)`: the nodes of a cycle of odd length.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::{bipartite::OddCycle, bipartition},
+///     graph::{NodeIndex, UnGraph},
+/// };
+///
+/// // Two components: a square and a single edge.
+/// let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (4, 5)]);
+/// let sides = bipartition(&graph).unwrap();
+/// let left: Vec<_> = sides.left.iter().map(|n| n.index()).collect();
+/// let right: Vec<_> = sides.right.iter().map(|n| n.index()).collect();
+/// assert_eq!(left, vec![0, 2, 4]);
+/// assert_eq!(right, vec![1, 3, 5]);
+///
+/// // A chord of the square makes two triangles.
+/// graph.add_edge(NodeIndex::new(0), NodeIndex::new(2), ());
+/// let OddCycle(cycle) = bipartition(&graph).unwrap_err();
+/// assert_eq!(cycle.len(), 3);
+/// ```
+pub fn bipartition<G>(graph: G) -> Result<Bipartition<G::NodeId>, OddCycle<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut neighbors = vec![Vec::new(); graph.node_bound()];
+    for a in graph.node_identifiers() {
+        let ia = graph.to_index(a);
+        for b in graph.neighbors(a) {
+            let ib = graph.to_index(b);
+            if ia == ib {
+                return Err(OddCycle(vec![a]));
+            }
+            neighbors[ia].push(ib);
+            neighbors[ib].push(ia);
+        }
+    }
+
+    let mut depths = vec![usize::MAX; graph.node_bound()];
+    let mut parents = vec![usize::MAX; graph.node_bound()];
+    let mut queue = VecDeque::new();
+    for start in graph.node_identifiers() {
+        let start = graph.to_index(start);
+        if depths[start] != usize::MAX {
+            continue;
+        }
+        depths[start] = 0;
+        queue.push_back(start);
+        while let Some(a) = queue.pop_front() {
+            for &b in &neighbors[a] {
+                if depths[b] == usize::MAX {
+                    depths[b] = depths[a] + 1;
+                    parents[b] = a;
+                    queue.push_back(b);
+                } else if depths[b] == depths[a] {
+                    let cycle = odd_cycle(&parents, a, b);
+                    return Err(OddCycle(
+                        cycle.into_iter().map(|i| graph.from_index(i)).collect(),
+                    ));
+                }
+            }
+        }
+    }
+
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    for node in graph.node_identifiers() {
+        if depths[graph.to_index(node)] % 2 == 0 {
+            left.push(node);
+        } else {
+            right.push(node);
+        }
+    }
+    Ok(Bipartition { left, right })
+}
+
+/// The cycle closed by the edge between `a` and `b`, two nodes at the same depth of a
+/// breadth-first search tree.
+fn odd_cycle(parents: &[usize], mut a: usize, mut b: usize) -> Vec<usize> {
+    let mut from_a = Vec::new();
+    let mut from_b = Vec::new();
+    while a != b {
+        from_a.push(a);
+        from_b.push(b);
+        a = parents[a];
+        b = parents[b];
+    }
+    from_a.push(a);
+    from_a.extend(from_b.into_iter().rev());
+    from_a
+}
@@ -0,0 +1,456 @@
+//! Canonical labeling and automorphism group of a graph, by partition refinement with
+//! individualization.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    unionfind::UnionFind,
+    visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// The canonical form of a graph, computed by [`canonical_form`] or [`canonical_form_with`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CanonicalForm<N, K, L> {
+    /// The nodes in canonical order: the position of a node in this vector is its canonical label.
+    ///
+    /// If two graphs have equal certificates, mapping the node at every position of the order of
+    /// the first graph to the node at the same position of the order of the second is an
+    /// isomorphism.
+    pub order: Vec<N>,
+    /// The graph relabeled canonically. Two graphs are isomorphic if and only if their
+    /// certificates are equal.
+    pub certificate: Certificate<K, L>,
+    /// Generators of the automorphism group of the graph, as permutations of the canonical labels:
+    /// the automorphism maps the node at position `i` of [`order`](Self::order) to the node at
+    /// position `generator[i]`.
+    pub generators: Vec<Vec<usize>>,
+    /// The orbits of the automorphism group: the sets of nodes that some automorphism maps to each
+    /// other. Every orbit is listed in canonical order, and the orbits by their first node.
+    pub orbits: Vec<Vec<N>>,
+}
+
+/// A complete invariant of a graph, with node and edge colors: two graphs have equal
+/// certificates if and only if they are isomorphic.
+///
+/// It can be hashed to deduplicate a collection of graphs up to isomorphism.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Certificate<K, L> {
+    directed: bool,
+    /// The color of the node of every canonical label.
+    nodes: Vec<K>,
+    /// The edges between canonical labels, sorted, with the smaller end first if undirected.
+    edges: Vec<(usize, usize, L)>,
+}
+
+/// [Canonical form][1] of a graph without colors: its canonical labeling, a certificate that
+/// identifies it up to isomorphism, and its automorphism group.
+///
+/// See [`canonical_form_with`] for the details, and to take node and edge colors into account.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph, which may have parallel edges and self loops.
+///
+/// # Returns
+/// * [`CanonicalForm`]: the canonical order of the nodes, the certificate, and generators and
+///   orbits of the automorphism group.
+///
+/// # Complexity
+/// * Time complexity: exponential in the worst case, and usually **O(|V|² |E| log |E|)** for graphs
+///   without large symmetric substructures.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Graph_canonization
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::canonical_form, graph::UnGraph};
+///
+/// // The same path of length 3, labeled differently.
+/// let a = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+/// let b = UnGraph::<(), ()>::from_edges([(2, 0), (0, 3), (3, 1)]);
+/// let form_a = canonical_form(&a);
+/// let form_b = canonical_form(&b);
+/// assert_eq!(form_a.certificate, form_b.certificate);
+///
+/// // The path has a single non-trivial automorphism, which reverses it.
+/// assert_eq!(form_a.generators.len(), 1);
+/// assert_eq!(form_a.orbits.len(), 2);
+/// ```
+pub fn canonical_form<G>(graph: G) -> CanonicalForm<G::NodeId, (), ()>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    canonical_form_with(graph, |_| (), |_| ())
+}
+
+/// [Canonical form][1] of a graph with colored nodes and edges: its canonical labeling, a
+/// certificate that identifies it up to isomorphism, and its automorphism group.
+///
+/// Isomorphisms and automorphisms must preserve the colors of the nodes and edges. The nodes are
+/// first partitioned by color, and the partition is refined until the nodes of every cell have the
+/// same number of edges of every color to every other cell. While some cell has more than one
+/// node, each of its nodes is individualized in turn into a cell of its own and the partition is
+/// refined again, as in [nauty and bliss][2]. Every discrete partition reached is a labeling of the
+/// graph, and the canonical labeling is the one whose relabeled graph is the smallest.
+///
+/// Two labelings with the same relabeled graph differ by an automorphism, which is recorded and
+/// used to prune the parts of the search tree that are images of parts already explored. The
+/// automorphisms recorded generate the whole automorphism group.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph, which may have parallel edges and self loops.
+/// * `node_color`: closure that returns the color of a node.
+/// * `edge_color`: closure that returns the color of an edge.
+///
+/// # Returns
+/// * [`CanonicalForm`]: the canonical order of the nodes, the certificate, and generators and
+///   orbits of the automorphism group.
+///
+/// # Complexity
+/// * Time complexity: exponential in the worst case, and usually **O(|V|² |E| log |E|)** for graphs
+///   without large symmetric substructures.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Graph_canonization
+/// [2]: https://doi.org/10.1016/j.jsc.2013.09.003
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::canonical_form_with, graph::UnGraph, visit::EdgeRef};
+///
+/// // Two molecules with the same skeleton: C-C-O and C-O-C.
+/// let mut ethanol = UnGraph::<char, u8>::default();
+/// let c1 = ethanol.add_node('C');
+/// let c2 = ethanol.add_node('C');
+/// let o = ethanol.add_node('O');
+/// ethanol.add_edge(c1, c2, 1);
+/// ethanol.add_edge(c2, o, 1);
+///
+/// let mut ether = UnGraph::<char, u8>::default();
+/// let c1 = ether.add_node('C');
+/// let o = ether.add_node('O');
+/// let c2 = ether.add_node('C');
+/// ether.add_edge(c1, o, 1);
+/// ether.add_edge(o, c2, 1);
+///
+/// let form = |graph: &UnGraph<char, u8>| {
+///     canonical_form_with(graph, |node| graph[node], |edge| *edge.weight())
+/// };
+/// assert_ne!(form(&ethanol).certificate, form(&ether).certificate);
+/// // Only the ether is symmetric.
+/// assert_eq!(form(&ethanol).orbits.len(), 3);
+/// assert_eq!(form(&ether).orbits.len(), 2);
+/// ```
+pub fn canonical_form_with<G, NC, EC, K, L>(
+    graph: G,
+    mut node_color: NC,
+    mut edge_color: EC,
+) -> CanonicalForm<G::NodeId, K, L>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    NC: FnMut(G::NodeId) -> K,
+    EC: FnMut(G::EdgeRef) -> L,
+    K: Ord + Clone,
+    L: Ord + Clone,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let n = nodes.len();
+    let mut compact = vec![usize::MAX; graph.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        compact[graph.to_index(node)] = i;
+    }
+
+    let mut node_colors: Vec<K> = nodes.iter().map(|&node| node_color(node)).collect();
+    let (_, mut cells) = ranks(&node_colors);
+    node_colors.sort();
+
+    let edges: Vec<(usize, usize, L)> = graph
+        .edge_references()
+        .map(|edge| {
+            (
+                compact[graph.to_index(edge.source())],
+                compact[graph.to_index(edge.target())],
+                edge_color(edge),
+            )
+        })
+        .collect();
+    let edge_colors: Vec<L> = edges.iter().map(|(_, _, color)| color.clone()).collect();
+    let (edge_palette, edge_ranks) = ranks(&edge_colors);
+
+    let directed = graph.is_directed();
+    let mut search = Search {
+        directed,
+        outgoing: vec![Vec::new(); n],
+        incoming: vec![Vec::new(); n],
+        edges: Vec::with_capacity(edges.len()),
+        first: None,
+        best: None,
+        generators: Vec::new(),
+    };
+    for (&(a, b, _), &color) in edges.iter().zip(&edge_ranks) {
+        search.edges.push((a, b, color));
+        search.outgoing[a].push((b, color));
+        if directed {
+            search.incoming[b].push((a, color));
+        } else {
+            search.outgoing[b].push((a, color));
+        }
+    }
+
+    search.refine(&mut cells);
+    search.search(cells, &mut Vec::new());
+
+    let best = search.best.take().unwrap_or(Leaf {
+        labels: Vec::new(),
+        edges: Vec::new(),
+        path: Vec::new(),
+    });
+    let mut inverse = vec![0; n];
+    for (v, &label) in best.labels.iter().enumerate() {
+        inverse[label] = v;
+    }
+    let order = inverse.iter().map(|&v| nodes[v]).collect();
+
+    // Express the generators and orbits in terms of canonical labels.
+    let generators: Vec<Vec<usize>> = search
+        .generators
+        .iter()
+        .map(|generator| inverse.iter().map(|&v| best.labels[generator[v]]).collect())
+        .collect();
+    let mut orbit_of = UnionFind::new(n);
+    for generator in &generators {
+        for (i, &j) in generator.iter().enumerate() {
+            orbit_of.union(i, j);
+        }
+    }
+    let mut orbits: Vec<Vec<G::NodeId>> = Vec::new();
+    let mut orbit_index = vec![usize::MAX; n];
+    for label in 0..n {
+        let root = orbit_of.find_mut(label);
+        if orbit_index[root] == usize::MAX {
+            orbit_index[root] = orbits.len();
+            orbits.push(Vec::new());
+        }
+        orbits[orbit_index[root]].push(nodes[inverse[label]]);
+    }
+
+    let certificate = Certificate {
+        directed,
+        nodes: node_colors,
+        edges: best
+            .edges
+            .into_iter()
+            .map(|(a, b, color)| (a, b, edge_palette[color].clone()))
+            .collect(),
+    };
+    CanonicalForm {
+        order,
+        certificate,
+        generators,
+        orbits,
+    }
+}
+
+/// The distinct values, sorted, and the rank of every value among them.
+fn ranks<T: Ord + Clone>(values: &[T]) -> (Vec<T>, Vec<usize>) {
+    let mut palette = values.to_vec();
+    palette.sort();
+    palette.dedup();
+    let ranks = values
+        .iter()
+        .map(|value| palette.binary_search(value).unwrap())
+        .collect();
+    (palette, ranks)
+}
+
+/// A leaf of the search tree: a labeling of the nodes and the relabeled edges.
+#[derive(Clone)]
+struct Leaf {
+    labels: Vec<usize>,
+    edges: Vec<(usize, usize, usize)>,
+    /// The nodes individualized on the way to the leaf.
+    path: Vec<usize>,
+}
+
+struct Search {
+    directed: bool,
+    /// The targets and colors of the outgoing edges of every node, or of all edges if undirected.
+    outgoing: Vec<Vec<(usize, usize)>>,
+    /// The sources and colors of the incoming edges of every node if directed.
+    incoming: Vec<Vec<(usize, usize)>>,
+    edges: Vec<(usize, usize, usize)>,
+    first: Option<Leaf>,
+    best: Option<Leaf>,
+    /// Automorphisms found, as permutations of the nodes.
+    generators: Vec<Vec<usize>>,
+}
+
+impl Search {
+    /// Refine an ordered partition, given as the cell of every node, until the nodes of every
+    /// cell have the same number of edges of every color to every cell.
+    ///
+    /// Cells are split in place, so the order of the cells only depends on the structure of the
+    /// graph and not on the indices of the nodes.
+    fn refine(&self, cells: &mut [usize]) {
+        let n = cells.len();
+        let mut count = cells.iter().max().map_or(0, |&c| c + 1);
+        let mut order: Vec<usize> = (0..n).collect();
+        loop {
+            let signatures: Vec<(usize, Vec<(bool, usize, usize)>)> = (0..n)
+                .map(|v| {
+                    let mut signature: Vec<_> = self.outgoing[v]
+                        .iter()
+                        .map(|&(u, color)| (false, cells[u], color))
+                        .chain(
+                            self.incoming[v]
+                                .iter()
+                                .map(|&(u, color)| (true, cells[u], color)),
+                        )
+                        .collect();
+                    signature.sort_unstable();
+                    (cells[v], signature)
+                })
+                .collect();
+            order.sort_by(|&a, &b| signatures[a].cmp(&signatures[b]));
+            let mut next = 0;
+            for (i, &v) in order.iter().enumerate() {
+                if i > 0 && signatures[v] != signatures[order[i - 1]] {
+                    next += 1;
+                }
+                cells[v] = next;
+            }
+            let refined = if n == 0 { 0 } else { next + 1 };
+            if refined == count {
+                return;
+            }
+            count = refined;
+        }
+    }
+
+    /// Explore the subtree of the search tree below the partition `cells`, reached by
+    /// individualizing the nodes of `path`.
+    ///
+    /// Returns the level of the search tree to return to when an automorphism shows that the
+    /// rest of the subtree is an image of a part already explored.
+    fn search(&mut self, cells: Vec<usize>, path: &mut Vec<usize>) -> Option<usize> {
+        let n = cells.len();
+        let mut sizes = vec![0; n];
+        for &c in &cells {
+            sizes[c] += 1;
+        }
+        let Some(target) = sizes.iter().position(|&size| size > 1) else {
+            return self.leaf(cells, path);
+        };
+
+        let level = path.len();
+        // The orbits of the automorphisms found so far that fix every node of `path`, extended
+        // as the subtrees below find new generators.
+        let mut orbits = UnionFind::new(n);
+        let mut merged = 0;
+        let mut explored: Vec<usize> = Vec::new();
+        for v in 0..n {
+            if cells[v] != target {
+                continue;
+            }
+            self.merge_orbits(path, &mut orbits, &mut merged);
+            if explored.iter().any(|&w| orbits.equiv(v, w)) {
+                continue;
+            }
+            explored.push(v);
+            // Put `v` in a cell of its own, just before the rest of its cell.
+            let split: Vec<usize> = cells
+                .iter()
+                .enumerate()
+                .map(|(u, &c)| 2 * c + usize::from(u != v))
+                .collect();
+            let (_, mut child) = ranks(&split);
+            self.refine(&mut child);
+            path.push(v);
+            let jump = self.search(child, path);
+            path.pop();
+            if let Some(jump) = jump {
+                if jump < level {
+                    return Some(jump);
+                }
+            }
+        }
+        None
+    }
+
+    /// Merge the generators found since the first `merged` into `orbits`, if they fix every node
+    /// of `path`.
+    fn merge_orbits(&self, path: &[usize], orbits: &mut UnionFind<usize>, merged: &mut usize) {
+        for generator in &self.generators[*merged..] {
+            if path.iter().all(|&p| generator[p] == p) {
+                for (i, &j) in generator.iter().enumerate() {
+                    orbits.union(i, j);
+                }
+            }
+        }
+        *merged = self.generators.len();
+    }
+
+    /// Record a discrete partition, and return the level to return to if it is equivalent to the
+    /// first or best leaf.
+    fn leaf(&mut self, labels: Vec<usize>, path: &[usize]) -> Option<usize> {
+        let mut edges: Vec<(usize, usize, usize)> = self
+            .edges
+            .iter()
+            .map(|&(a, b, color)| {
+                let (a, b) = (labels[a], labels[b]);
+                if self.directed || a <= b {
+                    (a, b, color)
+                } else {
+                    (b, a, color)
+                }
+            })
+            .collect();
+        edges.sort_unstable();
+        let leaf = Leaf {
+            labels,
+            edges,
+            path: path.to_vec(),
+        };
+
+        let Some(first) = &self.first else {
+            self.best = Some(leaf.clone());
+            self.first = Some(leaf);
+            return None;
+        };
+        if first.edges == leaf.edges {
+            let level = common_prefix(&first.path, path);
+            self.generators
+                .push(automorphism(&first.labels, &leaf.labels));
+            return Some(level);
+        }
+        let best = self.best.as_ref().unwrap();
+        if best.edges == leaf.edges {
+            let level = common_prefix(&best.path, path);
+            self.generators
+                .push(automorphism(&best.labels, &leaf.labels));
+            return Some(level);
+        }
+        if leaf.edges < best.edges {
+            self.best = Some(leaf);
+        }
+        None
+    }
+}
+
+/// The automorphism that maps the node of every label of `from` to the node of the same label
+/// of `to`.
+fn automorphism(from: &[usize], to: &[usize]) -> Vec<usize> {
+    let mut inverse = vec![0; to.len()];
+    for (v, &label) in to.iter().enumerate() {
+        inverse[label] = v;
+    }
+    from.iter().map(|&label| inverse[label]).collect()
+}
+
+fn common_prefix(a: &[usize], b: &[usize]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
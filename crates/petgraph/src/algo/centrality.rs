@@ -0,0 +1,438 @@
+//! Centrality measures based on distances and on the spectrum of the adjacency matrix.
+//!
+//! See also [`page_rank`](super::page_rank()) and
+//! [`betweenness_centrality`](super::betweenness_centrality()).
+
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+
+use super::{UnitMeasure, dijkstra};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoEdges, IntoNodeIdentifiers, NodeCount,
+    NodeIndexable, Visitable,
+};
+
+/// Closeness centrality of every node.
+///
+/// The closeness centrality of a node `u` is the reciprocal of the average length of the shortest
+/// paths from `u` to the `r - 1` other nodes it reaches:
+///
+/// `C(u) = (r - 1) / Σ d(u, v)`
+///
+/// If `wf_improved` is `true`, the score is further multiplied by `(r - 1) / (n - 1)`, as proposed
+/// by Wasserman and Faust, so that nodes reaching few others in a disconnected graph get a lower
+/// score. Nodes that reach no other node have a score of zero.
+///
+/// Paths follow the direction of the edges of directed graphs; wrap the graph in
+/// [`Reversed`](crate::visit::Reversed) to measure the distances *to* every node instead.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_cost`: closure that returns the length of a particular edge, which must be non-negative.
+///   Use `|_| 1.` for unweighted graphs.
+/// * `wf_improved`: whether to scale the scores by the fraction of reachable nodes.
+///
+/// # Returns
+/// * `Vec<D>`: the closeness centrality of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V|(|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::centrality::closeness_centrality, graph::UnGraph};
+///
+/// // A path graph: 0 - 1 - 2
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let closeness = closeness_centrality(&graph, |_| 1_f64, false);
+/// assert_eq!(closeness, vec![2. / 3., 1., 2. / 3.]);
+/// ```
+pub fn closeness_centrality<G, F, D>(graph: G, mut edge_cost: F, wf_improved: bool) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let node_count = graph.node_count();
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let distances = dijkstra(graph, node, None, &mut edge_cost);
+        let reached = distances.len() - 1;
+        let total = distances.into_values().sum::<D>();
+        if reached == 0 || total == D::zero() {
+            continue;
+        }
+        let mut score = D::from_usize(reached) / total;
+        if wf_improved {
+            score = score * D::from_usize(reached) / D::from_usize(node_count - 1);
+        }
+        scores[graph.to_index(node)] = score;
+    }
+    scores
+}
+
+/// Harmonic centrality of every node.
+///
+/// The harmonic centrality of a node `u` is the sum of the reciprocals of the lengths of the
+/// shortest paths from `u` to every other node, unreachable nodes contributing zero:
+///
+/// `H(u) = Σ 1 / d(u, v)`
+///
+/// Unlike [`closeness_centrality`], it is well-behaved on disconnected graphs.
+///
+/// Paths follow the direction of the edges of directed graphs; wrap the graph in
+/// [`Reversed`](crate::visit::Reversed) to measure the distances *to* every node instead.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_cost`: closure that returns the length of a particular edge, which must be positive. Use
+///   `|_| 1.` for unweighted graphs.
+///
+/// # Returns
+/// * `Vec<D>`: the harmonic centrality of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V|(|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::centrality::harmonic_centrality, graph::UnGraph};
+///
+/// // A path graph: 0 - 1 - 2
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let harmonic = harmonic_centrality(&graph, |_| 1_f64);
+/// assert_eq!(harmonic, vec![1.5, 2., 1.5]);
+/// ```
+pub fn harmonic_centrality<G, F, D>(graph: G, mut edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        scores[graph.to_index(node)] = dijkstra(graph, node, None, &mut edge_cost)
+            .into_iter()
+            .filter(|&(other, _)| other != node)
+            .map(|(_, dist)| D::one() / dist)
+            .sum();
+    }
+    scores
+}
+
+/// Call `f` with the indices of the endpoints of every edge, in both directions for undirected
+/// graphs.
+fn for_each_arc<G, F>(graph: G, mut f: F)
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(usize, usize),
+{
+    for edge in graph.edge_references() {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        f(a, b);
+        if !graph.is_directed() && a != b {
+            f(b, a);
+        }
+    }
+}
+
+fn abs<D: UnitMeasure + Copy>(x: D) -> D {
+    if x < D::zero() { D::zero() - x } else { x }
+}
+
+/// Return `true` if the sum of the absolute differences between `x` and `last` is below
+/// `n * tol`.
+pub(super) fn has_converged<D: UnitMeasure + Copy>(
+    x: &[D],
+    last: &[D],
+    node_count: usize,
+    tol: D,
+) -> bool {
+    x.iter().zip(last).map(|(&a, &b)| abs(a - b)).sum::<D>() < D::from_usize(node_count) * tol
+}
+
+/// Stopping criteria of [`eigenvector_centrality`] and [`hits`].
+///
+/// The [`Default`] options stop after `100` iterations, or once the scores change by less than
+/// [`UnitMeasure::default_tol`] per node.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IterationOptions<D> {
+    /// The maximum number of iterations.
+    pub max_iter: usize,
+    /// The tolerance used to detect convergence.
+    pub tol: D,
+}
+
+impl<D: UnitMeasure> Default for IterationOptions<D> {
+    fn default() -> Self {
+        IterationOptions {
+            max_iter: 100,
+            tol: D::default_tol(),
+        }
+    }
+}
+
+/// Parameters of [`katz_centrality`].
+///
+/// The [`Default`] parameters are those of NetworkX: an attenuation factor of `0.1`, a base score
+/// of `1`, at most `1000` iterations and the [`UnitMeasure::default_tol`] tolerance. Override
+/// some of them with the struct update syntax:
+///
+/// ```rust
+/// use petgraph::algo::centrality::KatzOptions;
+///
+/// let options = KatzOptions {
+///     alpha: 0.05,
+///     ..KatzOptions::default()
+/// };
+/// assert_eq!(options.beta, 1.);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KatzOptions<D> {
+    /// The attenuation factor.
+    pub alpha: D,
+    /// The base score of every node.
+    pub beta: D,
+    /// The maximum number of iterations.
+    pub max_iter: usize,
+    /// The tolerance used to detect convergence.
+    pub tol: D,
+}
+
+impl<D: UnitMeasure> Default for KatzOptions<D> {
+    fn default() -> Self {
+        KatzOptions {
+            alpha: D::from_f64(0.1),
+            beta: D::one(),
+            max_iter: 1000,
+            tol: D::default_tol(),
+        }
+    }
+}
+
+/// Scale `x` so that the sum of its entries is one, unless they are all zero.
+fn normalize_sum<D: UnitMeasure + Copy>(x: &mut [D]) {
+    let sum = x.iter().copied().sum::<D>();
+    if sum != D::zero() {
+        for v in x {
+            *v = *v / sum;
+        }
+    }
+}
+
+/// Eigenvector centrality of every node.
+///
+/// The eigenvector centrality of a node is proportional to the sum of the centralities of the
+/// nodes pointing to it, i.e. the scores form the principal eigenvector of the transposed
+/// adjacency matrix. It is computed by power iteration on `A + I`, which converges for connected
+/// bipartite graphs as well.
+///
+/// The scores are normalized so that they sum to one. For directed graphs, the result is only
+/// meaningful if the graph is strongly connected.
+///
+/// The iteration stops once the sum of the absolute changes of the scores is less than
+/// `n * tol`, or after `max_iter` iterations.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `options`: the maximum number of iterations and the convergence tolerance, see
+///   [`IterationOptions`].
+///
+/// # Returns
+/// * `Vec<D>`: the eigenvector centrality of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **k** is the number of iterations, **|V|** is the number of nodes and **|E|** is the
+/// number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::centrality::{IterationOptions, eigenvector_centrality},
+///     graph::UnGraph,
+/// };
+///
+/// // A star graph, with node 0 at its center.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+/// let options = IterationOptions {
+///     max_iter: 100,
+///     tol: 1e-9_f64,
+/// };
+/// let scores = eigenvector_centrality(&graph, options);
+/// // The center is twice as central as each leaf.
+/// assert!((scores[0] - 1. / 3.).abs() < 1e-6);
+/// assert!((scores[1] - 1. / 6.).abs() < 1e-6);
+/// ```
+pub fn eigenvector_centrality<G, D>(graph: G, options: IterationOptions<D>) -> Vec<D>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+    D: UnitMeasure + Copy,
+{
+    let IterationOptions { max_iter, tol } = options;
+    let node_count = graph.node_count();
+    let mut x = vec![D::zero(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        x[graph.to_index(node)] = D::one() / D::from_usize(node_count);
+    }
+
+    for _ in 0..max_iter {
+        let last = x.clone();
+        for_each_arc(graph, |a, b| x[b] = x[b] + last[a]);
+        normalize_sum(&mut x);
+        if has_converged(&x, &last, node_count, tol) {
+            break;
+        }
+    }
+    x
+}
+
+/// Katz centrality of every node.
+///
+/// The Katz centrality of a node counts the walks ending at it, walks of length `k` being
+/// attenuated by a factor `alpha^k`:
+///
+/// `x(v) = alpha Σ x(u) + beta`, where the sum ranges over the edges `u → v`.
+///
+/// The scores are computed by fixed-point iteration, which converges if `alpha` is less than the
+/// reciprocal of the largest eigenvalue of the adjacency matrix. In particular, choosing `alpha`
+/// less than the reciprocal of the maximum degree is always safe. The scores are not normalized.
+///
+/// The iteration stops once the sum of the absolute changes of the scores is less than
+/// `n * tol`, or after `max_iter` iterations.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `options`: the attenuation factor `alpha`, the base score `beta` of every node, the maximum
+///   number of iterations and the convergence tolerance, see [`KatzOptions`].
+///
+/// # Returns
+/// * `Vec<D>`: the Katz centrality of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **k** is the number of iterations, **|V|** is the number of nodes and **|E|** is the
+/// number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::centrality::{KatzOptions, katz_centrality},
+///     graph::DiGraph,
+/// };
+///
+/// // A directed path: 0 -> 1 -> 2
+/// let graph = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let options = KatzOptions {
+///     alpha: 0.5_f64,
+///     ..KatzOptions::default()
+/// };
+/// let scores = katz_centrality(&graph, options);
+/// assert_eq!(scores, vec![1., 1.5, 1.75]);
+/// ```
+pub fn katz_centrality<G, D>(graph: G, options: KatzOptions<D>) -> Vec<D>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+    D: UnitMeasure + Copy,
+{
+    let KatzOptions {
+        alpha,
+        beta,
+        max_iter,
+        tol,
+    } = options;
+    let node_count = graph.node_count();
+    let mut x = vec![D::zero(); graph.node_bound()];
+
+    for _ in 0..max_iter {
+        let last = x.clone();
+        for node in graph.node_identifiers() {
+            x[graph.to_index(node)] = beta;
+        }
+        for_each_arc(graph, |a, b| x[b] = x[b] + alpha * last[a]);
+        if has_converged(&x, &last, node_count, tol) {
+            break;
+        }
+    }
+    x
+}
+
+/// Hub and authority scores of every node, computed by the [HITS algorithm][1].
+///
+/// A good hub points to many good authorities, and a good authority is pointed to by many good
+/// hubs. The scores are computed by power iteration, and normalized so that each vector sums to
+/// one.
+///
+/// The iteration stops once the sum of the absolute changes of the hub scores is less than
+/// `n * tol`, or after `max_iter` iterations.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `options`: the maximum number of iterations and the convergence tolerance, see
+///   [`IterationOptions`].
+///
+/// # Returns
+/// * `(Vec<D>, Vec<D>)`: the hub and the authority score of every node, indexed by
+///   [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **k** is the number of iterations, **|V|** is the number of nodes and **|E|** is the
+/// number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/HITS_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::centrality::{IterationOptions, hits},
+///     graph::DiGraph,
+/// };
+///
+/// // Nodes 0 and 1 both point to nodes 2 and 3.
+/// let graph = DiGraph::<(), ()>::from_edges(&[(0, 2), (0, 3), (1, 2), (1, 3)]);
+/// let (hubs, authorities) = hits(&graph, IterationOptions::<f64>::default());
+/// assert_eq!(hubs, vec![0.5, 0.5, 0., 0.]);
+/// assert_eq!(authorities, vec![0., 0., 0.5, 0.5]);
+/// ```
+pub fn hits<G, D>(graph: G, options: IterationOptions<D>) -> (Vec<D>, Vec<D>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+    D: UnitMeasure + Copy,
+{
+    let IterationOptions { max_iter, tol } = options;
+    let node_count = graph.node_count();
+    let mut hubs = vec![D::zero(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        hubs[graph.to_index(node)] = D::one() / D::from_usize(node_count);
+    }
+    let mut authorities = vec![D::zero(); graph.node_bound()];
+
+    for _ in 0..max_iter {
+        let last = hubs.clone();
+        authorities.fill(D::zero());
+        for_each_arc(graph, |a, b| authorities[b] = authorities[b] + last[a]);
+        hubs.fill(D::zero());
+        for_each_arc(graph, |a, b| hubs[a] = hubs[a] + authorities[b]);
+        normalize_sum(&mut hubs);
+        if has_converged(&hubs, &last, node_count, tol) {
+            break;
+        }
+    }
+    normalize_sum(&mut authorities);
+    (hubs, authorities)
+}
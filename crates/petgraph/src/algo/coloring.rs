@@ -1,11 +1,15 @@
-use alloc::{collections::BinaryHeap, vec};
-use core::hash::Hash;
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::{cmp::Reverse, hash::Hash};
 
 use hashbrown::{HashMap, HashSet};
 
 use crate::{
+    rng::SplitMix64,
     scored::MaxScored,
-    visit::{IntoEdges, IntoNodeIdentifiers, NodeIndexable, VisitMap, Visitable},
+    visit::{
+        EdgeRef, IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable,
+        VisitMap, Visitable,
+    },
 };
 
 /// [DStatur algorithm][1] to properly color a non weighted undirected graph.
@@ -100,3 +104,794 @@ where
 
     (colored, max_color + 1)
 }
+
+/// The order in which [`greedy_coloring`] colors the nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColoringOrder {
+    /// By decreasing degree.
+    LargestFirst,
+    /// The reverse of the order in which the nodes are removed when repeatedly removing a node of
+    /// smallest degree, due to Matula and Beck. Uses at most `d + 1` colors, where `d` is the
+    /// degeneracy of the graph.
+    SmallestLast,
+    /// A random order drawn from the seed.
+    RandomSequential {
+        /// The seed of the pseudo-random order.
+        seed: u64,
+    },
+    /// A breadth-first order, so that every node other than the first of its connected component
+    /// is adjacent to a node colored before it.
+    ConnectedSequential,
+}
+
+/// [Greedy coloring][1] of an undirected graph, with a selectable order.
+///
+/// The nodes are colored one by one, in the given order, with the smallest color that none of
+/// their neighbors colored before has. This is a heuristic, so it does not necessarily return a
+/// minimum coloring, and different orders suit different graphs. Self loops are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph.
+/// * `order`: the order in which the nodes are colored.
+///
+/// # Returns
+/// Returns a tuple of:
+/// * [`struct@hashbrown::HashMap`] that associates to each `NodeId` its color.
+/// * `usize`: the number of used colors.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| log |V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Greedy_coloring
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::coloring::{ColoringOrder, greedy_coloring},
+///     graph::UnGraph,
+/// };
+///
+/// // A "crown": two rows of four nodes, each adjacent to all nodes of the other row but the one
+/// // facing it. Some orders color it with two colors, others need four.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 5),
+///     (0, 6),
+///     (0, 7),
+///     (1, 4),
+///     (1, 6),
+///     (1, 7),
+///     (2, 4),
+///     (2, 5),
+///     (2, 7),
+///     (3, 4),
+///     (3, 5),
+///     (3, 6),
+/// ]);
+/// let (coloring, colors) = greedy_coloring(&graph, ColoringOrder::SmallestLast);
+/// assert!(colors <= 4);
+/// for edge in graph.raw_edges() {
+///     assert_ne!(coloring[&edge.source()], coloring[&edge.target()]);
+/// }
+/// ```
+pub fn greedy_coloring<G>(graph: G, order: ColoringOrder) -> (HashMap<G::NodeId, usize>, usize)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::new(graph);
+    let n = adjacency.nodes.len();
+    let order = match order {
+        ColoringOrder::LargestFirst => {
+            let mut order: Vec<usize> = (0..n).collect();
+            order.sort_by_key(|&v| Reverse(adjacency.neighbors[v].len()));
+            order
+        }
+        ColoringOrder::SmallestLast => adjacency.smallest_last_order(),
+        ColoringOrder::RandomSequential { seed } => {
+            let mut order: Vec<usize> = (0..n).collect();
+            SplitMix64::new(seed).shuffle(&mut order);
+            order
+        }
+        ColoringOrder::ConnectedSequential => adjacency.breadth_first_order(),
+    };
+
+    let mut colors = vec![usize::MAX; n];
+    // `used[c] == v` if color `c` is taken by a neighbor of `v`.
+    let mut used = vec![usize::MAX; n];
+    let mut count = 0;
+    for v in order {
+        for &u in &adjacency.neighbors[v] {
+            if colors[u] != usize::MAX {
+                used[colors[u]] = v;
+            }
+        }
+        let color = (0..).find(|&c| used[c] != v).unwrap();
+        colors[v] = color;
+        count = count.max(color + 1);
+    }
+    (adjacency.into_coloring(&colors), count)
+}
+
+/// A coloring found by [`exact_coloring`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExactColoring<N: Eq + Hash> {
+    /// The color of every node.
+    pub coloring: HashMap<N, usize>,
+    /// The number of used colors.
+    pub colors: usize,
+    /// Whether the search was completed, proving that the number of colors is the
+    /// [chromatic number](https://en.wikipedia.org/wiki/Graph_coloring#Chromatic_number).
+    pub optimal: bool,
+}
+
+/// Minimum coloring of an undirected graph, by DSATUR [branch and bound][1].
+///
+/// The search starts from the coloring of [`dsatur_coloring`] and a clique of the graph, whose
+/// size is a lower bound of the number of colors. It then repeatedly colors the node with the
+/// most distinct colors among its neighbors, trying every color that does not exceed the best
+/// coloring found, until it proves that no coloring with fewer colors exists.
+///
+/// The problem is NP-hard, and the search can take exponential time. Once `max_branches`
+/// branches of the search are explored, the best coloring found so far is returned, and
+/// [`ExactColoring::optimal`] is `false`.
+///
+/// The graph must be undirected. It should not contain loops, which are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph without loops.
+/// * `max_branches`: the maximum number of branches of the search, or `None` for no limit.
+///
+/// # Returns
+/// * [`ExactColoring`]: the coloring with the fewest colors found, which uses the chromatic number
+///   of colors if the search was completed.
+///
+/// # Complexity
+/// * Time complexity: **O(k^|V|)** in the worst case, or **O(b(|V| + |E|))** with a budget of **b**
+///   branches.
+/// * Auxiliary space: **O(k|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **k** the number of
+/// colors found by [`dsatur_coloring`].
+///
+/// [1]: https://doi.org/10.1016/j.dam.2011.10.008
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::coloring::exact_coloring, graph::UnGraph};
+///
+/// // A wheel with a center and five spokes needs four colors.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1),
+///     (0, 2),
+///     (0, 3),
+///     (0, 4),
+///     (0, 5),
+///     (1, 2),
+///     (2, 3),
+///     (3, 4),
+///     (4, 5),
+///     (5, 1),
+/// ]);
+/// let result = exact_coloring(&graph, None);
+/// assert_eq!(result.colors, 4);
+/// assert!(result.optimal);
+/// ```
+pub fn exact_coloring<G>(graph: G, max_branches: Option<usize>) -> ExactColoring<G::NodeId>
+where
+    G: IntoEdges + IntoNodeIdentifiers + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::new(graph);
+    let n = adjacency.nodes.len();
+
+    let (initial, initial_count) = dsatur_coloring(graph);
+    let mut search = ColoringSearch {
+        neighbors: &adjacency.neighbors,
+        best: adjacency.nodes.iter().map(|node| initial[node]).collect(),
+        best_count: initial_count,
+        lower_bound: adjacency.greedy_clique_size(),
+        colors: vec![usize::MAX; n],
+        counts: vec![vec![0; initial_count]; n],
+        saturation: vec![0; n],
+        branches: max_branches,
+        exhausted: false,
+    };
+    if search.lower_bound < search.best_count {
+        search.expand(0, 0);
+    }
+
+    let ColoringSearch {
+        best,
+        best_count,
+        exhausted,
+        ..
+    } = search;
+    ExactColoring {
+        coloring: adjacency.into_coloring(&best),
+        colors: best_count,
+        optimal: !exhausted,
+    }
+}
+
+/// The reason why a coloring is not a proper coloring of a graph.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColoringError<N> {
+    /// The node has no color.
+    Uncolored(N),
+    /// The two nodes are adjacent and have the same color.
+    Conflict(N, N),
+}
+
+/// Check that `coloring` is a proper coloring of `graph`: that every node has a color, and that no
+/// two adjacent nodes have the same color.
+///
+/// A node with a self loop conflicts with itself.
+///
+/// # Arguments
+/// * `graph`: undirected graph.
+/// * `coloring`: the color of every node, as returned by [`dsatur_coloring`] or
+///   [`greedy_coloring`].
+///
+/// # Returns
+/// * `Ok(())`: if the coloring is proper.
+/// * `Err(ColoringError)`: an uncolored node or a pair of conflicting nodes otherwise.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(1)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use hashbrown::HashMap;
+/// use petgraph::{
+///     algo::coloring::{ColoringError, validate_coloring},
+///     graph::{NodeIndex, UnGraph},
+/// };
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let [a, b, c] = [0, 1, 2].map(NodeIndex::new);
+///
+/// let coloring = HashMap::from([(a, 0), (b, 1), (c, 0)]);
+/// assert_eq!(validate_coloring(&graph, &coloring), Ok(()));
+///
+/// let coloring = HashMap::from([(a, 0), (b, 1), (c, 1)]);
+/// assert_eq!(
+///     validate_coloring(&graph, &coloring),
+///     Err(ColoringError::Conflict(b, c))
+/// );
+/// ```
+pub fn validate_coloring<G>(
+    graph: G,
+    coloring: &HashMap<G::NodeId, usize>,
+) -> Result<(), ColoringError<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    for a in graph.node_identifiers() {
+        let color = coloring.get(&a).ok_or(ColoringError::Uncolored(a))?;
+        for b in graph.neighbors(a) {
+            match coloring.get(&b) {
+                None => return Err(ColoringError::Uncolored(b)),
+                Some(other) if other == color => return Err(ColoringError::Conflict(a, b)),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// [Edge coloring][1] of a simple graph with at most `Δ + 1` colors, where `Δ` is the maximum
+/// degree, with the algorithm of [Misra and Gries][2].
+///
+/// In a proper edge coloring, no two edges that share an end have the same color. By Vizing's
+/// theorem every simple graph has one with `Δ + 1` colors, and some need that many. For
+/// bipartite graphs, [`bipartite_edge_coloring`] finds one with only `Δ` colors.
+///
+/// The direction of the edges of a directed graph is ignored.
+///
+/// # Arguments
+/// * `graph`: a graph without self loops or parallel edges.
+///
+/// # Returns
+/// Returns a tuple of:
+/// * [`struct@hashbrown::HashMap`] that associates to each `EdgeId` its color.
+/// * `usize`: the number of used colors.
+///
+/// # Panics
+/// * If the graph has a self loop, or several edges between the same two nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|Δ)**.
+/// * Auxiliary space: **O(|V|Δ + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **Δ** the maximum
+/// degree.
+///
+/// [1]: https://en.wikipedia.org/wiki/Edge_coloring
+/// [2]: https://en.wikipedia.org/wiki/Misra_%26_Gries_edge_coloring_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::coloring::misra_gries_edge_coloring, graph::UnGraph, visit::EdgeRef};
+///
+/// // A triangle needs three colors, one more than its maximum degree.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// let (coloring, colors) = misra_gries_edge_coloring(&graph);
+/// assert_eq!(colors, 3);
+/// // The edges around every node have distinct colors.
+/// for node in graph.node_indices() {
+///     let mut around: Vec<_> = graph.edges(node).map(|e| coloring[&e.id()]).collect();
+///     around.sort();
+///     around.dedup();
+///     assert_eq!(around.len(), 2);
+/// }
+/// ```
+#[track_caller]
+pub fn misra_gries_edge_coloring<G>(graph: G) -> (HashMap<G::EdgeId, usize>, usize)
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    let mut state = EdgeColoring::new(graph, 1);
+    for v in 0..state.incident.len() {
+        let mut others: Vec<usize> = state.edges_of[v]
+            .iter()
+            .map(|&e| state.other(e, v))
+            .collect();
+        others.sort_unstable();
+        assert!(
+            others.iter().all(|&u| u != v) && others.windows(2).all(|w| w[0] != w[1]),
+            "The graph must not contain self loops or parallel edges."
+        );
+    }
+
+    let mut fan = Vec::new();
+    for e in 0..state.ends.len() {
+        let (u, v) = state.ends[e];
+        // A maximal fan of `u` starting at `v`: every edge `(u, fan[i])` is colored with a color
+        // free on `fan[i - 1]`.
+        fan.clear();
+        fan.push((v, e));
+        loop {
+            let last = fan.last().unwrap().0;
+            let next = (0..state.color_count)
+                .filter(|&c| state.is_free(last, c))
+                .filter_map(|c| state.incident[u][c])
+                .map(|f| (state.other(f, u), f))
+                .find(|&(w, _)| fan.iter().all(|&(x, _)| x != w));
+            match next {
+                Some(entry) => fan.push(entry),
+                None => break,
+            }
+        }
+
+        let c = state.free_color(u);
+        let d = state.free_color(fan.last().unwrap().0);
+        state.flip_path(u, d, c);
+
+        // The longest prefix of the fan that is still a fan, up to a node on which `d` is free.
+        let mut end = 0;
+        for i in 0..fan.len() {
+            if i > 0 && !state.is_free(fan[i - 1].0, state.colors[fan[i].1]) {
+                break;
+            }
+            end = i;
+            if state.is_free(fan[i].0, d) {
+                break;
+            }
+        }
+        // Rotate the colors of the fan, and color its last edge with `d`.
+        let rotated: Vec<usize> = fan[1..=end].iter().map(|&(_, f)| state.colors[f]).collect();
+        for &(_, f) in &fan[1..=end] {
+            state.unset(f);
+        }
+        for (&(_, f), &color) in fan[..end].iter().zip(&rotated) {
+            state.set(f, color);
+        }
+        state.set(fan[end].1, d);
+    }
+    state.into_coloring(graph)
+}
+
+/// Minimum [edge coloring][1] of a bipartite graph, with `Δ` colors, where `Δ` is the maximum
+/// degree.
+///
+/// In a proper edge coloring, no two edges that share an end have the same color. By König's
+/// theorem, the edges of every bipartite graph can be colored with `Δ` colors. Every edge is
+/// colored with a color free on both of its ends, after swapping two colors along an
+/// alternating path if needed. Parallel edges are allowed.
+///
+/// The direction of the edges of a directed graph is ignored.
+///
+/// # Arguments
+/// * `graph`: a bipartite graph.
+///
+/// # Returns
+/// * `Some((HashMap, usize))`: a [`struct@hashbrown::HashMap`] that associates to each `EdgeId` its
+///   color, and the number of used colors.
+/// * `None`: if the graph is not bipartite.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|(|V| + Δ))**.
+/// * Auxiliary space: **O(|V|Δ + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **Δ** the maximum
+/// degree.
+///
+/// [1]: https://en.wikipedia.org/wiki/Edge_coloring
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::coloring::bipartite_edge_coloring,
+///     graph::{EdgeIndex, UnGraph},
+/// };
+///
+/// // Every node of a cycle of length 4 has degree 2, and so does every color.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let (coloring, colors) = bipartite_edge_coloring(&graph).unwrap();
+/// assert_eq!(colors, 2);
+/// assert_ne!(coloring[&EdgeIndex::new(0)], coloring[&EdgeIndex::new(1)]);
+///
+/// // A triangle is not bipartite.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// assert_eq!(bipartite_edge_coloring(&graph), None);
+/// ```
+pub fn bipartite_edge_coloring<G>(graph: G) -> Option<(HashMap<G::EdgeId, usize>, usize)>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    let mut state = EdgeColoring::new(graph, 0);
+
+    // Check that the graph is bipartite, with a breadth-first search from every node.
+    let n = state.incident.len();
+    let mut sides = vec![usize::MAX; n];
+    let mut queue = Vec::new();
+    for start in 0..n {
+        if sides[start] != usize::MAX {
+            continue;
+        }
+        sides[start] = 0;
+        queue.push(start);
+        while let Some(v) = queue.pop() {
+            for &e in &state.edges_of[v] {
+                let u = state.other(e, v);
+                if sides[u] == usize::MAX {
+                    sides[u] = 1 - sides[v];
+                    queue.push(u);
+                } else if sides[u] == sides[v] {
+                    return None;
+                }
+            }
+        }
+    }
+
+    for e in 0..state.ends.len() {
+        let (u, v) = state.ends[e];
+        let a = state.free_color(u);
+        let b = state.free_color(v);
+        // The path from `v` alternating between `a` and `b` cannot reach `u`, which is on the
+        // other side, with an edge of color `a`: swapping its colors frees `a` on `v`.
+        if !state.is_free(v, a) {
+            state.flip_path(v, a, b);
+        }
+        state.set(e, a);
+    }
+    Some(state.into_coloring(graph))
+}
+
+/// State of an edge coloring, with the edges and nodes as indices.
+struct EdgeColoring {
+    ends: Vec<(usize, usize)>,
+    colors: Vec<usize>,
+    /// The edges incident to every node.
+    edges_of: Vec<Vec<usize>>,
+    /// `incident[v][c]` is the edge of color `c` incident to `v`, if any.
+    incident: Vec<Vec<Option<usize>>>,
+    color_count: usize,
+}
+
+impl EdgeColoring {
+    /// An uncolored state for `graph`, with `extra` colors more than its maximum degree.
+    fn new<G>(graph: G, extra: usize) -> Self
+    where
+        G: IntoEdgeReferences + NodeIndexable,
+    {
+        let n = graph.node_bound();
+        let ends: Vec<(usize, usize)> = graph
+            .edge_references()
+            .map(|edge| (graph.to_index(edge.source()), graph.to_index(edge.target())))
+            .collect();
+        let mut edges_of = vec![Vec::new(); n];
+        for (e, &(a, b)) in ends.iter().enumerate() {
+            edges_of[a].push(e);
+            if a != b {
+                edges_of[b].push(e);
+            }
+        }
+        let max_degree = edges_of.iter().map(Vec::len).max().unwrap_or(0);
+        let color_count = max_degree + extra;
+        EdgeColoring {
+            colors: vec![usize::MAX; ends.len()],
+            ends,
+            edges_of,
+            incident: vec![vec![None; color_count]; n],
+            color_count,
+        }
+    }
+
+    fn other(&self, e: usize, v: usize) -> usize {
+        let (a, b) = self.ends[e];
+        if a == v { b } else { a }
+    }
+
+    fn is_free(&self, v: usize, color: usize) -> bool {
+        self.incident[v][color].is_none()
+    }
+
+    /// The smallest color free on `v`.
+    fn free_color(&self, v: usize) -> usize {
+        (0..self.color_count).find(|&c| self.is_free(v, c)).unwrap()
+    }
+
+    fn set(&mut self, e: usize, color: usize) {
+        let (a, b) = self.ends[e];
+        self.colors[e] = color;
+        self.incident[a][color] = Some(e);
+        self.incident[b][color] = Some(e);
+    }
+
+    fn unset(&mut self, e: usize) {
+        let (a, b) = self.ends[e];
+        let color = core::mem::replace(&mut self.colors[e], usize::MAX);
+        self.incident[a][color] = None;
+        self.incident[b][color] = None;
+    }
+
+    /// Swap the colors `first` and `second` on the maximal path from `start` whose edges
+    /// alternate between them, starting with `first`.
+    fn flip_path(&mut self, start: usize, first: usize, second: usize) {
+        let mut path = Vec::new();
+        let (mut v, mut color) = (start, first);
+        while let Some(e) = self.incident[v][color] {
+            path.push(e);
+            v = self.other(e, v);
+            color = if color == first { second } else { first };
+        }
+        let flipped: Vec<usize> = path
+            .iter()
+            .map(|&e| {
+                if self.colors[e] == first {
+                    second
+                } else {
+                    first
+                }
+            })
+            .collect();
+        for &e in &path {
+            self.unset(e);
+        }
+        for (&e, &color) in path.iter().zip(&flipped) {
+            self.set(e, color);
+        }
+    }
+
+    fn into_coloring<G>(self, graph: G) -> (HashMap<G::EdgeId, usize>, usize)
+    where
+        G: IntoEdgeReferences,
+        G::EdgeId: Eq + Hash,
+    {
+        let count = self.colors.iter().map(|&c| c + 1).max().unwrap_or(0);
+        let coloring = graph
+            .edge_references()
+            .map(|edge| edge.id())
+            .zip(self.colors)
+            .collect();
+        (coloring, count)
+    }
+}
+
+/// The nodes of a graph with their neighbors, as positions in the node list, without self loops
+/// or parallel edges.
+struct Adjacency<N> {
+    nodes: Vec<N>,
+    neighbors: Vec<Vec<usize>>,
+}
+
+impl<N> Adjacency<N>
+where
+    N: Copy + Eq + Hash,
+{
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoNeighbors<NodeId = N> + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let mut positions = vec![usize::MAX; graph.node_bound()];
+        for (i, &node) in nodes.iter().enumerate() {
+            positions[graph.to_index(node)] = i;
+        }
+        let neighbors = nodes
+            .iter()
+            .enumerate()
+            .map(|(a, &node)| {
+                let mut list: Vec<usize> = graph
+                    .neighbors(node)
+                    .map(|b| positions[graph.to_index(b)])
+                    .filter(|&b| b != a)
+                    .collect();
+                list.sort_unstable();
+                list.dedup();
+                list
+            })
+            .collect();
+        Adjacency { nodes, neighbors }
+    }
+
+    /// Repeatedly remove a node of smallest degree, and return the nodes in reverse order.
+    fn smallest_last_order(&self) -> Vec<usize> {
+        let n = self.nodes.len();
+        let mut degrees: Vec<usize> = self.neighbors.iter().map(Vec::len).collect();
+        // Buckets of nodes by degree, with stale entries skipped when popped.
+        let mut buckets = vec![Vec::new(); n];
+        for (v, &d) in degrees.iter().enumerate() {
+            buckets[d].push(v);
+        }
+        let mut removed = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        let mut min = 0;
+        while order.len() < n {
+            let Some(v) = buckets[min].pop() else {
+                min += 1;
+                continue;
+            };
+            if removed[v] || degrees[v] != min {
+                continue;
+            }
+            removed[v] = true;
+            order.push(v);
+            for &u in &self.neighbors[v] {
+                if !removed[u] {
+                    degrees[u] -= 1;
+                    buckets[degrees[u]].push(u);
+                    min = min.min(degrees[u]);
+                }
+            }
+        }
+        order.reverse();
+        order
+    }
+
+    /// A breadth-first order of the nodes, starting every connected component at its first node.
+    fn breadth_first_order(&self) -> Vec<usize> {
+        let n = self.nodes.len();
+        let mut seen = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        for start in 0..n {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut head = order.len();
+            order.push(start);
+            while let Some(&v) = order.get(head) {
+                head += 1;
+                for &u in &self.neighbors[v] {
+                    if !seen[u] {
+                        seen[u] = true;
+                        order.push(u);
+                    }
+                }
+            }
+        }
+        order
+    }
+
+    /// The size of a clique grown greedily from a node of largest degree.
+    fn greedy_clique_size(&self) -> usize {
+        let Some(start) = (0..self.nodes.len()).max_by_key(|&v| self.neighbors[v].len()) else {
+            return 0;
+        };
+        let mut clique = vec![start];
+        let mut candidates = self.neighbors[start].clone();
+        candidates.sort_by_key(|&v| Reverse(self.neighbors[v].len()));
+        for v in candidates {
+            if clique
+                .iter()
+                .all(|u| self.neighbors[v].binary_search(u).is_ok())
+            {
+                clique.push(v);
+            }
+        }
+        clique.len()
+    }
+
+    fn into_coloring(self, colors: &[usize]) -> HashMap<N, usize> {
+        self.nodes.into_iter().zip(colors.iter().copied()).collect()
+    }
+}
+
+/// State of the DSATUR branch and bound.
+struct ColoringSearch<'a> {
+    neighbors: &'a [Vec<usize>],
+    best: Vec<usize>,
+    best_count: usize,
+    /// The size of a clique, which no coloring can use fewer colors than.
+    lower_bound: usize,
+    colors: Vec<usize>,
+    /// `counts[v][c]` is the number of neighbors of `v` with color `c`.
+    counts: Vec<Vec<usize>>,
+    /// The number of distinct colors among the neighbors of every node.
+    saturation: Vec<usize>,
+    /// The number of branches left, if limited.
+    branches: Option<usize>,
+    /// Whether the search was stopped by the limit on the number of branches.
+    exhausted: bool,
+}
+
+impl ColoringSearch<'_> {
+    /// Extend the current partial coloring, in which `colored` nodes use `used` colors.
+    fn expand(&mut self, colored: usize, used: usize) {
+        if let Some(branches) = &mut self.branches {
+            if *branches == 0 {
+                self.exhausted = true;
+                return;
+            }
+            *branches -= 1;
+        }
+        if colored == self.colors.len() {
+            self.best.clone_from(&self.colors);
+            self.best_count = used;
+            return;
+        }
+
+        // The uncolored node with the most distinct colors around it, then the largest degree.
+        let v = (0..self.colors.len())
+            .filter(|&v| self.colors[v] == usize::MAX)
+            .max_by_key(|&v| (self.saturation[v], self.neighbors[v].len(), Reverse(v)))
+            .unwrap();
+        for color in 0..=used {
+            // A new color is only worth trying if the coloring can still beat the best one.
+            if color == used && used + 1 >= self.best_count {
+                break;
+            }
+            if color < used && self.counts[v][color] > 0 {
+                continue;
+            }
+            self.set(v, color);
+            self.expand(colored + 1, used.max(color + 1));
+            self.unset(v, color);
+            // No coloring can use fewer colors than the partial one, or than the lower bound.
+            if self.exhausted || self.best_count <= used.max(self.lower_bound) {
+                return;
+            }
+        }
+    }
+
+    fn set(&mut self, v: usize, color: usize) {
+        self.colors[v] = color;
+        for &u in self.neighbors[v].iter() {
+            if self.counts[u][color] == 0 {
+                self.saturation[u] += 1;
+            }
+            self.counts[u][color] += 1;
+        }
+    }
+
+    fn unset(&mut self, v: usize, color: usize) {
+        self.colors[v] = usize::MAX;
+        for &u in self.neighbors[v].iter() {
+            self.counts[u][color] -= 1;
+            if self.counts[u][color] == 0 {
+                self.saturation[u] -= 1;
+            }
+        }
+    }
+}
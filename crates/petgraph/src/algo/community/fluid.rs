@@ -0,0 +1,145 @@
+use alloc::{vec, vec::Vec};
+
+use super::{NeighborCommunities, Network, into_labels, renumber, shuffled};
+use crate::{
+    rng::SplitMix64,
+    visit::{IntoEdgeReferences, IntoNodeIdentifiers, NodeCount, NodeIndexable},
+};
+
+/// Two total densities closer than this are considered equal.
+const DENSITY_TOLERANCE: f64 = 1e-4;
+
+/// [Asynchronous fluid communities][1] for community detection.
+///
+/// The algorithm finds exactly `k` communities, seen as fluids that expand and push each other
+/// through the graph. Every community starts at a random node and has a density equal to the
+/// inverse of its number of nodes. The nodes are then visited in a random order, and every node
+/// joins the community with the largest total density among itself and its neighbors, until no
+/// node changes its community or `max_iter` passes are done. Ties are broken in favor of the
+/// current community of the node, and otherwise at random.
+///
+/// The graph should be connected: the nodes of a connected component that does not contain any
+/// of the starting nodes are not reached and get the label `usize::MAX`. The edge weights are
+/// ignored.
+///
+/// The random choices are drawn from `seed`, so the same seed always gives the same partition.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+/// * `k`: the number of communities to find.
+/// * `max_iter`: the maximum number of passes over the nodes.
+/// * `seed`: the seed of the pseudo-random choices.
+///
+/// # Returns
+/// * `Vec<usize>`: the community of every node, indexed by [`NodeIndexable::to_index`]. The
+///   communities are numbered from zero, and indices that do not belong to a node are labeled
+///   `usize::MAX`.
+///
+/// # Panics
+/// * If `k` is zero or greater than the number of nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(p(|V| + |E|) + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **p** is the number of passes over the nodes, **|V|** the number of nodes and **|E|**
+/// the number of edges.
+///
+/// [1]: https://arxiv.org/abs/1703.09307
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::community::fluid_communities, graph::UnGraph};
+///
+/// // Two 4-cliques joined by an edge.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1),
+///     (0, 2),
+///     (0, 3),
+///     (1, 2),
+///     (1, 3),
+///     (2, 3),
+///     (4, 5),
+///     (4, 6),
+///     (4, 7),
+///     (5, 6),
+///     (5, 7),
+///     (6, 7),
+///     (3, 4),
+/// ]);
+/// let communities = fluid_communities(&graph, 2, 100, 3);
+/// assert!(communities[..4].iter().all(|&c| c == communities[0]));
+/// assert!(communities[4..].iter().all(|&c| c == communities[4]));
+/// assert_ne!(communities[0], communities[4]);
+/// ```
+#[track_caller]
+pub fn fluid_communities<G>(graph: G, k: usize, max_iter: usize, seed: u64) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+{
+    assert!(
+        k > 0 && k <= graph.node_count(),
+        "The number of communities should be between 1 and the number of nodes."
+    );
+    let (network, nodes) = Network::from_graph(graph, |_| 1.);
+    let n = network.node_count();
+    let mut rng = SplitMix64::new(seed);
+
+    let mut labels = vec![usize::MAX; n];
+    let mut sizes = vec![1usize; k];
+    let mut densities = vec![1.; k];
+    for (c, &node) in shuffled(n, &mut rng)[..k].iter().enumerate() {
+        labels[node] = c;
+    }
+
+    let mut totals = NeighborCommunities::new(k);
+    let mut best = Vec::new();
+    for _ in 0..max_iter {
+        let mut changed = false;
+        for node in shuffled(n, &mut rng) {
+            let current = labels[node];
+            if current != usize::MAX {
+                totals.add(current, densities[current]);
+            }
+            for &(other, _) in &network.neighbors[node] {
+                let c = labels[other];
+                if c != usize::MAX {
+                    totals.add(c, densities[c]);
+                }
+            }
+
+            let max = totals
+                .communities
+                .iter()
+                .map(|&c| totals.weight(c))
+                .fold(0., f64::max);
+            best.clear();
+            best.extend(
+                totals
+                    .communities
+                    .iter()
+                    .copied()
+                    .filter(|&c| max - totals.weight(c) < DENSITY_TOLERANCE),
+            );
+            totals.clear();
+
+            if best.is_empty() || best.contains(&current) {
+                continue;
+            }
+            let next = best[rng.gen_index(best.len())];
+            if current != usize::MAX {
+                sizes[current] -= 1;
+                densities[current] = 1. / sizes[current] as f64;
+            }
+            sizes[next] += 1;
+            densities[next] = 1. / sizes[next] as f64;
+            labels[node] = next;
+            changed = true;
+        }
+        if !changed {
+            break;
+        }
+    }
+    renumber(&mut labels);
+    into_labels(&labels, &nodes, graph.node_bound())
+}
@@ -0,0 +1,291 @@
+use alloc::{vec, vec::Vec};
+
+use super::{Network, into_labels, renumber, shuffled};
+use crate::{
+    rng::SplitMix64,
+    visit::{IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// Semi-synchronous [label propagation][1] for community detection.
+///
+/// Every node starts with its own label, and repeatedly adopts the label with the largest total
+/// edge weight among its neighbors, until every node has such a label. Ties are broken in favor of
+/// the current label of the node, and otherwise of the smallest label.
+///
+/// The nodes are first colored so that no two neighbors share a color, and all nodes of a color
+/// are updated at the same time. Unlike fully synchronous updates, which can oscillate forever,
+/// this always converges ([Cordasco and Gargano][2]). The result is deterministic, and
+/// [`parallel_label_propagation`] finds the same communities.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+/// * `edge_weight`: closure that returns the weight of a particular edge, which must be
+///   non-negative. Use `|_| 1.` for unweighted graphs.
+///
+/// # Returns
+/// * `Vec<usize>`: the community of every node, indexed by [`NodeIndexable::to_index`]. The
+///   communities are numbered from zero, and indices that do not belong to a node are labeled
+///   `usize::MAX`.
+///
+/// # Panics
+/// * If an edge weight is negative.
+///
+/// # Complexity
+/// * Time complexity: **O(p|E| log |V|)**, in practice close to linear.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **p** is the number of passes over the nodes, **|V|** the number of nodes and **|E|**
+/// the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Label_propagation_algorithm
+/// [2]: https://arxiv.org/abs/1103.4550
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::community::label_propagation, graph::UnGraph};
+///
+/// // Two 4-cliques joined by an edge.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1),
+///     (0, 2),
+///     (0, 3),
+///     (1, 2),
+///     (1, 3),
+///     (2, 3),
+///     (4, 5),
+///     (4, 6),
+///     (4, 7),
+///     (5, 6),
+///     (5, 7),
+///     (6, 7),
+///     (3, 4),
+/// ]);
+/// let communities = label_propagation(&graph, |_| 1.);
+/// assert_eq!(communities, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+/// ```
+#[track_caller]
+pub fn label_propagation<G, F>(graph: G, edge_weight: F) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (network, nodes) = Network::from_graph(graph, edge_weight);
+    let mut weights = Vec::new();
+    let mut best = Vec::new();
+    let mut labels = semi_synchronous(&network, |class, labels| {
+        class
+            .iter()
+            .filter_map(|&node| {
+                heaviest_labels(&network, node, labels, &mut weights, &mut best);
+                updated_label(labels[node], &best).map(|label| (node, label))
+            })
+            .collect()
+    });
+    renumber(&mut labels);
+    into_labels(&labels, &nodes, graph.node_bound())
+}
+
+/// Parallel semi-synchronous label propagation.
+///
+/// The nodes of every color are updated in parallel, and the communities are the same as those
+/// found by [`label_propagation`].
+///
+/// See [`label_propagation`].
+#[cfg(feature = "rayon")]
+#[track_caller]
+pub fn parallel_label_propagation<G, F>(graph: G, edge_weight: F) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    use rayon::prelude::*;
+
+    let (network, nodes) = Network::from_graph(graph, edge_weight);
+    let mut labels = semi_synchronous(&network, |class, labels| {
+        class
+            .par_iter()
+            .map_init(
+                || (Vec::new(), Vec::new()),
+                |(weights, best), &node| {
+                    heaviest_labels(&network, node, labels, weights, best);
+                    updated_label(labels[node], best).map(|label| (node, label))
+                },
+            )
+            .flatten()
+            .collect()
+    });
+    renumber(&mut labels);
+    into_labels(&labels, &nodes, graph.node_bound())
+}
+
+/// Asynchronous [label propagation][1] for community detection.
+///
+/// Every node starts with its own label. The nodes are then visited in a random order, and every
+/// node adopts the label with the largest total edge weight among its neighbors, until every
+/// node has such a label. Ties are broken in favor of the current label of the node, and
+/// otherwise at random.
+///
+/// The random choices are drawn from `seed`, so the same seed always gives the same partition.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+/// * `edge_weight`: closure that returns the weight of a particular edge, which must be
+///   non-negative. Use `|_| 1.` for unweighted graphs.
+/// * `seed`: the seed of the pseudo-random choices.
+///
+/// # Returns
+/// * `Vec<usize>`: the community of every node, indexed by [`NodeIndexable::to_index`]. The
+///   communities are numbered from zero, and indices that do not belong to a node are labeled
+///   `usize::MAX`.
+///
+/// # Panics
+/// * If an edge weight is negative.
+///
+/// # Complexity
+/// * Time complexity: **O(p|E| log |V|)**, in practice close to linear.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **p** is the number of passes over the nodes, **|V|** the number of nodes and **|E|**
+/// the number of edges.
+///
+/// [1]: https://arxiv.org/abs/0709.2938
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::community::asynchronous_label_propagation, graph::UnGraph};
+///
+/// // Two 4-cliques joined by an edge.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1),
+///     (0, 2),
+///     (0, 3),
+///     (1, 2),
+///     (1, 3),
+///     (2, 3),
+///     (4, 5),
+///     (4, 6),
+///     (4, 7),
+///     (5, 6),
+///     (5, 7),
+///     (6, 7),
+///     (3, 4),
+/// ]);
+/// let communities = asynchronous_label_propagation(&graph, |_| 1., 1);
+/// assert_eq!(communities, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+/// ```
+#[track_caller]
+pub fn asynchronous_label_propagation<G, F>(graph: G, edge_weight: F, seed: u64) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (network, nodes) = Network::from_graph(graph, edge_weight);
+    let mut rng = SplitMix64::new(seed);
+    let mut labels: Vec<usize> = (0..network.node_count()).collect();
+    let mut weights = Vec::new();
+    let mut best = Vec::new();
+    loop {
+        let mut changed = false;
+        for node in shuffled(network.node_count(), &mut rng) {
+            heaviest_labels(&network, node, &labels, &mut weights, &mut best);
+            if !best.is_empty() && best.binary_search(&labels[node]).is_err() {
+                labels[node] = best[rng.gen_index(best.len())];
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    renumber(&mut labels);
+    into_labels(&labels, &nodes, graph.node_bound())
+}
+
+/// Run semi-synchronous label propagation on `network`, where `update` returns the nodes of a
+/// color class whose label changes, with their new label.
+fn semi_synchronous<U>(network: &Network, mut update: U) -> Vec<usize>
+where
+    U: FnMut(&[usize], &[usize]) -> Vec<(usize, usize)>,
+{
+    let n = network.node_count();
+
+    // Greedy coloring: no two nodes of a class are neighbors.
+    let mut colors = vec![usize::MAX; n];
+    let mut classes: Vec<Vec<usize>> = Vec::new();
+    let mut used = Vec::new();
+    for node in 0..n {
+        used.clear();
+        used.extend(
+            network.neighbors[node]
+                .iter()
+                .map(|&(other, _)| colors[other])
+                .filter(|&c| c != usize::MAX),
+        );
+        used.sort_unstable();
+        used.dedup();
+        let color = used
+            .iter()
+            .enumerate()
+            .find(|&(i, &c)| i != c)
+            .map_or(used.len(), |(i, _)| i);
+        if color == classes.len() {
+            classes.push(Vec::new());
+        }
+        classes[color].push(node);
+        colors[node] = color;
+    }
+
+    let mut labels: Vec<usize> = (0..n).collect();
+    loop {
+        let mut changed = false;
+        for class in &classes {
+            let changes = update(class, &labels);
+            changed |= !changes.is_empty();
+            for (node, label) in changes {
+                labels[node] = label;
+            }
+        }
+        if !changed {
+            return labels;
+        }
+    }
+}
+
+/// Collect into `best` the labels with the largest positive total weight among the neighbors of
+/// `node`, in increasing order, using `weights` as scratch space.
+fn heaviest_labels(
+    network: &Network,
+    node: usize,
+    labels: &[usize],
+    weights: &mut Vec<(usize, f64)>,
+    best: &mut Vec<usize>,
+) {
+    weights.clear();
+    weights.extend(
+        network.neighbors[node]
+            .iter()
+            .map(|&(other, weight)| (labels[other], weight)),
+    );
+    weights.sort_unstable_by_key(|&(label, _)| label);
+
+    best.clear();
+    let mut max = 0.;
+    for group in weights.chunk_by(|a, b| a.0 == b.0) {
+        let total: f64 = group.iter().map(|&(_, weight)| weight).sum();
+        if total > max {
+            max = total;
+            best.clear();
+            best.push(group[0].0);
+        } else if total == max && max > 0. {
+            best.push(group[0].0);
+        }
+    }
+}
+
+/// The new label of a node with label `current`, if it changes.
+fn updated_label(current: usize, best: &[usize]) -> Option<usize> {
+    match best.first() {
+        Some(&label) if best.binary_search(&current).is_err() => Some(label),
+        _ => None,
+    }
+}
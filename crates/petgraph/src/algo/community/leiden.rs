@@ -0,0 +1,280 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use super::{MIN_GAIN, NeighborCommunities, Network, into_labels, renumber, shuffled};
+use crate::{
+    rng::SplitMix64,
+    visit::{IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// [Leiden algorithm][1] for community detection.
+///
+/// Like [`louvain`](super::louvain()), the algorithm moves single nodes to the neighboring
+/// community that increases the [modularity](super::modularity()) the most, and then merges
+/// communities into single nodes. Before merging, it refines every community by splitting it
+/// into well-connected subcommunities, and only merges those. This guarantees that every
+/// community found is connected, and usually gives a partition of higher modularity.
+///
+/// The refinement merges every node into the subcommunity that increases the modularity the
+/// most, rather than into a random one as in the original algorithm. The nodes are visited in a
+/// random order drawn from `seed`, so the same seed always gives the same partition.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+/// * `edge_weight`: closure that returns the weight of a particular edge, which must be
+///   non-negative. Use `|_| 1.` for unweighted graphs.
+/// * `resolution`: the resolution `γ` of the modularity, usually 1.
+/// * `seed`: the seed of the pseudo-random order in which the nodes are visited.
+///
+/// # Returns
+/// * `Vec<usize>`: the community of every node, indexed by [`NodeIndexable::to_index`]. The
+///   communities are numbered from zero, and indices that do not belong to a node are labeled
+///   `usize::MAX`.
+///
+/// # Panics
+/// * If an edge weight is negative.
+///
+/// # Complexity
+/// * Time complexity: **O(p(|V| + |E|) + |E| log |E|)** per level, in practice close to linear.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **p** is the number of times a node is visited, **|V|** the number of nodes and **|E|**
+/// the number of edges.
+///
+/// [1]: https://doi.org/10.1038/s41598-019-41695-z
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::community::{leiden, modularity},
+///     graph::UnGraph,
+/// };
+///
+/// // Three 4-cliques, joined in a ring by single edges.
+/// let mut edges = Vec::new();
+/// for clique in 0..3 {
+///     let first = 4 * clique;
+///     for a in first..first + 4 {
+///         for b in a + 1..first + 4 {
+///             edges.push((a, b));
+///         }
+///     }
+///     edges.push((first + 3, (first + 4) % 12));
+/// }
+/// let graph = UnGraph::<(), ()>::from_edges(edges);
+///
+/// let communities = leiden(&graph, |_| 1., 1., 7);
+/// for node in 0..12 {
+///     assert_eq!(communities[node], communities[node - node % 4]);
+/// }
+/// assert!(modularity(&graph, |_| 1., &communities, 1.) > 0.5);
+/// ```
+#[track_caller]
+pub fn leiden<G, F>(graph: G, edge_weight: F, resolution: f64, seed: u64) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (mut network, nodes) = Network::from_graph(graph, edge_weight);
+    let mut rng = SplitMix64::new(seed);
+    // The node of the current network that every node of the graph has been merged into.
+    let mut labels: Vec<usize> = (0..nodes.len()).collect();
+    // The community of every node of the current network.
+    let mut communities: Vec<usize> = labels.clone();
+    loop {
+        move_nodes_fast(&network, &mut communities, resolution, &mut rng);
+        let count = renumber(&mut communities);
+        if count == network.node_count() {
+            break;
+        }
+
+        let mut refined = refine(&network, &communities, count, resolution, &mut rng);
+        let mut refined_count = renumber(&mut refined);
+        if refined_count == network.node_count() {
+            // Nothing could be merged; merge the connected parts of the communities instead,
+            // or stop if the communities have none.
+            refined = connected_parts(&network, &communities);
+            refined_count = renumber(&mut refined);
+            if refined_count == network.node_count() {
+                communities = refined;
+                break;
+            }
+        }
+
+        let mut aggregate_communities = vec![0; refined_count];
+        for (node, &r) in refined.iter().enumerate() {
+            aggregate_communities[r] = communities[node];
+        }
+        for label in &mut labels {
+            *label = refined[*label];
+        }
+        network = network.aggregate(&refined, refined_count);
+        communities = aggregate_communities;
+    }
+
+    let mut result: Vec<usize> = labels.iter().map(|&node| communities[node]).collect();
+    renumber(&mut result);
+    into_labels(&result, &nodes, graph.node_bound())
+}
+
+/// Split every community into the connected components of the subgraph that it induces. Return
+/// the component of every node.
+fn connected_parts(network: &Network, communities: &[usize]) -> Vec<usize> {
+    let n = network.node_count();
+    let mut parts = vec![usize::MAX; n];
+    let mut stack = Vec::new();
+    let mut count = 0;
+    for start in 0..n {
+        if parts[start] != usize::MAX {
+            continue;
+        }
+        parts[start] = count;
+        stack.push(start);
+        while let Some(node) = stack.pop() {
+            for &(other, _) in &network.neighbors[node] {
+                if parts[other] == usize::MAX && communities[other] == communities[node] {
+                    parts[other] = count;
+                    stack.push(other);
+                }
+            }
+        }
+        count += 1;
+    }
+    parts
+}
+
+/// Move single nodes to the community that increases the modularity the most, possibly a new
+/// empty one, revisiting only the neighbors of the nodes that moved.
+fn move_nodes_fast(
+    network: &Network,
+    communities: &mut [usize],
+    resolution: f64,
+    rng: &mut SplitMix64,
+) {
+    let two_m = 2. * network.total_weight;
+    if two_m == 0. {
+        return;
+    }
+    let min_gain = MIN_GAIN * network.total_weight;
+    let n = network.node_count();
+    let mut totals = vec![0.; n];
+    let mut sizes = vec![0usize; n];
+    for (node, &c) in communities.iter().enumerate() {
+        totals[c] += network.degrees[node];
+        sizes[c] += 1;
+    }
+    let mut empty: Vec<usize> = (0..n).filter(|&c| sizes[c] == 0).collect();
+    let mut neighbors = NeighborCommunities::new(n);
+    let mut queue: VecDeque<usize> = shuffled(n, rng).into();
+    let mut queued = vec![true; n];
+
+    while let Some(node) = queue.pop_front() {
+        queued[node] = false;
+        let current = communities[node];
+        let degree = network.degrees[node];
+        neighbors.collect(network, node, communities, |_| true);
+        totals[current] -= degree;
+        sizes[current] -= 1;
+        if sizes[current] == 0 {
+            empty.push(current);
+        }
+
+        let gain = |c: usize| neighbors.weight(c) - resolution * totals[c] * degree / two_m;
+        let mut best = current;
+        let mut best_gain = gain(current);
+        let candidates = neighbors.communities.iter().chain(empty.last());
+        for &c in candidates {
+            let g = gain(c);
+            if g > best_gain + min_gain {
+                best = c;
+                best_gain = g;
+            }
+        }
+
+        if sizes[best] == 0 {
+            empty.pop();
+        }
+        totals[best] += degree;
+        sizes[best] += 1;
+        communities[node] = best;
+        neighbors.clear();
+
+        if best != current {
+            for &(other, _) in &network.neighbors[node] {
+                if !queued[other] && communities[other] != best {
+                    queued[other] = true;
+                    queue.push_back(other);
+                }
+            }
+        }
+    }
+}
+
+/// Split every community into well-connected subcommunities, by merging singletons into the
+/// subcommunity of the same community that increases the modularity the most. Return the
+/// subcommunity of every node.
+fn refine(
+    network: &Network,
+    communities: &[usize],
+    count: usize,
+    resolution: f64,
+    rng: &mut SplitMix64,
+) -> Vec<usize> {
+    let two_m = 2. * network.total_weight;
+    let min_gain = MIN_GAIN * network.total_weight;
+    let n = network.node_count();
+    let mut community_totals = vec![0.; count];
+    for (node, &c) in communities.iter().enumerate() {
+        community_totals[c] += network.degrees[node];
+    }
+
+    let mut refined: Vec<usize> = (0..n).collect();
+    let mut totals = network.degrees.clone();
+    let mut sizes = vec![1usize; n];
+    // The weight of the edges between every subcommunity and the rest of its community.
+    let mut external: Vec<f64> = (0..n)
+        .map(|node| {
+            network.neighbors[node]
+                .iter()
+                .filter(|&&(other, _)| communities[other] == communities[node])
+                .map(|&(_, weight)| weight)
+                .sum()
+        })
+        .collect();
+    let well_connected = |external: f64, total: f64, community_total: f64| {
+        external >= resolution * total * (community_total - total) / two_m
+    };
+
+    let mut neighbors = NeighborCommunities::new(n);
+    for node in shuffled(n, rng) {
+        let current = refined[node];
+        let c = communities[node];
+        let degree = network.degrees[node];
+        if sizes[current] > 1 || !well_connected(external[current], degree, community_totals[c]) {
+            continue;
+        }
+
+        neighbors.collect(network, node, &refined, |other| communities[other] == c);
+        let mut best = current;
+        let mut best_gain = 0.;
+        for &r in &neighbors.communities {
+            if !well_connected(external[r], totals[r], community_totals[c]) {
+                continue;
+            }
+            let gain = neighbors.weight(r) - resolution * totals[r] * degree / two_m;
+            if gain > best_gain + min_gain {
+                best = r;
+                best_gain = gain;
+            }
+        }
+
+        if best != current {
+            external[best] += external[current] - 2. * neighbors.weight(best);
+            totals[best] += degree;
+            sizes[best] += 1;
+            sizes[current] = 0;
+            refined[node] = best;
+        }
+        neighbors.clear();
+    }
+    refined
+}
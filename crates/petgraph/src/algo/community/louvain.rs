@@ -0,0 +1,132 @@
+use alloc::{vec, vec::Vec};
+
+use super::{MIN_GAIN, NeighborCommunities, Network, into_labels, renumber, shuffled};
+use crate::{
+    rng::SplitMix64,
+    visit::{IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// [Louvain method][1] for community detection.
+///
+/// Starting with every node in its own community, the algorithm repeatedly moves single nodes to
+/// the neighboring community that increases the [modularity](super::modularity()) the most, until
+/// no move improves it. It then merges every community into a single node and starts over on the
+/// smaller graph, until the partition does not change anymore.
+///
+/// The nodes are visited in a random order drawn from `seed`, so the same seed always gives the
+/// same partition. The communities found are not guaranteed to be connected; use
+/// [`leiden`](super::leiden()) if they need to be.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+/// * `edge_weight`: closure that returns the weight of a particular edge, which must be
+///   non-negative. Use `|_| 1.` for unweighted graphs.
+/// * `resolution`: the resolution `γ` of the modularity, usually 1.
+/// * `seed`: the seed of the pseudo-random order in which the nodes are visited.
+///
+/// # Returns
+/// * `Vec<usize>`: the community of every node, indexed by [`NodeIndexable::to_index`]. The
+///   communities are numbered from zero, and indices that do not belong to a node are labeled
+///   `usize::MAX`.
+///
+/// # Panics
+/// * If an edge weight is negative.
+///
+/// # Complexity
+/// * Time complexity: **O(p(|V| + |E|) + |E| log |E|)** per level, in practice close to linear.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **p** is the number of passes over the nodes, **|V|** the number of nodes and **|E|**
+/// the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Louvain_method
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::community::louvain, graph::UnGraph};
+///
+/// // Two triangles joined by an edge.
+/// let graph =
+///     UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)]);
+/// let communities = louvain(&graph, |_| 1., 1., 42);
+/// assert_eq!(communities[0], communities[1]);
+/// assert_eq!(communities[0], communities[2]);
+/// assert_eq!(communities[3], communities[4]);
+/// assert_eq!(communities[3], communities[5]);
+/// assert_ne!(communities[0], communities[3]);
+/// ```
+#[track_caller]
+pub fn louvain<G, F>(graph: G, edge_weight: F, resolution: f64, seed: u64) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (mut network, nodes) = Network::from_graph(graph, edge_weight);
+    let mut rng = SplitMix64::new(seed);
+    // The node of the current network that every node of the graph has been merged into.
+    let mut labels: Vec<usize> = (0..nodes.len()).collect();
+    loop {
+        let mut communities: Vec<usize> = (0..network.node_count()).collect();
+        if !move_nodes(&network, &mut communities, resolution, &mut rng) {
+            break;
+        }
+        let count = renumber(&mut communities);
+        for label in &mut labels {
+            *label = communities[*label];
+        }
+        network = network.aggregate(&communities, count);
+    }
+    into_labels(&labels, &nodes, graph.node_bound())
+}
+
+/// Move single nodes between communities as long as it increases the modularity, and return
+/// `true` if any node moved.
+fn move_nodes(
+    network: &Network,
+    communities: &mut [usize],
+    resolution: f64,
+    rng: &mut SplitMix64,
+) -> bool {
+    let two_m = 2. * network.total_weight;
+    if two_m == 0. {
+        return false;
+    }
+    let min_gain = MIN_GAIN * network.total_weight;
+    let n = network.node_count();
+    let mut totals = vec![0.; n];
+    for (node, &c) in communities.iter().enumerate() {
+        totals[c] += network.degrees[node];
+    }
+    let mut neighbors = NeighborCommunities::new(n);
+    let order = shuffled(n, rng);
+    let mut improved = false;
+    loop {
+        let mut moved = false;
+        for &node in &order {
+            let current = communities[node];
+            let degree = network.degrees[node];
+            neighbors.collect(network, node, communities, |_| true);
+            totals[current] -= degree;
+
+            let gain = |c: usize| neighbors.weight(c) - resolution * totals[c] * degree / two_m;
+            let mut best = current;
+            let mut best_gain = gain(current);
+            for &c in &neighbors.communities {
+                let g = gain(c);
+                if g > best_gain + min_gain {
+                    best = c;
+                    best_gain = g;
+                }
+            }
+
+            totals[best] += degree;
+            communities[node] = best;
+            moved |= best != current;
+            neighbors.clear();
+        }
+        if !moved {
+            return improved;
+        }
+        improved = true;
+    }
+}
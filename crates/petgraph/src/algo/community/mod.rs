@@ -0,0 +1,325 @@
+//! Community detection: partitioning the nodes of a graph into densely connected groups.
+//!
+//! The [modularity] of a partition compares the weight of the edges inside every community with
+//! the weight expected if the edges were rewired at random while keeping the degrees of the nodes.
+//! [louvain] and [leiden] greedily search for a partition of high modularity. [leiden] is slower
+//! but guarantees that every community is connected, which [louvain] does not.
+//!
+//! For graphs too large for these, [label_propagation], [asynchronous_label_propagation] and
+//! [fluid_communities] run in near-linear time, but do not optimize any global measure.
+//!
+//! All algorithms treat the graph as undirected and, except [fluid_communities], weighted, with
+//! the weights given by a closure that must return non-negative values. The randomized
+//! algorithms take a `seed`, and always give the same result for the same seed.
+//!
+//! A partition is represented by a label per node, indexed by [`NodeIndexable::to_index`], like
+//! [`UnionFind::into_labeling`]: two nodes are in the same community if and only if they have the
+//! same label.
+//!
+//! The `resolution` parameter `γ` of the modularity controls the size of the communities: values
+//! greater than 1 favor smaller communities, values less than 1 favor larger ones.
+//!
+//! [`UnionFind::into_labeling`]: crate::unionfind::UnionFind::into_labeling
+
+mod fluid;
+mod label_propagation;
+mod leiden;
+mod louvain;
+
+use alloc::{vec, vec::Vec};
+
+pub use fluid::fluid_communities;
+use hashbrown::HashMap;
+#[cfg(feature = "rayon")]
+pub use label_propagation::parallel_label_propagation;
+pub use label_propagation::{asynchronous_label_propagation, label_propagation};
+pub use leiden::leiden;
+pub use louvain::louvain;
+
+use crate::{
+    rng::SplitMix64,
+    visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// Modularity of a partition of the nodes of a graph.
+///
+/// The modularity of a partition into communities `c` is
+///
+/// `Q = Σ_c (L_c / m - γ (d_c / 2m)²)`
+///
+/// where `m` is the total weight of the edges, `L_c` the total weight of the edges inside `c`,
+/// `d_c` the total weighted degree of the nodes of `c` (a self loop counts twice), and `γ` the
+/// resolution. The modularity of a graph without edges is zero.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+/// * `edge_weight`: closure that returns the weight of a particular edge, which must be
+///   non-negative. Use `|_| 1.` for unweighted graphs.
+/// * `communities`: the label of the community of every node, indexed by
+///   [`NodeIndexable::to_index`]. The entries of indices that do not belong to a node are ignored.
+/// * `resolution`: the resolution `γ`, usually 1.
+///
+/// # Returns
+/// * `f64`: the modularity of the partition, between -1/2 and 1 for `γ = 1`.
+///
+/// # Panics
+/// * If the length of `communities` is not [`NodeIndexable::node_bound`].
+/// * If an edge weight is negative.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::community::modularity, graph::UnGraph};
+///
+/// // Two triangles joined by an edge.
+/// let graph =
+///     UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)]);
+/// let q = modularity(&graph, |_| 1., &[0, 0, 0, 1, 1, 1], 1.);
+/// assert!((q - 5. / 14.).abs() < 1e-12);
+///
+/// // A single community has a modularity of zero.
+/// assert_eq!(modularity(&graph, |_| 1., &[0; 6], 1.), 0.);
+/// ```
+#[track_caller]
+pub fn modularity<G, F>(graph: G, edge_weight: F, communities: &[usize], resolution: f64) -> f64
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    assert_eq!(
+        communities.len(),
+        graph.node_bound(),
+        "There should be one community label per node index."
+    );
+    let (network, nodes) = Network::from_graph(graph, edge_weight);
+    let mut ids = HashMap::new();
+    let labels: Vec<usize> = nodes
+        .iter()
+        .map(|&i| {
+            let next = ids.len();
+            *ids.entry(communities[i]).or_insert(next)
+        })
+        .collect();
+    network.modularity(&labels, ids.len(), resolution)
+}
+
+/// An undirected weighted graph with nodes `0..n`, in which the nodes of the input graph or
+/// whole communities are merged.
+#[derive(Clone, Debug)]
+struct Network {
+    /// The other endpoints of the edges of every node, with the total weight of the edges.
+    neighbors: Vec<Vec<(usize, f64)>>,
+    /// The total weight of the self loops of every node.
+    self_loops: Vec<f64>,
+    /// The weighted degree of every node, where a self loop counts twice.
+    degrees: Vec<f64>,
+    /// The total weight of the edges.
+    total_weight: f64,
+}
+
+impl Network {
+    /// Build the network of `graph`, and return it along with the index of the node of `graph`
+    /// that every node of the network stands for.
+    #[track_caller]
+    fn from_graph<G, F>(graph: G, mut edge_weight: F) -> (Self, Vec<usize>)
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> f64,
+    {
+        let nodes: Vec<usize> = graph
+            .node_identifiers()
+            .map(|n| graph.to_index(n))
+            .collect();
+        let mut compact = vec![usize::MAX; graph.node_bound()];
+        for (i, &ix) in nodes.iter().enumerate() {
+            compact[ix] = i;
+        }
+        let edges = graph.edge_references().map(|edge| {
+            let weight = edge_weight(edge);
+            assert!(weight >= 0., "Edge weights should be non-negative.");
+            (
+                compact[graph.to_index(edge.source())],
+                compact[graph.to_index(edge.target())],
+                weight,
+            )
+        });
+        (Self::from_edges(nodes.len(), edges), nodes)
+    }
+
+    /// Build a network with `n` nodes from a list of weighted edges, merging parallel edges.
+    fn from_edges(n: usize, edges: impl Iterator<Item = (usize, usize, f64)>) -> Self {
+        let mut neighbors = vec![Vec::new(); n];
+        let mut self_loops = vec![0.; n];
+        let mut degrees = vec![0.; n];
+        let mut total_weight = 0.;
+        for (a, b, weight) in edges {
+            if a == b {
+                self_loops[a] += weight;
+            } else {
+                neighbors[a].push((b, weight));
+                neighbors[b].push((a, weight));
+            }
+            degrees[a] += weight;
+            degrees[b] += weight;
+            total_weight += weight;
+        }
+        for list in &mut neighbors {
+            list.sort_unstable_by_key(|&(b, _)| b);
+            list.dedup_by(|(b, w), (a, v)| {
+                let same = a == b;
+                if same {
+                    *v += *w;
+                }
+                same
+            });
+        }
+        Network {
+            neighbors,
+            self_loops,
+            degrees,
+            total_weight,
+        }
+    }
+
+    fn node_count(&self) -> usize {
+        self.degrees.len()
+    }
+
+    /// Merge the nodes of every community of `labels`, numbered `0..count`, into a single node.
+    fn aggregate(&self, labels: &[usize], count: usize) -> Self {
+        let mut edges = Vec::new();
+        for (a, list) in self.neighbors.iter().enumerate() {
+            edges.push((labels[a], labels[a], self.self_loops[a]));
+            // Every edge appears in the lists of both endpoints.
+            edges.extend(
+                list.iter()
+                    .filter(|&&(b, _)| a < b)
+                    .map(|&(b, weight)| (labels[a], labels[b], weight)),
+            );
+        }
+        Self::from_edges(count, edges.into_iter())
+    }
+
+    /// Modularity of the partition `labels`, numbered `0..count`.
+    fn modularity(&self, labels: &[usize], count: usize, resolution: f64) -> f64 {
+        if self.total_weight == 0. {
+            return 0.;
+        }
+        let mut inner = vec![0.; count];
+        let mut totals = vec![0.; count];
+        for (a, list) in self.neighbors.iter().enumerate() {
+            let c = labels[a];
+            inner[c] += self.self_loops[a];
+            totals[c] += self.degrees[a];
+            for &(b, weight) in list {
+                if labels[b] == c {
+                    // Counted once from each endpoint.
+                    inner[c] += weight / 2.;
+                }
+            }
+        }
+        let m = self.total_weight;
+        inner
+            .iter()
+            .zip(&totals)
+            .map(|(&l, &d)| l / m - resolution * (d / (2. * m)) * (d / (2. * m)))
+            .sum()
+    }
+}
+
+/// The weights of the edges between a node and each of the neighboring communities.
+#[derive(Clone, Debug)]
+struct NeighborCommunities {
+    weights: Vec<f64>,
+    seen: Vec<bool>,
+    communities: Vec<usize>,
+}
+
+impl NeighborCommunities {
+    fn new(n: usize) -> Self {
+        NeighborCommunities {
+            weights: vec![0.; n],
+            seen: vec![false; n],
+            communities: Vec::new(),
+        }
+    }
+
+    /// Collect the communities of the neighbors of `node` for which `include` returns `true`.
+    fn collect(
+        &mut self,
+        network: &Network,
+        node: usize,
+        labels: &[usize],
+        mut include: impl FnMut(usize) -> bool,
+    ) {
+        for &(other, weight) in &network.neighbors[node] {
+            if !include(other) {
+                continue;
+            }
+            self.add(labels[other], weight);
+        }
+    }
+
+    /// Add `weight` to the total weight of the edges to community `c`.
+    fn add(&mut self, c: usize, weight: f64) {
+        if !self.seen[c] {
+            self.seen[c] = true;
+            self.communities.push(c);
+        }
+        self.weights[c] += weight;
+    }
+
+    /// The total weight of the edges to community `c`.
+    fn weight(&self, c: usize) -> f64 {
+        self.weights[c]
+    }
+
+    fn clear(&mut self) {
+        for &c in &self.communities {
+            self.weights[c] = 0.;
+            self.seen[c] = false;
+        }
+        self.communities.clear();
+    }
+}
+
+/// Moving a node only counts as an improvement if its gain exceeds this fraction of the total
+/// weight, so that rounding errors cannot make nodes move back and forth forever.
+const MIN_GAIN: f64 = 1e-12;
+
+/// Renumber `labels` to `0..count` in order of first appearance, and return `count`. Labels
+/// equal to `usize::MAX` are left unchanged.
+fn renumber(labels: &mut [usize]) -> usize {
+    let mut ids = vec![usize::MAX; labels.len()];
+    let mut count = 0;
+    for label in labels.iter_mut().filter(|label| **label != usize::MAX) {
+        if ids[*label] == usize::MAX {
+            ids[*label] = count;
+            count += 1;
+        }
+        *label = ids[*label];
+    }
+    count
+}
+
+/// Return `0..n` in a random order.
+fn shuffled(n: usize, rng: &mut SplitMix64) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut order);
+    order
+}
+
+/// Map the community of every node of the network back to the index of the node in the graph,
+/// with `usize::MAX` at indices that do not belong to a node.
+fn into_labels(labels: &[usize], nodes: &[usize], node_bound: usize) -> Vec<usize> {
+    let mut result = vec![usize::MAX; node_bound];
+    for (&ix, &label) in nodes.iter().zip(labels) {
+        result[ix] = label;
+    }
+    result
+}
@@ -0,0 +1,242 @@
+//! Longest paths and [critical path analysis][1] of directed acyclic graphs.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Critical_path_method
+
+use alloc::{vec, vec::Vec};
+use core::ops::Sub;
+
+use super::{Cycle, Measure, toposort};
+use crate::{
+    acyclic::Acyclic,
+    visit::{
+        EdgeIndexable, EdgeRef, GraphBase, IntoEdgeReferences, IntoEdges, IntoNeighborsDirected,
+        IntoNodeIdentifiers, NodeIndexable, Visitable,
+    },
+};
+
+/// The result of a critical path analysis, where the nodes of a directed acyclic graph are events,
+/// such as the start or the end of tasks, and every edge is a duration that must pass between its
+/// source and its target.
+///
+/// Created with [`dag_critical_path`] or [`acyclic_critical_path`]. The vectors of node times are
+/// indexed by [`NodeIndexable::to_index`], and the vector of edge slacks by
+/// [`EdgeIndexable::to_index`]; the entries of indices without a node or an edge are
+/// `K::default()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CriticalPath<N, K> {
+    /// The length of a longest path, which is the duration of the whole project.
+    pub length: K,
+    /// The nodes of a longest path, from a source to a sink. Empty if the graph has no node.
+    pub path: Vec<N>,
+    /// The earliest time of every node: the length of a longest path from a source that ends at
+    /// it, since the project starts at `K::default()`.
+    pub earliest: Vec<K>,
+    /// The latest time of every node: the latest time at which it can happen without delaying
+    /// the end of the project.
+    pub latest: Vec<K>,
+    /// The slack, or float, of every node: its latest time minus its earliest time. The nodes of
+    /// the critical paths have no slack.
+    pub node_slack: Vec<K>,
+    /// The slack of every edge: the latest time of its target minus the earliest time of its
+    /// source and its duration.
+    pub edge_slack: Vec<K>,
+}
+
+/// Compute a longest path of a directed acyclic graph, with the earliest and the latest time and
+/// the slack of every node and edge.
+///
+/// The graph is sorted topologically first, see [`acyclic_critical_path`] for graphs that already
+/// maintain a topological order. The project starts at `K::default()` at every node without
+/// predecessors, and ends at the latest earliest time of the nodes without successors. Edge costs
+/// may be zero, for dummy activities and milestones, or negative.
+///
+/// # Arguments
+/// * `graph`: a directed acyclic graph.
+/// * `edge_cost`: closure that returns the duration of an edge, called once per edge.
+///
+/// # Returns
+/// * `Ok`: [`CriticalPath`], the longest path and the times of every node and edge.
+/// * `Err`: [`Cycle`] if the graph was not acyclic.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::dag_critical_path, graph::DiGraph, visit::EdgeRef};
+///
+/// // The events of a project, and the tasks between them with their durations in days.
+/// let mut graph = DiGraph::<&str, u32>::new();
+/// let start = graph.add_node("start");
+/// let walls = graph.add_node("walls");
+/// let roof = graph.add_node("roof");
+/// let wiring = graph.add_node("wiring");
+/// let end = graph.add_node("end");
+/// graph.extend_with_edges([
+///     (start, walls, 10),
+///     (walls, roof, 5),
+///     (walls, wiring, 3),
+///     (roof, end, 4),
+///     (wiring, end, 2),
+/// ]);
+///
+/// let cpm = dag_critical_path(&graph, |e| *e.weight()).unwrap();
+/// assert_eq!(cpm.length, 19);
+/// assert_eq!(cpm.path, [start, walls, roof, end]);
+/// // The wiring can start at day 13 at the earliest and end at day 17 at the latest.
+/// assert_eq!(cpm.earliest[wiring.index()], 13);
+/// assert_eq!(cpm.latest[wiring.index()], 17);
+/// assert_eq!(cpm.node_slack[wiring.index()], 4);
+/// assert_eq!(cpm.node_slack[roof.index()], 0);
+/// ```
+pub fn dag_critical_path<G, F, K>(
+    graph: G,
+    edge_cost: F,
+) -> Result<CriticalPath<G::NodeId, K>, Cycle<G::NodeId>>
+where
+    G: IntoEdges + IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
+    G: NodeIndexable + EdgeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let order = toposort(graph, None)?;
+    Ok(critical_path(graph, &order, edge_cost))
+}
+
+/// Compute a longest path of an [`Acyclic`] graph, with the earliest and the latest time and the
+/// slack of every node and edge.
+///
+/// Same as [`dag_critical_path`], but uses the topological order that the graph maintains instead
+/// of sorting it, and cannot fail.
+///
+/// # Arguments
+/// * `graph`: an acyclic graph.
+/// * `edge_cost`: closure that returns the duration of an edge, called once per edge.
+///
+/// # Returns
+/// * [`CriticalPath`]: the longest path and the times of every node and edge.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     acyclic::Acyclic, algo::acyclic_critical_path, data::Build, graph::DiGraph, visit::EdgeRef,
+/// };
+///
+/// let mut graph: Acyclic<DiGraph<(), i32>> = Acyclic::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.try_add_edge(a, b, 2).unwrap();
+/// graph.try_add_edge(b, c, 3).unwrap();
+/// graph.try_add_edge(a, c, 4).unwrap();
+///
+/// let cpm = acyclic_critical_path(&graph, |e| *e.weight());
+/// assert_eq!(cpm.length, 5);
+/// assert_eq!(cpm.path, [a, b, c]);
+/// assert_eq!(cpm.edge_slack, [0, 0, 1]);
+/// ```
+pub fn acyclic_critical_path<'a, G, F, K>(
+    graph: &'a Acyclic<G>,
+    edge_cost: F,
+) -> CriticalPath<G::NodeId, K>
+where
+    G: Visitable,
+    &'a Acyclic<G>: IntoEdges + NodeIndexable + EdgeIndexable + GraphBase<NodeId = G::NodeId>,
+    F: FnMut(<&'a Acyclic<G> as IntoEdgeReferences>::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let order: Vec<_> = graph.nodes_iter().collect();
+    critical_path(graph, &order, edge_cost)
+}
+
+/// Critical path analysis over the nodes of the graph in topological order.
+fn critical_path<G, F, K>(
+    graph: G,
+    order: &[G::NodeId],
+    mut edge_cost: F,
+) -> CriticalPath<G::NodeId, K>
+where
+    G: IntoEdges + NodeIndexable + EdgeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let ix = |a| NodeIndexable::to_index(&graph, a);
+    let zero = K::default();
+    let mut earliest = vec![zero; graph.node_bound()];
+    let mut predecessors = vec![None; graph.node_bound()];
+    let mut reached = vec![false; graph.node_bound()];
+    let mut costs = vec![zero; EdgeIndexable::edge_bound(&graph)];
+
+    // Forward pass: the earliest time of a node is the latest arrival from its predecessors.
+    for &a in order {
+        let from = earliest[ix(a)];
+        for edge in graph.edges(a) {
+            let cost = edge_cost(edge);
+            costs[EdgeIndexable::to_index(&graph, edge.id())] = cost;
+            let b = ix(edge.target());
+            if !reached[b] || from + cost > earliest[b] {
+                reached[b] = true;
+                earliest[b] = from + cost;
+                predecessors[b] = Some(a);
+            }
+        }
+    }
+
+    // The project ends at the latest sink.
+    let mut end = None;
+    let mut length = zero;
+    for &a in order {
+        if graph.edges(a).next().is_some() {
+            continue;
+        }
+        if end.is_none() || earliest[ix(a)] > length {
+            end = Some(a);
+            length = earliest[ix(a)];
+        }
+    }
+    let mut path: Vec<_> = core::iter::successors(end, |&a| predecessors[ix(a)]).collect();
+    path.reverse();
+
+    // Backward pass: the latest time of a node is the earliest departure to its successors.
+    let mut latest = vec![zero; graph.node_bound()];
+    for &a in order.iter().rev() {
+        let mut time = None;
+        for edge in graph.edges(a) {
+            let cost = costs[EdgeIndexable::to_index(&graph, edge.id())];
+            let to = latest[ix(edge.target())] - cost;
+            if time.is_none_or(|time| to < time) {
+                time = Some(to);
+            }
+        }
+        latest[ix(a)] = time.unwrap_or(length);
+    }
+
+    let mut node_slack = vec![zero; graph.node_bound()];
+    let mut edge_slack = vec![zero; costs.len()];
+    for &a in order {
+        let from = earliest[ix(a)];
+        node_slack[ix(a)] = latest[ix(a)] - from;
+        for edge in graph.edges(a) {
+            let e = EdgeIndexable::to_index(&graph, edge.id());
+            edge_slack[e] = latest[ix(edge.target())] - (from + costs[e]);
+        }
+    }
+
+    CriticalPath {
+        length,
+        path,
+        earliest,
+        latest,
+        node_slack,
+        edge_slack,
+    }
+}
@@ -0,0 +1,169 @@
+//! Single-source shortest paths of directed acyclic graphs.
+use alloc::vec;
+
+use super::{BoundedMeasure, Cycle, bellman_ford::Paths, toposort};
+use crate::{
+    acyclic::Acyclic,
+    visit::{
+        EdgeRef, GraphBase, IntoEdgeReferences, IntoEdges, IntoNeighborsDirected,
+        IntoNodeIdentifiers, NodeIndexable, Visitable,
+    },
+};
+
+/// Compute shortest paths from node `source` to all other in a directed acyclic graph.
+///
+/// The edges of the nodes are relaxed once, in topological order, so negative edge costs are
+/// permitted and the running time is linear, unlike [`bellman_ford`](super::bellman_ford). The
+/// graph is sorted topologically first, see [`acyclic_shortest_paths`] for graphs that already
+/// maintain a topological order.
+///
+/// # Arguments
+/// * `graph`: a directed acyclic graph.
+/// * `source`: the source node.
+/// * `edge_cost`: closure that returns the cost of a particular edge.
+///
+/// # Returns
+/// * `Ok`: a struct [`Paths`] containing distances and predecessors along each shortest path,
+///   indexed by the graph's node indices. The nodes that are not reachable from `source` are at
+///   distance `K::max()`, without predecessor.
+/// * `Err`: [`Cycle`] if the graph was not acyclic.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{Graph, algo::dag_shortest_paths};
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[(0, 1, 4), (0, 2, 1), (2, 1, -2), (1, 3, 3), (2, 3, 5)]);
+///
+/// // Graph represented with the weight of each edge.
+/// //
+/// //     4       3
+/// // a ----> b ----> d
+/// // | 1     ^ -2    ^
+/// // \-----> c ------/
+/// //             5
+///
+/// let paths = dag_shortest_paths(&g, a, |edge| *edge.weight()).unwrap();
+/// assert_eq!(paths.distances, vec![0, -1, 1, 2]);
+/// assert_eq!(paths.predecessors, vec![None, Some(c), Some(a), Some(b)]);
+///
+/// // Node a cannot be reached from b.
+/// let paths = dag_shortest_paths(&g, b, |edge| *edge.weight()).unwrap();
+/// assert_eq!(paths.distances, vec![i32::MAX, 0, i32::MAX, 3]);
+/// ```
+pub fn dag_shortest_paths<G, F, K>(
+    graph: G,
+    source: G::NodeId,
+    edge_cost: F,
+) -> Result<Paths<G::NodeId, K>, Cycle<G::NodeId>>
+where
+    G: IntoEdges + IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let order = toposort(graph, None)?;
+    Ok(relax_in_order(graph, source, order, edge_cost))
+}
+
+/// Compute shortest paths from node `source` to all other in an [`Acyclic`] graph.
+///
+/// Same as [`dag_shortest_paths`], but uses the topological order that the graph maintains
+/// instead of sorting it, and cannot fail.
+///
+/// # Arguments
+/// * `graph`: an acyclic graph.
+/// * `source`: the source node.
+/// * `edge_cost`: closure that returns the cost of a particular edge.
+///
+/// # Returns
+/// * [`Paths`]: distances and predecessors along each shortest path, indexed by the graph's node
+///   indices. The nodes that are not reachable from `source` are at distance `K::max()`, without
+///   predecessor.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     acyclic::Acyclic, algo::acyclic_shortest_paths, data::Build, graph::DiGraph, visit::EdgeRef,
+/// };
+///
+/// let mut graph: Acyclic<DiGraph<(), f64>> = Acyclic::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.try_add_edge(a, b, 2.0).unwrap();
+/// graph.try_add_edge(b, c, -3.0).unwrap();
+/// graph.try_add_edge(a, c, 1.0).unwrap();
+///
+/// let paths = acyclic_shortest_paths(&graph, a, |edge| *edge.weight());
+/// assert_eq!(paths.distances, vec![0.0, 2.0, -1.0]);
+/// assert_eq!(paths.predecessors, vec![None, Some(a), Some(b)]);
+/// ```
+pub fn acyclic_shortest_paths<'a, G, F, K>(
+    graph: &'a Acyclic<G>,
+    source: G::NodeId,
+    edge_cost: F,
+) -> Paths<G::NodeId, K>
+where
+    G: Visitable,
+    &'a Acyclic<G>: IntoEdges + NodeIndexable + GraphBase<NodeId = G::NodeId>,
+    F: FnMut(<&'a Acyclic<G> as IntoEdgeReferences>::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    relax_in_order(graph, source, graph.nodes_iter(), edge_cost)
+}
+
+/// Relax the edges of the nodes reachable from `source`, with the nodes in topological order.
+fn relax_in_order<G, I, F, K>(
+    graph: G,
+    source: G::NodeId,
+    order: I,
+    mut edge_cost: F,
+) -> Paths<G::NodeId, K>
+where
+    G: IntoEdges + NodeIndexable,
+    I: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let ix = |i| graph.to_index(i);
+
+    let mut predecessors = vec![None; graph.node_bound()];
+    let mut distances = vec![K::max(); graph.node_bound()];
+    distances[ix(source)] = K::default();
+
+    // The nodes before `source` in the order cannot be reached from it.
+    for i in order.into_iter().skip_while(|&i| ix(i) != ix(source)) {
+        if distances[ix(i)] == K::max() {
+            continue;
+        }
+        for edge in graph.edges(i) {
+            let j = edge.target();
+            let (dist, overflow) = distances[ix(i)].overflowing_add(edge_cost(edge));
+            if !overflow && dist < distances[ix(j)] {
+                distances[ix(j)] = dist;
+                predecessors[ix(j)] = Some(i);
+            }
+        }
+    }
+
+    Paths {
+        distances,
+        predecessors,
+    }
+}
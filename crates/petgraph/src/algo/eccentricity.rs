@@ -0,0 +1,318 @@
+//! Distance-based measures of a graph: eccentricity, diameter, radius, center and periphery.
+
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+
+use super::{Measure, dijkstra};
+use crate::visit::{
+    GraphBase, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable, Visitable,
+};
+
+/// Computed [*eccentricity*](https://en.wikipedia.org/wiki/Distance_(graph_theory)) of every
+/// node of a graph.
+///
+/// The eccentricity of a node is the greatest distance from it to any other node. It is
+/// undefined for a node that does not reach every other node.
+pub struct Eccentricity<G: GraphBase, K> {
+    graph: G,
+    values: Vec<Option<K>>,
+}
+
+impl<G, K> Eccentricity<G, K>
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+    K: PartialOrd + Copy,
+{
+    /// The eccentricity of `node`.
+    ///
+    /// Returns `None` if the node does not reach every other node or does not exist.
+    pub fn eccentricity(&self, node: G::NodeId) -> Option<K> {
+        self.values.get(self.graph.to_index(node)).and_then(|&e| e)
+    }
+
+    /// The diameter of the graph: the greatest eccentricity of its nodes.
+    ///
+    /// Returns `None` if the graph has no nodes or if a node does not reach every other node.
+    pub fn diameter(&self) -> Option<K> {
+        self.extremum(|e, best| e > best)
+    }
+
+    /// The radius of the graph: the smallest eccentricity of its nodes.
+    ///
+    /// Returns `None` if the graph has no nodes or if a node does not reach every other node.
+    pub fn radius(&self) -> Option<K> {
+        self.extremum(|e, best| e < best)
+    }
+
+    /// The center of the graph: the nodes whose eccentricity is the radius.
+    ///
+    /// Returns an empty vector if the radius is not defined.
+    pub fn center(&self) -> Vec<G::NodeId> {
+        self.nodes_with(self.radius())
+    }
+
+    /// The periphery of the graph: the nodes whose eccentricity is the diameter.
+    ///
+    /// Returns an empty vector if the diameter is not defined.
+    pub fn periphery(&self) -> Vec<G::NodeId> {
+        self.nodes_with(self.diameter())
+    }
+
+    /// The eccentricity preferred by `better` among all nodes, or `None` if any is undefined.
+    fn extremum(&self, better: impl Fn(K, K) -> bool) -> Option<K> {
+        let mut best = None;
+        for node in self.graph.node_identifiers() {
+            let e = self.eccentricity(node)?;
+            if best.is_none_or(|best| better(e, best)) {
+                best = Some(e);
+            }
+        }
+        best
+    }
+
+    fn nodes_with(&self, value: Option<K>) -> Vec<G::NodeId> {
+        let Some(value) = value else {
+            return Vec::new();
+        };
+        self.graph
+            .node_identifiers()
+            .filter(|&node| self.eccentricity(node) == Some(value))
+            .collect()
+    }
+}
+
+/// Eccentricity of every node of an unweighted graph, with a breadth-first search from every
+/// node.
+///
+/// The distance between two nodes is the number of edges of a shortest path between them. Paths
+/// follow the direction of the edges of directed graphs.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+///
+/// # Returns
+/// * [`Eccentricity`]: the eccentricity of every node, from which the diameter, radius, center and
+///   periphery of the graph follow.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|(|V| + |E|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::eccentricity, graph::UnGraph};
+///
+/// // A path graph: 0 - 1 - 2 - 3
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+/// let nodes: Vec<_> = graph.node_indices().collect();
+/// let eccentricity = eccentricity(&graph);
+/// assert_eq!(eccentricity.eccentricity(nodes[0]), Some(3));
+/// assert_eq!(eccentricity.diameter(), Some(3));
+/// assert_eq!(eccentricity.radius(), Some(2));
+/// assert_eq!(eccentricity.center(), vec![nodes[1], nodes[2]]);
+/// assert_eq!(eccentricity.periphery(), vec![nodes[0], nodes[3]]);
+/// ```
+pub fn eccentricity<G>(graph: G) -> Eccentricity<G, usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+{
+    let mut bfs = Bfs::new(graph.node_bound());
+    let mut values = vec![None; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let start = graph.to_index(node);
+        bfs.run(graph, start);
+        if bfs.order.len() == graph.node_count() {
+            values[start] = Some(bfs.last_distance());
+        }
+    }
+    Eccentricity { graph, values }
+}
+
+/// Eccentricity of every node of a weighted graph, with [`dijkstra`] from every node.
+///
+/// The distance between two nodes is the total cost of the edges of a shortest path between
+/// them. Paths follow the direction of the edges of directed graphs.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_cost`: closure that returns the cost of a particular edge, which must be non-negative.
+///
+/// # Returns
+/// * [`Eccentricity`]: the eccentricity of every node, from which the diameter, radius, center and
+///   periphery of the graph follow.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|(|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::weighted_eccentricity, graph::UnGraph};
+///
+/// // A triangle with a long edge: the shortest path from 0 to 2 goes through 1.
+/// let graph = UnGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 2), (0, 2, 5)]);
+/// let nodes: Vec<_> = graph.node_indices().collect();
+/// let eccentricity = weighted_eccentricity(&graph, |e| *e.weight());
+/// assert_eq!(eccentricity.eccentricity(nodes[0]), Some(3));
+/// assert_eq!(eccentricity.eccentricity(nodes[1]), Some(2));
+/// assert_eq!(eccentricity.diameter(), Some(3));
+/// assert_eq!(eccentricity.center(), vec![nodes[1]]);
+/// ```
+pub fn weighted_eccentricity<G, F, K>(graph: G, mut edge_cost: F) -> Eccentricity<G, K>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut values = vec![None; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let distances = dijkstra(graph, node, None, &mut edge_cost);
+        if distances.len() == graph.node_count() {
+            values[graph.to_index(node)] = distances
+                .into_values()
+                .reduce(|a, b| if b > a { b } else { a });
+        }
+    }
+    Eccentricity { graph, values }
+}
+
+/// Diameter of a large, sparse, unweighted and undirected graph, with the [iFUB][1] algorithm.
+///
+/// A double sweep of breadth-first searches first finds a lower bound of the diameter and a node
+/// `u` near the middle of a long shortest path. The eccentricities of the nodes are then computed
+/// by decreasing distance from `u`, until the lower bound meets the upper bound given by that
+/// distance. The result is exact, and on real-world graphs usually needs only a handful of
+/// breadth-first searches instead of one per node.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. For a directed graph, the neighbors must be reported in both
+///   directions, for example through [`UndirectedAdaptor`](crate::visit::UndirectedAdaptor).
+///
+/// # Returns
+/// * `Some(usize)`: the diameter of the graph, as a number of edges.
+/// * `None`: if the graph has no nodes or is not connected.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|(|V| + |E|))** in the worst case, close to linear in practice.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://doi.org/10.1016/j.tcs.2012.09.018
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::ifub_diameter, graph::UnGraph};
+///
+/// // A cycle of length 6 with a pendant path of length 2.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1),
+///     (1, 2),
+///     (2, 3),
+///     (3, 4),
+///     (4, 5),
+///     (5, 0),
+///     (0, 6),
+///     (6, 7),
+/// ]);
+/// assert_eq!(ifub_diameter(&graph), Some(5));
+/// ```
+pub fn ifub_diameter<G>(graph: G) -> Option<usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+{
+    let start = graph.to_index(graph.node_identifiers().next()?);
+    let mut bfs = Bfs::new(graph.node_bound());
+
+    // Double sweep: the farthest node from any node is the end of a long shortest path.
+    bfs.run(graph, start);
+    if bfs.order.len() != graph.node_count() {
+        return None;
+    }
+    let a = bfs.last();
+    bfs.run(graph, a);
+    let mut lower = bfs.last_distance();
+    let mut u = bfs.last();
+    for _ in 0..lower / 2 {
+        u = bfs.parents[u];
+    }
+
+    bfs.run(graph, u);
+    let levels: Vec<(usize, usize)> = bfs
+        .order
+        .iter()
+        .map(|&node| (node, bfs.distances[node]))
+        .collect();
+    let mut level = bfs.last_distance();
+    lower = lower.max(level);
+    let mut upper = 2 * level;
+    let mut end = levels.len();
+    while upper > lower {
+        // The eccentricity of the nodes further than `level` from `u` is at most `2 * level`.
+        let begin = levels[..end].partition_point(|&(_, d)| d < level);
+        for &(node, _) in &levels[begin..end] {
+            bfs.run(graph, node);
+            lower = lower.max(bfs.last_distance());
+        }
+        end = begin;
+        level -= 1;
+        upper = 2 * level;
+    }
+    Some(lower)
+}
+
+/// Reusable state of a breadth-first search over node indices.
+struct Bfs {
+    distances: Vec<usize>,
+    parents: Vec<usize>,
+    /// The visited nodes, in the order of the search.
+    order: Vec<usize>,
+}
+
+impl Bfs {
+    fn new(node_bound: usize) -> Self {
+        Self {
+            distances: vec![usize::MAX; node_bound],
+            parents: vec![usize::MAX; node_bound],
+            order: Vec::new(),
+        }
+    }
+
+    fn run<G>(&mut self, graph: G, start: usize)
+    where
+        G: IntoNeighbors + NodeIndexable,
+    {
+        for &node in &self.order {
+            self.distances[node] = usize::MAX;
+        }
+        self.order.clear();
+        self.distances[start] = 0;
+        self.order.push(start);
+        let mut head = 0;
+        while let Some(&node) = self.order.get(head) {
+            head += 1;
+            for next in graph.neighbors(graph.from_index(node)) {
+                let next = graph.to_index(next);
+                if self.distances[next] == usize::MAX {
+                    self.distances[next] = self.distances[node] + 1;
+                    self.parents[next] = node;
+                    self.order.push(next);
+                }
+            }
+        }
+    }
+
+    /// The last visited node, which is one of the farthest from the start.
+    fn last(&self) -> usize {
+        *self.order.last().unwrap()
+    }
+
+    fn last_distance(&self) -> usize {
+        self.distances[self.last()]
+    }
+}
@@ -38,6 +38,9 @@ mod state {
         pub ins_size: usize,
         pub adjacency_matrix: G::AdjMatrix,
         generation: usize,
+        /// Scratch space for the images in G1 of the mapped neighbors of a candidate node, once
+        /// per edge.
+        pub images: Vec<usize>,
     }
 
     impl<'a, G> Vf2State<'a, G>
@@ -55,6 +58,7 @@ mod state {
                 ins_size: 0,
                 adjacency_matrix: g.adjacency_matrix(),
                 generation: 0,
+                images: Vec::new(),
             }
         }
 
@@ -237,23 +241,62 @@ mod semantic {
             e0: (G0::NodeId, G0::NodeId),
             e1: (G1::NodeId, G1::NodeId),
         ) -> bool {
-            let w0 = g0
+            // The weights of parallel edges must match one to one.
+            let w0: Vec<_> = g0
                 .edges_directed(e0.0, Outgoing)
-                .find(|edge| edge.target() == e0.1)
-                .and_then(|edge| g0.edge_weight(edge.id()));
-            let w1 = g1
+                .filter(|edge| edge.target() == e0.1)
+                .filter_map(|edge| g0.edge_weight(edge.id()))
+                .collect();
+            let w1: Vec<_> = g1
                 .edges_directed(e1.0, Outgoing)
-                .find(|edge| edge.target() == e1.1)
-                .and_then(|edge| g1.edge_weight(edge.id()));
-            if let (Some(x), Some(y)) = (w0, w1) {
-                self(x, y)
-            } else {
-                false
+                .filter(|edge| edge.target() == e1.1)
+                .filter_map(|edge| g1.edge_weight(edge.id()))
+                .collect();
+            match (w0.as_slice(), w1.as_slice()) {
+                ([], _) | (_, []) => false,
+                ([x], [y]) => self(x, y),
+                _ if w0.len() != w1.len() => false,
+                _ => {
+                    let compatible: Vec<Vec<bool>> = w0
+                        .iter()
+                        .map(|x| w1.iter().map(|y| self(x, y)).collect())
+                        .collect();
+                    has_perfect_matching(&compatible)
+                }
             }
         }
     }
 }
 
+/// Whether every row of a compatibility matrix can be matched to a distinct column, with
+/// augmenting paths. Used to pair parallel edges one to one.
+pub(crate) fn has_perfect_matching(compatible: &[Vec<bool>]) -> bool {
+    fn augment(
+        compatible: &[Vec<bool>],
+        row: usize,
+        visited: &mut [bool],
+        matched: &mut [usize],
+    ) -> bool {
+        for column in 0..matched.len() {
+            if compatible[row][column] && !visited[column] {
+                visited[column] = true;
+                if matched[column] == usize::MAX
+                    || augment(compatible, matched[column], visited, matched)
+                {
+                    matched[column] = row;
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    let columns = compatible.first().map_or(0, Vec::len);
+    let mut matched = vec![usize::MAX; columns];
+    (0..compatible.len())
+        .all(|row| augment(compatible, row, &mut vec![false; columns], &mut matched))
+}
+
 mod matching {
     use super::*;
 
@@ -308,9 +351,20 @@ mod matching {
             };
         }
 
+        // The index in G1 of the image of a mapped neighbor of the candidate pair.
+        macro_rules! image {
+            (0, $n_neigh:expr, $m_neigh:expr) => {
+                $m_neigh
+            };
+            (1, $n_neigh:expr, $m_neigh:expr) => {
+                st.1.graph.to_index($n_neigh)
+            };
+        }
+
         macro_rules! r_succ {
             ($j:tt) => {{
                 let mut succ_count = 0;
+                field!(st, $j).images.clear();
                 for n_neigh in field!(st, $j)
                     .graph
                     .neighbors_directed(field!(nodes, $j), Outgoing)
@@ -333,7 +387,14 @@ mod matching {
                     if !has_edge {
                         return false;
                     }
+                    let image = if field!(nodes, $j) != n_neigh {
+                        image!($j, n_neigh, m_neigh)
+                    } else {
+                        st.1.graph.to_index(nodes.1)
+                    };
+                    field!(st, $j).images.push(image);
                 }
+                field!(st, $j).images.sort_unstable();
                 succ_count
             }};
         }
@@ -341,6 +402,7 @@ mod matching {
         macro_rules! r_pred {
             ($j:tt) => {{
                 let mut pred_count = 0;
+                field!(st, $j).images.clear();
                 for n_neigh in field!(st, $j)
                     .graph
                     .neighbors_directed(field!(nodes, $j), Incoming)
@@ -359,7 +421,9 @@ mod matching {
                     if !has_edge {
                         return false;
                     }
+                    field!(st, $j).images.push(image!($j, n_neigh, m_neigh));
                 }
+                field!(st, $j).images.sort_unstable();
                 pred_count
             }};
         }
@@ -381,11 +445,14 @@ mod matching {
         // R_new: Equal for G0, G1: Ñ n Pred(G, n); both Succ and Pred,
         //      Ñ is G0 - M - Tin - Tout
         // last attempt to add these did not speed up any of the testcases
-        if r_succ!(0) > r_succ!(1) {
+        //
+        // The images of the mapped neighbors are compared as multisets, so that parallel edges
+        // are matched by multiplicity.
+        if r_succ!(0) > r_succ!(1) || st.0.images != st.1.images {
             return false;
         }
         // R_pred
-        if st.0.graph.is_directed() && r_pred!(0) > r_pred!(1) {
+        if st.0.graph.is_directed() && (r_pred!(0) > r_pred!(1) || st.0.images != st.1.images) {
             return false;
         }
 
@@ -793,7 +860,8 @@ mod matching {
 /// Using the VF2 algorithm, only matching graph syntactically (graph
 /// structure).
 ///
-/// The graphs should not be [multigraphs].
+/// The graphs may be [multigraphs]: two nodes must be joined by as many
+/// edges as their images.
 ///
 /// **Reference**
 ///
@@ -824,7 +892,9 @@ where
 /// Using the VF2 algorithm, examining both syntactic and semantic
 /// graph isomorphism (graph structure and matching node and edge weights).
 ///
-/// The graphs should not be [multigraphs].
+/// The graphs may be [multigraphs]: two nodes must be joined by as many
+/// edges as their images, and the weights of these parallel edges must match
+/// one to one.
 ///
 /// [multigraphs]: https://en.wikipedia.org/wiki/Multigraph
 pub fn is_isomorphic_matching<G0, G1, NM, EM>(
@@ -863,7 +933,8 @@ where
 /// Using the VF2 algorithm, only matching graph syntactically (graph
 /// structure).
 ///
-/// The graphs should not be [multigraphs].
+/// The graphs may be [multigraphs]: two nodes must be joined by as many
+/// edges as their images.
 ///
 /// # Subgraph isomorphism
 ///
@@ -917,7 +988,9 @@ where
 /// Using the VF2 algorithm, examining both syntactic and semantic
 /// graph isomorphism (graph structure and matching node and edge weights).
 ///
-/// The graphs should not be [multigraphs].
+/// The graphs may be [multigraphs]: two nodes must be joined by as many
+/// edges as their images, and the weights of these parallel edges must match
+/// one to one.
 ///
 /// [multigraphs]: https://en.wikipedia.org/wiki/Multigraph
 pub fn is_isomorphic_subgraph_matching<G0, G1, NM, EM>(
@@ -956,7 +1029,9 @@ where
 /// if `g0` is isomorphic to a subgraph of `g1`, return the mappings between
 /// them.
 ///
-/// The graphs should not be [multigraphs].
+/// The graphs may be [multigraphs]: two nodes must be joined by as many
+/// edges as their images, and the weights of these parallel edges must match
+/// one to one.
 ///
 /// [multigraphs]: https://en.wikipedia.org/wiki/Multigraph
 pub fn subgraph_isomorphisms_iter<'a, G0, G1, NM, EM>(
@@ -0,0 +1,374 @@
+//! Core and truss decompositions, which peel a graph into nested subgraphs of increasing
+//! cohesion.
+
+use alloc::{vec, vec::Vec};
+
+use hashbrown::HashMap;
+
+use super::DegreeKind;
+use crate::visit::{
+    EdgeFiltered, EdgeIndexable, EdgeRef, GraphProp, IntoEdgeReferences, NodeFiltered,
+    NodeIndexable,
+};
+
+/// Core number of every node.
+///
+/// The *k-core* of a graph is its largest subgraph in which every node has a degree of at least
+/// `k`. The core number of a node is the largest `k` such that the node belongs to the k-core.
+///
+/// This is the bucket-based peeling algorithm of [Batagelj and Zaversnik][1], which repeatedly
+/// removes a node of minimum degree. Self loops are ignored, and parallel edges all count
+/// towards the degree.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `degree`: the degree that defines the cores of a directed graph; ignored for undirected
+///   graphs.
+///
+/// # Returns
+/// * `Vec<usize>`: the core number of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://arxiv.org/abs/cs/0310049
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::{DegreeKind, k_core::core_numbers},
+///     graph::{DiGraph, UnGraph},
+/// };
+///
+/// // A triangle with a pendant node.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// assert_eq!(core_numbers(&graph, DegreeKind::Total), vec![2, 2, 2, 1]);
+///
+/// // A directed cycle with an extra edge into it.
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 0)]);
+/// assert_eq!(core_numbers(&graph, DegreeKind::In), vec![1, 1, 1, 0]);
+/// assert_eq!(core_numbers(&graph, DegreeKind::Out), vec![1, 1, 1, 1]);
+/// assert_eq!(core_numbers(&graph, DegreeKind::Total), vec![2, 2, 2, 1]);
+/// ```
+pub fn core_numbers<G>(graph: G, degree: DegreeKind) -> Vec<usize>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let n = graph.node_bound();
+    // `affected[a]` lists the nodes whose degree counts an edge from `a`.
+    let mut degrees = vec![0; n];
+    let mut affected = vec![Vec::new(); n];
+    for edge in graph.edge_references() {
+        let s = graph.to_index(edge.source());
+        let t = graph.to_index(edge.target());
+        if s == t {
+            continue;
+        }
+        if !graph.is_directed() || degree != DegreeKind::Out {
+            degrees[t] += 1;
+            affected[s].push(t);
+        }
+        if !graph.is_directed() || degree != DegreeKind::In {
+            degrees[s] += 1;
+            affected[t].push(s);
+        }
+    }
+
+    let mut peeling = Peeling::new(degrees);
+    for i in 0..n {
+        let node = peeling.order[i];
+        let core = peeling.values[node];
+        for &other in &affected[node] {
+            if peeling.values[other] > core {
+                peeling.decrement(other);
+            }
+        }
+    }
+    peeling.values
+}
+
+/// The k-core of a graph.
+///
+/// The k-core is the largest subgraph in which every node has a degree of at least `k`, as
+/// defined by `degree` for directed graphs. See [`core_numbers`].
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `k`: the minimum degree of the nodes of the core.
+/// * `degree`: the degree that defines the cores of a directed graph; ignored for undirected
+///   graphs.
+///
+/// # Returns
+/// * A [`NodeFiltered`] view of `graph` that only includes the nodes of the k-core.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::{DegreeKind, k_core::k_core},
+///     graph::UnGraph,
+///     visit::{IntoNodeIdentifiers, NodeIndexable},
+/// };
+///
+/// // A 4-clique with a tail.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1),
+///     (0, 2),
+///     (0, 3),
+///     (1, 2),
+///     (1, 3),
+///     (2, 3),
+///     (3, 4),
+///     (4, 5),
+/// ]);
+/// let core = k_core(&graph, 3, DegreeKind::Total);
+/// let nodes: Vec<_> = core.node_identifiers().map(|n| graph.to_index(n)).collect();
+/// assert_eq!(nodes, vec![0, 1, 2, 3]);
+/// ```
+pub fn k_core<G>(
+    graph: G,
+    k: usize,
+    degree: DegreeKind,
+) -> NodeFiltered<G, impl Fn(G::NodeId) -> bool>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let cores = core_numbers(graph, degree);
+    NodeFiltered(graph, move |node| cores[graph.to_index(node)] >= k)
+}
+
+/// Truss number of every edge.
+///
+/// The *k-truss* of a graph is its largest subgraph in which every edge belongs to at least
+/// `k - 2` triangles. The truss number of an edge is the largest `k` such that the edge belongs
+/// to the k-truss, and is at least 2.
+///
+/// The truss numbers are computed by repeatedly removing an edge that belongs to the fewest
+/// triangles, like [`core_numbers`] removes nodes. The direction of the edges is ignored, parallel
+/// edges are treated as a single edge, and self loops get a truss number of 0.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+///
+/// # Returns
+/// * `Vec<usize>`: the truss number of every edge, indexed by [`EdgeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|E| Δ)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges and **Δ** the maximum degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::k_core::truss_numbers, graph::UnGraph};
+///
+/// // Two triangles sharing the edge 1-2, and a pendant edge.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+/// assert_eq!(truss_numbers(&graph), vec![3, 3, 3, 3, 3, 2]);
+/// ```
+pub fn truss_numbers<G>(graph: G) -> Vec<usize>
+where
+    G: IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+{
+    // Merge parallel edges into links between distinct nodes.
+    let mut link_ids = HashMap::new();
+    let mut links = Vec::new();
+    let mut edge_links = vec![usize::MAX; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        let s = NodeIndexable::to_index(&graph, edge.source());
+        let t = NodeIndexable::to_index(&graph, edge.target());
+        if s == t {
+            continue;
+        }
+        let key = (s.min(t), s.max(t));
+        let link = *link_ids.entry(key).or_insert_with(|| {
+            links.push(key);
+            links.len() - 1
+        });
+        edge_links[EdgeIndexable::to_index(&graph, edge.id())] = link;
+    }
+
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); graph.node_bound()];
+    for (link, &(a, b)) in links.iter().enumerate() {
+        adjacency[a].push((b, link));
+        adjacency[b].push((a, link));
+    }
+
+    // `marks[w]` is the link between `w` and the node whose neighbors are marked.
+    let mut marks = vec![usize::MAX; graph.node_bound()];
+    let mut support = vec![0; links.len()];
+    for (a, neighbors) in adjacency.iter().enumerate() {
+        for &(b, link) in neighbors {
+            marks[b] = link;
+        }
+        for &(b, ab) in neighbors.iter().filter(|&&(b, _)| b > a) {
+            for &(c, bc) in adjacency[b].iter().filter(|&&(c, _)| c > b) {
+                let ac = marks[c];
+                if ac != usize::MAX {
+                    support[ab] += 1;
+                    support[bc] += 1;
+                    support[ac] += 1;
+                }
+            }
+        }
+        for &(b, _) in neighbors {
+            marks[b] = usize::MAX;
+        }
+    }
+
+    let mut removed = vec![false; links.len()];
+    let mut peeling = Peeling::new(support);
+    for i in 0..links.len() {
+        let link = peeling.order[i];
+        let triangles = peeling.values[link];
+        let (a, b) = links[link];
+        removed[link] = true;
+        for &(c, ac) in &adjacency[a] {
+            if !removed[ac] {
+                marks[c] = ac;
+            }
+        }
+        for &(c, bc) in &adjacency[b] {
+            let ac = marks[c];
+            if removed[bc] || ac == usize::MAX {
+                continue;
+            }
+            for other in [ac, bc] {
+                if peeling.values[other] > triangles {
+                    peeling.decrement(other);
+                }
+            }
+        }
+        for &(c, _) in &adjacency[a] {
+            marks[c] = usize::MAX;
+        }
+    }
+
+    edge_links
+        .iter()
+        .map(|&link| {
+            if link == usize::MAX {
+                0
+            } else {
+                peeling.values[link] + 2
+            }
+        })
+        .collect()
+}
+
+/// The k-truss of a graph.
+///
+/// The k-truss is the largest subgraph in which every edge belongs to at least `k - 2`
+/// triangles. See [`truss_numbers`].
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+/// * `k`: the minimum truss number of the edges of the k-truss.
+///
+/// # Returns
+/// * An [`EdgeFiltered`] view of `graph` that only includes the edges of the k-truss. Nodes that
+///   are not incident to any of these edges are kept as isolated nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|E| Δ)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges and **Δ** the maximum degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::k_core::k_truss,
+///     graph::UnGraph,
+///     visit::{EdgeRef, IntoEdgeReferences},
+/// };
+///
+/// // A 4-clique with a triangle attached to it.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1),
+///     (0, 2),
+///     (0, 3),
+///     (1, 2),
+///     (1, 3),
+///     (2, 3),
+///     (3, 4),
+///     (4, 5),
+///     (5, 3),
+/// ]);
+/// let truss = k_truss(&graph, 4);
+/// assert_eq!(truss.edge_references().count(), 6);
+/// assert!(truss.edge_references().all(|e| e.target().index() < 4));
+/// ```
+pub fn k_truss<G>(graph: G, k: usize) -> EdgeFiltered<G, impl Fn(G::EdgeRef) -> bool>
+where
+    G: IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+{
+    let trusses = truss_numbers(graph);
+    EdgeFiltered(graph, move |edge: G::EdgeRef| {
+        trusses[EdgeIndexable::to_index(&graph, edge.id())] >= k
+    })
+}
+
+/// Items sorted by a value that can only decrease, from the algorithm of Batagelj and Zaversnik.
+///
+/// The items are processed in the order of `order`; decreasing the value of an item that comes
+/// after the current one keeps the order sorted.
+struct Peeling {
+    /// The items, sorted by value.
+    order: Vec<usize>,
+    /// The position of every item in `order`.
+    positions: Vec<usize>,
+    /// The position in `order` of the first item with every value.
+    starts: Vec<usize>,
+    /// The value of every item.
+    values: Vec<usize>,
+}
+
+impl Peeling {
+    fn new(values: Vec<usize>) -> Self {
+        let max = values.iter().copied().max().unwrap_or(0);
+        let mut starts = vec![0; max + 2];
+        for &v in &values {
+            starts[v + 1] += 1;
+        }
+        for v in 1..starts.len() {
+            starts[v] += starts[v - 1];
+        }
+        let mut next = starts.clone();
+        let mut order = vec![0; values.len()];
+        let mut positions = vec![0; values.len()];
+        for (item, &v) in values.iter().enumerate() {
+            positions[item] = next[v];
+            order[next[v]] = item;
+            next[v] += 1;
+        }
+        Peeling {
+            order,
+            positions,
+            starts,
+            values,
+        }
+    }
+
+    /// Decrease the value of `item` by one, moving it to the front of its bucket first.
+    fn decrement(&mut self, item: usize) {
+        let value = self.values[item];
+        let first = self.starts[value];
+        let other = self.order[first];
+        let position = self.positions[item];
+        self.order.swap(first, position);
+        self.positions[other] = position;
+        self.positions[item] = first;
+        self.starts[value] += 1;
+        self.values[item] -= 1;
+    }
+}
@@ -0,0 +1,373 @@
+//! Exact maximum clique, maximum independent set and minimum vertex cover, by branch and bound.
+//!
+//! These problems are NP-hard, and the searches can take exponential time. They all accept a
+//! budget on the number of branches of the search: once it is exhausted, the best solution found
+//! so far is returned, and [`SearchResult::optimal`] is `false`.
+
+use alloc::{vec, vec::Vec};
+
+use fixedbitset::FixedBitSet;
+
+use super::Measure;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The best set of nodes found by a branch-and-bound search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResult<N, W> {
+    /// The nodes of the solution.
+    pub nodes: Vec<N>,
+    /// The total weight of the nodes of the solution.
+    pub weight: W,
+    /// Whether the search was completed, proving that the solution is optimal.
+    pub optimal: bool,
+}
+
+/// Maximum weight [clique][1] of a graph.
+///
+/// A clique is a set of pairwise adjacent nodes. This is the branch-and-bound algorithm of
+/// [Tomita and Seki][2], where the candidates of every branch are greedily colored and a branch
+/// is pruned once the weights of the heaviest node of every color cannot beat the best clique
+/// found. With unit weights, this bound is the number of colors.
+///
+/// Self loops are ignored. In a directed graph, two nodes are adjacent only if there are edges
+/// in both directions between them.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+/// * `node_weight`: closure that returns the non-negative weight of a node. Use `|_| 1` for the
+///   clique with the most nodes.
+/// * `max_branches`: the maximum number of branches of the search, or `None` for no limit.
+///
+/// # Returns
+/// * [`SearchResult`]: the heaviest clique found, which is a maximum clique if the search was
+///   completed.
+///
+/// # Complexity
+/// * Time complexity: **O(2^|V|)** in the worst case, or **O(b|V|²)** with a budget of **b**
+///   branches.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// [1]: https://en.wikipedia.org/wiki/Clique_problem
+/// [2]: https://doi.org/10.1007/3-540-45066-1_22
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::maximum_clique, graph::UnGraph};
+///
+/// // A triangle and a 4-clique sharing node 2.
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1),
+///     (0, 2),
+///     (1, 2),
+///     (2, 3),
+///     (2, 4),
+///     (2, 5),
+///     (3, 4),
+///     (3, 5),
+///     (4, 5),
+/// ]);
+/// let clique = maximum_clique(&graph, |_| 1, None);
+/// assert_eq!(clique.weight, 4);
+/// assert!(clique.optimal);
+///
+/// // The triangle is heavier with these weights.
+/// let weights = [5, 5, 1, 1, 1, 1];
+/// let clique = maximum_clique(&graph, |n| weights[n.index()], None);
+/// assert_eq!(clique.weight, 11);
+/// ```
+pub fn maximum_clique<G, F, W>(
+    graph: G,
+    node_weight: F,
+    max_branches: Option<usize>,
+) -> SearchResult<G::NodeId, W>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> W,
+    W: Measure + Copy,
+{
+    let (nodes, out, incoming) = adjacency(graph);
+    let adjacent = out
+        .into_iter()
+        .zip(incoming)
+        .map(|(mut out, incoming)| {
+            out.intersect_with(&incoming);
+            out
+        })
+        .collect();
+    solve(nodes, adjacent, node_weight, max_branches)
+}
+
+/// Maximum weight [independent set][1] of a graph.
+///
+/// An independent set is a set of nodes of which no two are adjacent, which is a clique of the
+/// complement of the graph: see [`maximum_clique`].
+///
+/// Self loops are ignored. In a directed graph, two nodes are adjacent if there is an edge in
+/// either direction between them.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+/// * `node_weight`: closure that returns the non-negative weight of a node. Use `|_| 1` for the
+///   independent set with the most nodes.
+/// * `max_branches`: the maximum number of branches of the search, or `None` for no limit.
+///
+/// # Returns
+/// * [`SearchResult`]: the heaviest independent set found, which is a maximum independent set if
+///   the search was completed.
+///
+/// # Complexity
+/// * Time complexity: **O(2^|V|)** in the worst case, or **O(b|V|²)** with a budget of **b**
+///   branches.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// [1]: https://en.wikipedia.org/wiki/Independent_set_(graph_theory)
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::maximum_independent_set, graph::UnGraph};
+///
+/// // A cycle of length 5.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let independent_set = maximum_independent_set(&graph, |_| 1, None);
+/// assert_eq!(independent_set.nodes.len(), 2);
+/// assert!(independent_set.optimal);
+/// ```
+pub fn maximum_independent_set<G, F, W>(
+    graph: G,
+    node_weight: F,
+    max_branches: Option<usize>,
+) -> SearchResult<G::NodeId, W>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> W,
+    W: Measure + Copy,
+{
+    let (nodes, out, incoming) = adjacency(graph);
+    let adjacent = out
+        .into_iter()
+        .zip(incoming)
+        .enumerate()
+        .map(|(v, (mut out, incoming))| {
+            out.union_with(&incoming);
+            out.toggle_range(..);
+            out.remove(v);
+            out
+        })
+        .collect();
+    solve(nodes, adjacent, node_weight, max_branches)
+}
+
+/// Minimum weight [vertex cover][1] of a graph.
+///
+/// A vertex cover is a set of nodes that contains at least one end of every edge. Its complement
+/// is an independent set, so this is the complement of [`maximum_independent_set`].
+///
+/// Self loops are ignored. In a directed graph, the direction of the edges is ignored.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+/// * `node_weight`: closure that returns the non-negative weight of a node. Use `|_| 1` for the
+///   vertex cover with the fewest nodes.
+/// * `max_branches`: the maximum number of branches of the search, or `None` for no limit.
+///
+/// # Returns
+/// * [`SearchResult`]: the lightest vertex cover found, which is a minimum vertex cover if the
+///   search was completed.
+///
+/// # Complexity
+/// * Time complexity: **O(2^|V|)** in the worst case, or **O(b|V|²)** with a budget of **b**
+///   branches.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// [1]: https://en.wikipedia.org/wiki/Vertex_cover
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::minimum_vertex_cover,
+///     graph::{NodeIndex, UnGraph},
+/// };
+///
+/// // A star: the center covers every edge.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+/// let cover = minimum_vertex_cover(&graph, |_| 1, None);
+/// assert_eq!(cover.nodes, vec![NodeIndex::new(0)]);
+/// ```
+pub fn minimum_vertex_cover<G, F, W>(
+    graph: G,
+    mut node_weight: F,
+    max_branches: Option<usize>,
+) -> SearchResult<G::NodeId, W>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> W,
+    W: Measure + Copy,
+{
+    let independent_set = maximum_independent_set(graph, &mut node_weight, max_branches);
+    let mut excluded = FixedBitSet::with_capacity(graph.node_bound());
+    for &node in &independent_set.nodes {
+        excluded.insert(graph.to_index(node));
+    }
+    let nodes: Vec<_> = graph
+        .node_identifiers()
+        .filter(|&node| !excluded.contains(graph.to_index(node)))
+        .collect();
+    let weight = nodes
+        .iter()
+        .fold(W::default(), |total, &node| total + node_weight(node));
+    SearchResult {
+        nodes,
+        weight,
+        optimal: independent_set.optimal,
+    }
+}
+
+/// The nodes of `graph` with their outgoing and incoming adjacency, as sets of positions in
+/// the returned node list, without self loops.
+fn adjacency<G>(graph: G) -> (Vec<G::NodeId>, Vec<FixedBitSet>, Vec<FixedBitSet>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let n = nodes.len();
+    let mut positions = vec![usize::MAX; graph.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        positions[graph.to_index(node)] = i;
+    }
+    let mut out = vec![FixedBitSet::with_capacity(n); n];
+    let mut incoming = vec![FixedBitSet::with_capacity(n); n];
+    for (a, &node) in nodes.iter().enumerate() {
+        for b in graph.neighbors(node) {
+            let b = positions[graph.to_index(b)];
+            if a != b {
+                out[a].insert(b);
+                incoming[b].insert(a);
+            }
+        }
+    }
+    (nodes, out, incoming)
+}
+
+/// Find a maximum weight clique of the graph on `nodes` with the adjacency `adjacent`.
+fn solve<N, F, W>(
+    nodes: Vec<N>,
+    adjacent: Vec<FixedBitSet>,
+    mut node_weight: F,
+    max_branches: Option<usize>,
+) -> SearchResult<N, W>
+where
+    N: Copy,
+    F: FnMut(N) -> W,
+    W: Measure + Copy,
+{
+    let n = nodes.len();
+    // Search the nodes by decreasing degree, which keeps the colorings small.
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|&v| core::cmp::Reverse(adjacent[v].count_ones(..)));
+    let mut position = vec![0; n];
+    for (i, &v) in order.iter().enumerate() {
+        position[v] = i;
+    }
+    let relabel = |set: &FixedBitSet| {
+        let mut relabeled = FixedBitSet::with_capacity(n);
+        relabeled.extend(set.ones().map(|v| position[v]));
+        relabeled
+    };
+
+    let mut search = Search {
+        adjacent: order.iter().map(|&v| relabel(&adjacent[v])).collect(),
+        weights: order.iter().map(|&v| node_weight(nodes[v])).collect(),
+        clique: Vec::new(),
+        best: Vec::new(),
+        best_weight: W::default(),
+        branches: max_branches,
+        exhausted: false,
+    };
+    let mut candidates = FixedBitSet::with_capacity(n);
+    candidates.insert_range(..);
+    search.expand(W::default(), candidates);
+
+    SearchResult {
+        nodes: search.best.iter().map(|&i| nodes[order[i]]).collect(),
+        weight: search.best_weight,
+        optimal: !search.exhausted,
+    }
+}
+
+struct Search<W> {
+    adjacent: Vec<FixedBitSet>,
+    weights: Vec<W>,
+    clique: Vec<usize>,
+    best: Vec<usize>,
+    best_weight: W,
+    /// The number of branches left, if limited.
+    branches: Option<usize>,
+    /// Whether the search was stopped by the limit on the number of branches.
+    exhausted: bool,
+}
+
+impl<W> Search<W>
+where
+    W: Measure + Copy,
+{
+    /// Extend the current clique, of weight `weight`, with nodes of `candidates`.
+    fn expand(&mut self, weight: W, mut candidates: FixedBitSet) {
+        if let Some(branches) = &mut self.branches {
+            if *branches == 0 {
+                self.exhausted = true;
+                return;
+            }
+            *branches -= 1;
+        }
+        if weight > self.best_weight {
+            self.best_weight = weight;
+            self.best.clone_from(&self.clique);
+        }
+
+        // Greedy coloring: the nodes of a color are pairwise non-adjacent, so a clique has at
+        // most one node of every color, and the heaviest node of every color bounds its weight.
+        let mut colored = Vec::new();
+        let mut uncolored = candidates.clone();
+        let mut bound = W::default();
+        while !uncolored.is_clear() {
+            let mut available = uncolored.clone();
+            let start = colored.len();
+            let mut heaviest = W::default();
+            while let Some(v) = available.minimum() {
+                uncolored.remove(v);
+                available.remove(v);
+                available.difference_with(&self.adjacent[v]);
+                if self.weights[v] > heaviest {
+                    heaviest = self.weights[v];
+                }
+                colored.push((v, W::default()));
+            }
+            bound = bound + heaviest;
+            for entry in &mut colored[start..] {
+                entry.1 = bound;
+            }
+        }
+
+        // The later a node is colored, the larger its bound: branch on those first.
+        for &(v, bound) in colored.iter().rev() {
+            if weight + bound <= self.best_weight {
+                return;
+            }
+            let mut next = candidates.clone();
+            next.intersect_with(&self.adjacent[v]);
+            self.clique.push(v);
+            self.expand(weight + self.weights[v], next);
+            self.clique.pop();
+            candidates.remove(v);
+            if self.exhausted {
+                return;
+            }
+        }
+    }
+}
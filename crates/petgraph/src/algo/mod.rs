@@ -14,35 +14,72 @@ the `Graph` type.
 pub mod articulation_points;
 pub mod astar;
 pub mod bellman_ford;
+pub mod betweenness_centrality;
+pub mod bipartite;
 pub mod bridges;
+pub mod canonical;
+pub mod centrality;
 pub mod coloring;
+pub mod community;
+pub mod critical_path;
+pub mod dag_shortest_paths;
 pub mod dijkstra;
 pub mod dominators;
+pub mod eccentricity;
 pub mod feedback_arc_set;
 pub mod floyd_warshall;
 pub mod ford_fulkerson;
 pub mod isomorphism;
 pub mod johnson;
+pub mod k_core;
 pub mod k_shortest_path;
 pub mod matching;
 pub mod maximal_cliques;
+pub mod maximum_clique;
 pub mod maximum_flow;
 pub mod min_spanning_tree;
 pub mod page_rank;
+pub mod reachability;
 pub mod scc;
+pub mod similarity;
 pub mod simple_paths;
+pub mod spanning_tree;
 pub mod spfa;
-#[cfg(feature = "stable_graph")]
+pub mod stats;
 pub mod steiner_tree;
 pub mod tred;
+pub mod tree_isomorphism;
+pub mod triangles;
+pub mod vf2pp;
+pub mod weisfeiler_lehman;
 
 use alloc::{vec, vec::Vec};
 
 pub use astar::astar;
 pub use bellman_ford::{bellman_ford, find_negative_cycle};
+pub use betweenness_centrality::{
+    betweenness_centrality, edge_betweenness_centrality, weighted_betweenness_centrality,
+    weighted_edge_betweenness_centrality,
+};
+#[cfg(feature = "rayon")]
+pub use betweenness_centrality::{
+    parallel_betweenness_centrality, parallel_weighted_betweenness_centrality,
+};
+pub use bipartite::bipartition;
 pub use bridges::bridges;
-pub use coloring::dsatur_coloring;
+pub use canonical::{canonical_form, canonical_form_with};
+pub use centrality::{
+    IterationOptions, KatzOptions, closeness_centrality, eigenvector_centrality,
+    harmonic_centrality, hits, katz_centrality,
+};
+pub use coloring::{
+    bipartite_edge_coloring, dsatur_coloring, exact_coloring, greedy_coloring,
+    misra_gries_edge_coloring, validate_coloring,
+};
+pub use critical_path::{CriticalPath, acyclic_critical_path, dag_critical_path};
+pub use dag_shortest_paths::{acyclic_shortest_paths, dag_shortest_paths};
 pub use dijkstra::{bidirectional_dijkstra, dijkstra};
+pub use eccentricity::{Eccentricity, eccentricity, ifub_diameter, weighted_eccentricity};
 pub use feedback_arc_set::greedy_feedback_arc_set;
 pub use floyd_warshall::floyd_warshall;
 pub use isomorphism::{
@@ -52,22 +89,42 @@ pub use isomorphism::{
 pub use johnson::johnson;
 #[cfg(feature = "rayon")]
 pub use johnson::parallel_johnson;
+pub use k_core::{core_numbers, k_core, k_truss, truss_numbers};
 pub use k_shortest_path::k_shortest_path;
 pub use matching::{Matching, greedy_matching, maximum_matching};
 pub use maximal_cliques::maximal_cliques;
+pub use maximum_clique::{maximum_clique, maximum_independent_set, minimum_vertex_cover};
 pub use maximum_flow::{dinics, ford_fulkerson};
 pub use min_spanning_tree::{min_spanning_tree, min_spanning_tree_prim};
-pub use page_rank::page_rank;
+pub use page_rank::{DanglingStrategy, PageRankOptions, page_rank, sparse_page_rank};
+pub use reachability::{ReachabilityIndex, reachability_index};
 #[allow(deprecated)]
 pub use scc::scc;
 pub use scc::{
     kosaraju_scc::kosaraju_scc,
     tarjan_scc::{TarjanScc, tarjan_scc},
 };
+pub use similarity::{
+    approximate_graph_edit_distance, graph_edit_distance, maximum_common_induced_subgraph,
+};
 pub use simple_paths::{all_simple_paths, all_simple_paths_multi};
+pub use spanning_tree::{random_spanning_tree, spanning_tree_count, spanning_tree_count_float};
 pub use spfa::spfa;
 #[cfg(feature = "stable_graph")]
 pub use steiner_tree::steiner_tree;
+pub use steiner_tree::{steiner_tree_kou, steiner_tree_mehlhorn};
+pub use tred::{
+    ReachabilityMatrix, reachability_matrix, transitive_closure, transitive_reduction,
+    transitive_reduction_edges,
+};
+pub use tree_isomorphism::{
+    is_isomorphic_rooted_trees, is_isomorphic_trees, rooted_tree_encoding, tree_encoding,
+};
+pub use triangles::{average_clustering, clustering, csr_triangles, transitivity, triangles};
+pub use vf2pp::{MatchMode, vf2pp_is_isomorphic, vf2pp_matches};
+pub use weisfeiler_lehman::{
+    color_refinement, weisfeiler_lehman_graph_hash, weisfeiler_lehman_subgraph_hashes,
+};
 
 use super::{
     EdgeType,
@@ -535,6 +592,19 @@ impl<N> Cycle<N> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct NegativeCycle(pub ());
 
+/// The degree of the nodes of a directed graph that an algorithm uses.
+///
+/// The nodes of an undirected graph have a single degree, the number of incident edges.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DegreeKind {
+    /// The number of incoming edges.
+    In,
+    /// The number of outgoing edges.
+    Out,
+    /// The number of incoming and outgoing edges.
+    Total,
+}
+
 /// Return `true` if the graph\* is bipartite.
 ///
 /// A graph is bipartite if its nodes can be divided into
@@ -543,7 +613,8 @@ pub struct NegativeCycle(pub ());
 /// This algorithm implements 2-coloring algorithm based on the BFS algorithm.
 /// Always treats the input graph as if undirected.
 ///
-/// \* The algorithm checks only the subgraph that is reachable from the `start`.
+/// \* The algorithm checks only the subgraph that is reachable from the `start`. See
+/// [`bipartition`] to check every component and get the two sides or an odd cycle.
 ///
 /// # Arguments
 /// * `g`: an input graph.
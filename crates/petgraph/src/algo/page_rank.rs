@@ -3,8 +3,8 @@ use alloc::{vec, vec::Vec};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
-use super::UnitMeasure;
-use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable};
+use super::{UnitMeasure, centrality::has_converged};
+use crate::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable};
 
 /// Page Rank algorithm.
 ///
@@ -31,6 +31,8 @@ use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable};
 ///
 /// [pr]: https://en.wikipedia.org/wiki/PageRank
 ///
+/// See [`sparse_page_rank`] for a faster variant suited to large graphs.
+///
 /// # Example
 /// ```rust
 /// use petgraph::{Graph, algo::page_rank};
@@ -107,6 +109,250 @@ where
     ranks
 }
 
+/// How [`sparse_page_rank`] redistributes the rank of dangling nodes, i.e. nodes without
+/// outgoing edges (or whose outgoing edges all have a zero weight).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DanglingStrategy {
+    /// Spread the rank of dangling nodes evenly over all nodes.
+    Uniform,
+    /// Spread the rank of dangling nodes according to the personalization vector.
+    Personalization,
+    /// Keep the rank on the dangling node, as if it had a self loop.
+    SelfLoop,
+}
+
+/// Parameters of [`sparse_page_rank`].
+///
+/// The [`Default`] parameters are those of NetworkX: a damping factor of `0.85`, uniform
+/// teleportation and redistribution of the rank of dangling nodes, at most `100` iterations and
+/// the [`UnitMeasure::default_tol`] tolerance. Override some of them with the struct update
+/// syntax:
+///
+/// ```rust
+/// use petgraph::algo::PageRankOptions;
+///
+/// let options = PageRankOptions {
+///     damping_factor: 0.9,
+///     ..PageRankOptions::default()
+/// };
+/// assert_eq!(options.max_iter, 100);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageRankOptions<'a, D> {
+    /// A value in range `0.0 <= damping_factor <= 1.0`.
+    pub damping_factor: D,
+    /// The teleport weight of every node, indexed by [`NodeIndexable::to_index`], or `None` to
+    /// teleport uniformly. The weights are normalized to sum to one.
+    pub personalization: Option<&'a [D]>,
+    /// How to redistribute the rank of dangling nodes.
+    pub dangling: DanglingStrategy,
+    /// Maximum number of iterations of the main loop.
+    pub max_iter: usize,
+    /// The convergence tolerance.
+    pub tol: D,
+}
+
+impl<D: UnitMeasure> Default for PageRankOptions<'_, D> {
+    fn default() -> Self {
+        PageRankOptions {
+            damping_factor: D::from_f64(0.85),
+            personalization: None,
+            dangling: DanglingStrategy::Uniform,
+            max_iter: 100,
+            tol: D::default_tol(),
+        }
+    }
+}
+
+/// Sparse Page Rank algorithm.
+///
+/// Computes the ranks of every node in a graph using the [Page Rank algorithm][pr], by power
+/// iteration over the edges of the graph. Unlike [`page_rank`], every iteration runs in linear
+/// time, and the iteration stops as soon as the ranks converge.
+///
+/// Every iteration, a random surfer follows an outgoing edge of its current node with a
+/// probability of `damping_factor`, picking the edge proportionally to its weight, and otherwise
+/// teleports to a node drawn from the personalization vector. The rank of dangling nodes is
+/// redistributed according to the `dangling` strategy.
+///
+/// The iteration stops when the sum of the absolute changes of the ranks drops below
+/// `|V| * tol`, or after `max_iter` iterations.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_weight`: closure that returns the weight of a particular edge, which must be
+///   non-negative. Use `|_| 1.` for unweighted graphs.
+/// * `options`: the damping factor, personalization vector, dangling strategy and stopping
+///   criteria, see [`PageRankOptions`].
+///
+/// # Returns
+/// * A `Vec` mapping each node index to its rank. The ranks sum to one.
+///
+/// # Panics
+/// * If the damping factor is not between 0 and 1 (0 and 1 included).
+/// * If an edge weight is negative.
+/// * If the length of `personalization` is not [`NodeIndexable::node_bound`], if one of its entries
+///   is negative, or if they sum to zero.
+///
+/// # Complexity
+/// * Time complexity: **O(n(|V| + |E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **n** is the number of iterations, **|V|** the number of vertices (i.e nodes) and **|E|**
+/// the number of edges.
+///
+/// [pr]: https://en.wikipedia.org/wiki/PageRank
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     Graph,
+///     algo::{DanglingStrategy, PageRankOptions, sparse_page_rank},
+/// };
+///
+/// let mut g: Graph<(), f64> = Graph::new();
+/// g.extend_with_edges(&[(0, 1, 1.), (1, 2, 1.), (2, 0, 1.), (2, 3, 2.)]);
+///
+/// let ranks = sparse_page_rank(&g, |e| *e.weight(), PageRankOptions::default());
+/// assert!((ranks.iter().sum::<f64>() - 1.).abs() < 1e-9);
+///
+/// // Teleporting only to node 0 favors it and its successors.
+/// let personalization = [1., 0., 0., 0.];
+/// let options = PageRankOptions {
+///     personalization: Some(&personalization),
+///     dangling: DanglingStrategy::Personalization,
+///     tol: 1e-10,
+///     ..PageRankOptions::default()
+/// };
+/// let personalized = sparse_page_rank(&g, |e| *e.weight(), options);
+/// assert!(personalized[0] > ranks[0]);
+/// assert!(personalized[3] < ranks[3]);
+/// ```
+#[track_caller]
+pub fn sparse_page_rank<G, F, D>(
+    graph: G,
+    mut edge_weight: F,
+    options: PageRankOptions<'_, D>,
+) -> Vec<D>
+where
+    G: NodeCount + IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let node_count = graph.node_count();
+    if node_count == 0 {
+        return vec![];
+    }
+    let PageRankOptions {
+        damping_factor,
+        personalization,
+        dangling,
+        max_iter,
+        tol,
+    } = options;
+    assert!(
+        D::zero() <= damping_factor && damping_factor <= D::one(),
+        "Damping factor should be between 0 and 1."
+    );
+    let nb = D::from_usize(node_count);
+    let bound = graph.node_bound();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+
+    let mut teleport = vec![D::zero(); bound];
+    match personalization {
+        None => {
+            for &i in &nodes {
+                teleport[i] = D::one() / nb;
+            }
+        }
+        Some(weights) => {
+            assert_eq!(
+                weights.len(),
+                bound,
+                "The personalization vector should have one entry per node index."
+            );
+            let mut sum = D::zero();
+            for &i in &nodes {
+                assert!(
+                    weights[i] >= D::zero(),
+                    "Personalization weights should be non-negative."
+                );
+                teleport[i] = weights[i];
+                sum = sum + weights[i];
+            }
+            assert!(
+                sum > D::zero(),
+                "Personalization weights should not all be zero."
+            );
+            for &i in &nodes {
+                teleport[i] = teleport[i] / sum;
+            }
+        }
+    }
+
+    // Transition probabilities along every edge with a positive weight.
+    let mut out_weights = vec![D::zero(); bound];
+    let mut arcs = Vec::new();
+    for node in graph.node_identifiers() {
+        let a = graph.to_index(node);
+        for edge in graph.edges(node) {
+            let weight = edge_weight(edge);
+            assert!(weight >= D::zero(), "Edge weights should be non-negative.");
+            if weight > D::zero() {
+                out_weights[a] = out_weights[a] + weight;
+                arcs.push((a, graph.to_index(edge.target()), weight));
+            }
+        }
+    }
+    for (a, _, weight) in &mut arcs {
+        *weight = *weight / out_weights[*a];
+    }
+    let dangling_nodes: Vec<usize> = nodes
+        .iter()
+        .copied()
+        .filter(|&i| out_weights[i] == D::zero())
+        .collect();
+
+    let mut ranks = vec![D::zero(); bound];
+    for &i in &nodes {
+        ranks[i] = D::one() / nb;
+    }
+    let mut next = vec![D::zero(); bound];
+    for _ in 0..max_iter {
+        for &i in &nodes {
+            next[i] = (D::one() - damping_factor) * teleport[i];
+        }
+        for &(a, b, weight) in &arcs {
+            next[b] = next[b] + damping_factor * ranks[a] * weight;
+        }
+        match dangling {
+            DanglingStrategy::SelfLoop => {
+                for &i in &dangling_nodes {
+                    next[i] = next[i] + damping_factor * ranks[i];
+                }
+            }
+            DanglingStrategy::Uniform | DanglingStrategy::Personalization => {
+                let mass = damping_factor * dangling_nodes.iter().map(|&i| ranks[i]).sum::<D>();
+                for &i in &nodes {
+                    let share = match dangling {
+                        DanglingStrategy::Uniform => D::one() / nb,
+                        _ => teleport[i],
+                    };
+                    next[i] = next[i] + mass * share;
+                }
+            }
+        }
+        core::mem::swap(&mut ranks, &mut next);
+        if has_converged(&ranks, &next, node_count, tol) {
+            break;
+        }
+    }
+    ranks
+}
+
 #[allow(dead_code)]
 fn out_edges_info<G, D>(graph: G, index_w: usize, index_v: usize) -> (D, bool)
 where
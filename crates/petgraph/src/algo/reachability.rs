@@ -0,0 +1,198 @@
+//! Reachability index of a directed graph by [pruned 2-hop labeling][1].
+//!
+//! Every node `u` gets a list of hubs that it reaches, and a list of hubs that reach it, such that
+//! for every pair of nodes `u`, `v` where `u` reaches `v`, some hub is in both the outgoing list
+//! of `u` and the incoming list of `v`. A query then intersects two sorted lists instead of
+//! traversing the graph, and on most sparse graphs, such as the dependency graphs of build
+//! systems, the lists are short: the index takes far less space than a transitive closure.
+//!
+//! [1]: https://doi.org/10.14778/2732286.2732290
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::cmp::Reverse;
+
+use crate::{
+    Direction::{Incoming, Outgoing},
+    rng::mix64,
+    visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// A reachability index of a directed graph, which answers whether a node reaches another one
+/// without a traversal.
+///
+/// Created with [`reachability_index`].
+pub struct ReachabilityIndex<G> {
+    graph: G,
+    /// The hubs reached by every node, by [`NodeIndexable::to_index`]: the ranks of the hubs of
+    /// node `i` are `outgoing[offsets_out[i]..offsets_out[i + 1]]`, in increasing order.
+    offsets_out: Vec<usize>,
+    outgoing: Vec<usize>,
+    /// The hubs that reach every node, in the same form.
+    offsets_in: Vec<usize>,
+    incoming: Vec<usize>,
+}
+
+impl<G> ReachabilityIndex<G>
+where
+    G: NodeIndexable,
+{
+    /// Return `true` if there is a path from `u` to `v`. A node always reaches itself.
+    ///
+    /// Computes in **O(|L(u)| + |L(v)|)** time, where **|L(u)|** and **|L(v)|** are the numbers
+    /// of hubs of `u` and `v`.
+    pub fn reaches(&self, u: G::NodeId, v: G::NodeId) -> bool {
+        let (u, v) = (self.graph.to_index(u), self.graph.to_index(v));
+        u == v || self.hubs_meet(u, v)
+    }
+
+    /// Return the total number of hubs in the lists of all nodes, which measures the size of the
+    /// index.
+    pub fn label_count(&self) -> usize {
+        self.outgoing.len() + self.incoming.len()
+    }
+
+    /// Whether a hub reached by `u` reaches `v`.
+    fn hubs_meet(&self, u: usize, v: usize) -> bool {
+        let from = &self.outgoing[self.offsets_out[u]..self.offsets_out[u + 1]];
+        let to = &self.incoming[self.offsets_in[v]..self.offsets_in[v + 1]];
+        intersect(from, to)
+    }
+}
+
+/// Whether two sorted lists have an element in common.
+fn intersect(a: &[usize], b: &[usize]) -> bool {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            core::cmp::Ordering::Less => i += 1,
+            core::cmp::Ordering::Greater => j += 1,
+            core::cmp::Ordering::Equal => return true,
+        }
+    }
+    false
+}
+
+/// Build a reachability index of a directed graph by pruned 2-hop labeling.
+///
+/// The nodes are taken as hubs one at a time, by decreasing product of their in-degree and
+/// out-degree plus one, with ties broken pseudo-randomly. A breadth-first search from every hub
+/// adds it to the incoming lists of the nodes that it reaches, and a search in reverse to the
+/// outgoing lists of the nodes that reach it, except that the searches stop at the nodes whose
+/// reachability from or to the hub is already answered by earlier hubs. The graph may have
+/// cycles, but the index is smallest for directed acyclic graphs with a few nodes through which
+/// many paths go.
+///
+/// Build the index once and reuse it, instead of calling
+/// [`has_path_connecting`](super::has_path_connecting) for every pair of nodes. Unlike
+/// [`reachability_matrix`](super::reachability_matrix), its size does not grow with the square of
+/// the number of nodes on most graphs.
+///
+/// # Arguments
+/// * `graph`: a directed graph.
+///
+/// # Returns
+/// * [`ReachabilityIndex`]: the index, which borrows the graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| (|V| + |E|))** in the worst case, and close to **O(|L| (1 + |E| /
+///   |V|))** on most graphs.
+/// * Auxiliary space: **O(|V| + |L|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges and **|L|** the number of
+/// hubs in the lists of all nodes, which is at most **|V|²**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::reachability_index, graph::DiGraph};
+///
+/// // Two libraries that depend on a common one, and a binary that depends on both.
+/// let mut graph = DiGraph::<&str, ()>::new();
+/// let core = graph.add_node("core");
+/// let net = graph.add_node("net");
+/// let db = graph.add_node("db");
+/// let app = graph.add_node("app");
+/// graph.extend_with_edges([(app, net), (app, db), (net, core), (db, core)]);
+///
+/// let index = reachability_index(&graph);
+/// assert!(index.reaches(app, core));
+/// assert!(!index.reaches(net, db));
+/// assert!(!index.reaches(core, app));
+/// ```
+pub fn reachability_index<G>(graph: G) -> ReachabilityIndex<G>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let bound = graph.node_bound();
+    let mut order: Vec<(usize, usize)> = graph
+        .node_identifiers()
+        .map(|a| {
+            let degrees = (graph.neighbors_directed(a, Outgoing).count() + 1)
+                * (graph.neighbors_directed(a, Incoming).count() + 1);
+            (degrees, graph.to_index(a))
+        })
+        .collect();
+    // Ties are broken by a hash of the index, in an order that does not follow the structure of
+    // the graph.
+    order.sort_by_key(|&(degrees, a)| (Reverse(degrees), mix64(a as u64), a));
+
+    let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); bound];
+    let mut incoming: Vec<Vec<usize>> = vec![Vec::new(); bound];
+    let mut visited = vec![false; bound];
+    let mut touched = Vec::new();
+    let mut queue = VecDeque::new();
+    for (rank, &(_, hub)) in order.iter().enumerate() {
+        for direction in [Outgoing, Incoming] {
+            queue.push_back(hub);
+            visited[hub] = true;
+            touched.push(hub);
+            while let Some(a) = queue.pop_front() {
+                // Skip the nodes already known to be reached from the hub, or to reach it.
+                let known = a != hub
+                    && match direction {
+                        Outgoing => intersect(&outgoing[hub], &incoming[a]),
+                        Incoming => intersect(&outgoing[a], &incoming[hub]),
+                    };
+                if known {
+                    continue;
+                }
+                match direction {
+                    Outgoing => incoming[a].push(rank),
+                    Incoming => outgoing[a].push(rank),
+                }
+                for b in graph.neighbors_directed(graph.from_index(a), direction) {
+                    let b = graph.to_index(b);
+                    if !visited[b] {
+                        visited[b] = true;
+                        touched.push(b);
+                        queue.push_back(b);
+                    }
+                }
+            }
+            for a in touched.drain(..) {
+                visited[a] = false;
+            }
+        }
+    }
+
+    let (offsets_out, outgoing) = flatten(outgoing);
+    let (offsets_in, incoming) = flatten(incoming);
+    ReachabilityIndex {
+        graph,
+        offsets_out,
+        outgoing,
+        offsets_in,
+        incoming,
+    }
+}
+
+/// Concatenate lists, with the offset of every list and the end of the last one.
+fn flatten(lists: Vec<Vec<usize>>) -> (Vec<usize>, Vec<usize>) {
+    let mut offsets = Vec::with_capacity(lists.len() + 1);
+    let mut flat = Vec::with_capacity(lists.iter().map(Vec::len).sum());
+    for list in lists {
+        offsets.push(flat.len());
+        flat.extend(list);
+    }
+    offsets.push(flat.len());
+    (offsets, flat)
+}
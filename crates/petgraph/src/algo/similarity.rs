@@ -0,0 +1,829 @@
+//! Similarity of two graphs: [maximum common induced subgraph][1] and [graph edit distance][2].
+//!
+//! Unlike isomorphism, which only tells whether two graphs are the same, these algorithms measure
+//! how much two graphs differ, and align the nodes that they have in common.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Maximum_common_induced_subgraph
+//! [2]: https://en.wikipedia.org/wiki/Graph_edit_distance
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::ops::Sub;
+
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    algo::{Measure, isomorphism::has_perfect_matching, vf2pp::Side},
+    scored::MinScored,
+    visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// Find a [maximum common induced subgraph][1] of two graphs, with a [McSplit][2]-style
+/// branch and bound.
+///
+/// The result is a largest set of pairs of nodes such that the nodes of every pair match, and
+/// any two nodes of `g0` are joined by as many edges as the nodes that they are paired with in
+/// `g1`. The edges between two paired nodes of `g0` must match the edges between their
+/// counterparts one to one.
+///
+/// The search pairs the nodes of `g0` one at a time, starting with the node with the fewest
+/// candidates, or leaves them out. Pairing two nodes narrows the candidates of every other node
+/// of `g0` to the nodes of `g1` that are joined to the new pair in the same way. The nodes of
+/// `g0` with the same candidates form a class, and a branch is abandoned when the pairs found
+/// so far, plus the smaller of the sizes of every class and of its candidates, cannot beat the
+/// best solution.
+///
+/// # Arguments
+/// * `g0`: the first graph.
+/// * `g1`: the second graph, with the same direction as `g0`.
+/// * `node_match`: closure that returns whether a node of `g0` may be paired with a node of `g1`.
+///   Use `|_, _| true` to only compare the structure.
+/// * `edge_match`: closure that returns whether an edge of `g0` may correspond to an edge of `g1`.
+///   Use `|_, _| true` to only compare the structure.
+///
+/// # Returns
+/// * `Vec<(G0::NodeId, G1::NodeId)>`: the pairs of nodes of the common subgraph, in the order of
+///   the nodes of `g0` in [`IntoNodeIdentifiers::node_identifiers`].
+///
+/// # Complexity
+/// * Time complexity: exponential in the worst case, with **O(|V₀| |V₁|)** work per node of the
+///   search.
+/// * Auxiliary space: **O(|V₀|² |V₁| + |E₀| + |E₁|)**.
+///
+/// where **|V₀|**, **|E₀|** are the numbers of nodes and edges of `g0` and **|V₁|**, **|E₁|**
+/// those of `g1`.
+///
+/// [1]: https://en.wikipedia.org/wiki/Maximum_common_induced_subgraph
+/// [2]: https://doi.org/10.24963/ijcai.2017/99
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::maximum_common_induced_subgraph, graph::DiGraph};
+///
+/// // Two versions of a workflow: a step was added between "build" and "deploy".
+/// let mut v1 = DiGraph::<&str, ()>::new();
+/// let fetch = v1.add_node("fetch");
+/// let build = v1.add_node("build");
+/// let deploy = v1.add_node("deploy");
+/// v1.extend_with_edges([(fetch, build), (build, deploy)]);
+///
+/// let mut v2 = DiGraph::<&str, ()>::new();
+/// let fetch2 = v2.add_node("fetch");
+/// let build2 = v2.add_node("build");
+/// let test2 = v2.add_node("test");
+/// let deploy2 = v2.add_node("deploy");
+/// v2.extend_with_edges([(fetch2, build2), (build2, test2), (test2, deploy2)]);
+///
+/// let common = maximum_common_induced_subgraph(&v1, &v2, |a, b| v1[a] == v2[b], |_, _| true);
+/// assert_eq!(common, vec![(fetch, fetch2), (build, build2)]);
+/// ```
+pub fn maximum_common_induced_subgraph<G0, G1, NM, EM>(
+    g0: G0,
+    g1: G1,
+    mut node_match: NM,
+    edge_match: EM,
+) -> Vec<(G0::NodeId, G1::NodeId)>
+where
+    G0: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G1: IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = G0::EdgeType>,
+    NM: FnMut(G0::NodeId, G1::NodeId) -> bool,
+    EM: FnMut(G0::EdgeRef, G1::EdgeRef) -> bool,
+{
+    let mut search = CommonSubgraph {
+        g0: Side::new(g0),
+        g1: Side::new(g1),
+        directed: g0.is_directed(),
+        edge_match,
+        current: Vec::new(),
+        best: Vec::new(),
+    };
+
+    let (n0, n1) = (search.g0.len(), search.g1.len());
+    let mut candidates = Vec::new();
+    for a in 0..n0 {
+        let mut domain = FixedBitSet::with_capacity(n1);
+        for b in 0..n1 {
+            if node_match(search.g0.nodes[a], search.g1.nodes[b]) && search.joined_alike(a, a, b, b)
+            {
+                domain.insert(b);
+            }
+        }
+        if !domain.is_clear() {
+            candidates.push((a, domain));
+        }
+    }
+    search.expand(candidates);
+
+    let mut best = search.best;
+    best.sort_unstable();
+    best.into_iter()
+        .map(|(a, b)| (search.g0.nodes[a], search.g1.nodes[b]))
+        .collect()
+}
+
+/// The state of the search for a maximum common induced subgraph.
+struct CommonSubgraph<N0, E0, N1, E1, EM> {
+    g0: Side<N0, E0>,
+    g1: Side<N1, E1>,
+    directed: bool,
+    edge_match: EM,
+    /// The pairs of the current branch.
+    current: Vec<(usize, usize)>,
+    /// The largest set of pairs found so far.
+    best: Vec<(usize, usize)>,
+}
+
+impl<N0, E0: Copy, N1, E1: Copy, EM> CommonSubgraph<N0, E0, N1, E1, EM>
+where
+    EM: FnMut(E0, E1) -> bool,
+{
+    /// Whether `a` and `c` of `g0` are joined like `b` and `d` of `g1`.
+    fn joined_alike(&mut self, a: usize, c: usize, b: usize, d: usize) -> bool {
+        self.same_edges(a, c, b, d) && (!self.directed || a == c || self.same_edges(c, a, d, b))
+    }
+
+    fn same_edges(&mut self, a: usize, c: usize, b: usize, d: usize) -> bool {
+        let (edges0, edges1) = (self.g0.between(a, c), self.g1.between(b, d));
+        if edges0.len() != edges1.len() {
+            return false;
+        }
+        let compatible: Vec<Vec<bool>> = edges0
+            .iter()
+            .map(|&(_, e0)| {
+                edges1
+                    .iter()
+                    .map(|&(_, e1)| (self.edge_match)(self.g0.edges[e0], self.g1.edges[e1]))
+                    .collect()
+            })
+            .collect();
+        has_perfect_matching(&compatible)
+    }
+
+    /// Search the branch where the nodes of `candidates` may still be paired with the nodes of
+    /// their domains.
+    fn expand(&mut self, mut candidates: Vec<(usize, FixedBitSet)>) {
+        if self.current.len() > self.best.len() {
+            self.best.clone_from(&self.current);
+        }
+        if self.current.len() + bound(&mut candidates) <= self.best.len() {
+            return;
+        }
+
+        let next = candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (_, domain))| domain.count_ones(..))
+            .map(|(i, _)| i)
+            .unwrap();
+        let (a, domain) = candidates.swap_remove(next);
+        for b in domain.ones() {
+            let mut narrowed = Vec::with_capacity(candidates.len());
+            for (c, domain) in &candidates {
+                let mut refined = FixedBitSet::with_capacity(domain.len());
+                for d in domain.ones() {
+                    if d != b && self.joined_alike(a, *c, b, d) {
+                        refined.insert(d);
+                    }
+                }
+                if !refined.is_clear() {
+                    narrowed.push((*c, refined));
+                }
+            }
+            self.current.push((a, b));
+            self.expand(narrowed);
+            self.current.pop();
+        }
+        // The branch where `a` is left out.
+        self.expand(candidates);
+    }
+}
+
+/// An upper bound on the number of pairs that can still be added: the nodes with the same
+/// domain can be paired with at most as many nodes as the domain has.
+fn bound(candidates: &mut [(usize, FixedBitSet)]) -> usize {
+    candidates.sort_unstable_by(|(_, x), (_, y)| x.as_slice().cmp(y.as_slice()));
+    let mut union = FixedBitSet::with_capacity(candidates.first().map_or(0, |(_, d)| d.len()));
+    let mut total = 0;
+    for class in candidates.chunk_by(|(_, x), (_, y)| x == y) {
+        let domain = &class[0].1;
+        union.union_with(domain);
+        total += class.len().min(domain.count_ones(..));
+    }
+    total.min(union.count_ones(..))
+}
+
+/// A sequence of edits that turns a graph into another, and its total cost.
+///
+/// Every node and every edge of both graphs appears in exactly one edit: a substitution pairs an
+/// element of the first graph with an element of the second one, a deletion removes an element of
+/// the first graph and an insertion adds an element of the second graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EditPath<N0, N1, E0, E1, K> {
+    /// The total cost of the edits.
+    pub cost: K,
+    /// The node edits: `(Some(a), Some(b))` substitutes `b` for `a`, `(Some(a), None)` deletes
+    /// `a` and `(None, Some(b))` inserts `b`.
+    pub nodes: Vec<(Option<N0>, Option<N1>)>,
+    /// The edge edits, in the same form as the node edits.
+    pub edges: Vec<(Option<E0>, Option<E1>)>,
+}
+
+/// Compute the exact [graph edit distance][1] between two graphs, and the edit path that achieves
+/// it, with an A* search.
+///
+/// The nodes of `g0` are substituted by a node of `g1` or deleted one at a time, by decreasing
+/// degree, and the remaining nodes of `g1` are inserted at the end. The edges follow the nodes: two
+/// edges whose ends are substituted for each other are substituted, and the others are deleted or
+/// inserted. Parallel edges are paired in the order of [`IntoEdgeReferences::edge_references`].
+/// The remaining cost of a partial path is estimated by the cheapest edit of every remaining node
+/// of either graph, and the search only keeps the partial paths that may beat the path found by
+/// [`approximate_graph_edit_distance`].
+///
+/// The costs are given by two closures, which are called with a pair of elements for a
+/// substitution, with `None` as the second argument for a deletion and with `None` as the first
+/// argument for an insertion. The costs must not be negative.
+///
+/// # Arguments
+/// * `g0`: the source graph.
+/// * `g1`: the target graph, with the same direction as `g0`.
+/// * `node_cost`: closure that returns the cost of a node edit.
+/// * `edge_cost`: closure that returns the cost of an edge edit.
+///
+/// # Returns
+/// * [`EditPath`]: the edit distance and a cheapest sequence of edits. The node edits list the
+///   nodes of `g0` in the order of [`IntoNodeIdentifiers::node_identifiers`], then the inserted
+///   nodes of `g1`.
+///
+/// # Complexity
+/// * Time complexity: exponential in the worst case.
+/// * Auxiliary space: exponential in the worst case.
+///
+/// The search is only practical for small graphs; use [`approximate_graph_edit_distance`] for
+/// larger ones.
+///
+/// [1]: https://en.wikipedia.org/wiki/Graph_edit_distance
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::graph_edit_distance, graph::DiGraph};
+///
+/// // Two versions of a workflow: the second one skips from the first step to the last one.
+/// let v1 = DiGraph::<&str, ()>::from_edges([(0, 1), (1, 2)]);
+/// let mut v2 = v1.clone();
+/// v2.add_edge(0.into(), 2.into(), ());
+///
+/// // Keeping a node or an edge is free, and adding or removing one costs 2.
+/// fn cost<A, B>(a: Option<A>, b: Option<B>) -> u32 {
+///     if a.is_some() && b.is_some() { 0 } else { 2 }
+/// }
+/// let path = graph_edit_distance(&v1, &v2, cost, cost);
+/// assert_eq!(path.cost, 2);
+/// assert_eq!(path.edges.iter().filter(|(a, _)| a.is_none()).count(), 1);
+/// assert!(path.nodes.iter().all(|(a, b)| a == b));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn graph_edit_distance<G0, G1, NC, EC, K>(
+    g0: G0,
+    g1: G1,
+    node_cost: NC,
+    edge_cost: EC,
+) -> EditPath<G0::NodeId, G1::NodeId, G0::EdgeId, G1::EdgeId, K>
+where
+    G0: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G1: IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = G0::EdgeType>,
+    NC: FnMut(Option<G0::NodeId>, Option<G1::NodeId>) -> K,
+    EC: FnMut(Option<G0::EdgeRef>, Option<G1::EdgeRef>) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let mut editor = Editor::new(g0, g1, node_cost, edge_cost);
+    let approximation = editor.approximate_mapping();
+    let upper = editor.edit_path(&approximation).cost;
+    let mapping = editor.search(upper).unwrap_or(approximation);
+    editor.edit_path(&mapping)
+}
+
+/// Approximate the [graph edit distance][1] between two graphs by an assignment of their nodes,
+/// with the [bipartite method][2] of Riesen and Bunke.
+///
+/// Every node of `g0` is substituted by a node of `g1` or deleted, and the remaining nodes of `g1`
+/// are inserted, so as to minimize the cost of the node edits plus the cheapest edits of the
+/// edges around every node, with the [Hungarian algorithm][3]. The result is the cost of the edit
+/// path implied by this assignment, which is an upper bound on the edit distance. The edges and
+/// the costs are treated as by [`graph_edit_distance`].
+///
+/// # Arguments
+/// * `g0`: the source graph.
+/// * `g1`: the target graph, with the same direction as `g0`.
+/// * `node_cost`: closure that returns the cost of a node edit, called with `None` as the second
+///   argument for a deletion and as the first argument for an insertion.
+/// * `edge_cost`: closure that returns the cost of an edge edit, called in the same way.
+///
+/// # Returns
+/// * [`EditPath`]: an upper bound on the edit distance, and a sequence of edits with this cost.
+///
+/// # Complexity
+/// * Time complexity: **O((|V₀| + |V₁|)³ + |V₀| |V₁| Δ³)**.
+/// * Auxiliary space: **O((|V₀| + |V₁|)² + Δ² + |E₀| + |E₁|)**.
+///
+/// where **|V₀|**, **|E₀|** are the numbers of nodes and edges of `g0`, **|V₁|**, **|E₁|** those
+/// of `g1` and **Δ** the largest sum of the degrees of a node of `g0` and a node of `g1`.
+///
+/// [1]: https://en.wikipedia.org/wiki/Graph_edit_distance
+/// [2]: https://doi.org/10.1016/j.imavis.2008.04.004
+/// [3]: https://en.wikipedia.org/wiki/Hungarian_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::{approximate_graph_edit_distance, graph_edit_distance},
+///     graph::UnGraph,
+/// };
+///
+/// let square = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+/// fn cost<A, B>(a: Option<A>, b: Option<B>) -> f64 {
+///     if a.is_some() && b.is_some() { 0.0 } else { 1.0 }
+/// }
+///
+/// let exact = graph_edit_distance(&square, &path, cost, cost);
+/// let approximate = approximate_graph_edit_distance(&square, &path, cost, cost);
+/// assert_eq!(exact.cost, 1.0);
+/// assert!(approximate.cost >= exact.cost);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn approximate_graph_edit_distance<G0, G1, NC, EC, K>(
+    g0: G0,
+    g1: G1,
+    node_cost: NC,
+    edge_cost: EC,
+) -> EditPath<G0::NodeId, G1::NodeId, G0::EdgeId, G1::EdgeId, K>
+where
+    G0: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G1: IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = G0::EdgeType>,
+    NC: FnMut(Option<G0::NodeId>, Option<G1::NodeId>) -> K,
+    EC: FnMut(Option<G0::EdgeRef>, Option<G1::EdgeRef>) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let mut editor = Editor::new(g0, g1, node_cost, edge_cost);
+    let mapping = editor.approximate_mapping();
+    editor.edit_path(&mapping)
+}
+
+/// The two graphs of an edit distance problem, with the costs of their node edits.
+struct Editor<N0, E0, N1, E1, EC, K> {
+    g0: Side<N0, E0>,
+    g1: Side<N1, E1>,
+    directed: bool,
+    edge_cost: EC,
+    substitutions: Vec<Vec<K>>,
+    deletions: Vec<K>,
+    insertions: Vec<K>,
+    edge_deletions: Vec<K>,
+    edge_insertions: Vec<K>,
+}
+
+/// A partial edit path of the A* search: the image of one node of `g0`, and the partial path
+/// that it extends.
+struct Step {
+    parent: usize,
+    image: usize,
+}
+
+impl<N0, E0, N1, E1, EC, K> Editor<N0, E0, N1, E1, EC, K>
+where
+    N0: Copy,
+    N1: Copy,
+    E0: EdgeRef<NodeId = N0>,
+    E1: EdgeRef<NodeId = N1>,
+    EC: FnMut(Option<E0>, Option<E1>) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    fn new<G0, G1, NC>(g0: G0, g1: G1, mut node_cost: NC, mut edge_cost: EC) -> Self
+    where
+        G0: IntoEdgeReferences<NodeId = N0, EdgeRef = E0>
+            + IntoNodeIdentifiers
+            + NodeIndexable
+            + GraphProp,
+        G1: IntoEdgeReferences<NodeId = N1, EdgeRef = E1> + IntoNodeIdentifiers + NodeIndexable,
+        G1: GraphProp,
+        NC: FnMut(Option<N0>, Option<N1>) -> K,
+    {
+        let directed = g0.is_directed();
+        let g0 = Side::new(g0);
+        let g1 = Side::new(g1);
+        let substitutions = g0
+            .nodes
+            .iter()
+            .map(|&a| {
+                g1.nodes
+                    .iter()
+                    .map(|&b| node_cost(Some(a), Some(b)))
+                    .collect()
+            })
+            .collect();
+        let deletions = g0.nodes.iter().map(|&a| node_cost(Some(a), None)).collect();
+        let insertions = g1.nodes.iter().map(|&b| node_cost(None, Some(b))).collect();
+        let edge_deletions = g0.edges.iter().map(|&e| edge_cost(Some(e), None)).collect();
+        let edge_insertions = g1.edges.iter().map(|&e| edge_cost(None, Some(e))).collect();
+        Editor {
+            g0,
+            g1,
+            directed,
+            edge_cost,
+            substitutions,
+            deletions,
+            insertions,
+            edge_deletions,
+            edge_insertions,
+        }
+    }
+
+    /// The cost of pairing two lists of parallel edges in order.
+    fn pair_cost(&mut self, edges0: &[(usize, usize)], edges1: &[(usize, usize)]) -> K {
+        let mut cost = K::default();
+        for (i, &(_, e0)) in edges0.iter().enumerate() {
+            cost = cost
+                + match edges1.get(i) {
+                    Some(&(_, e1)) => {
+                        (self.edge_cost)(Some(self.g0.edges[e0]), Some(self.g1.edges[e1]))
+                    }
+                    None => self.edge_deletions[e0],
+                };
+        }
+        for &(_, e1) in edges1.iter().skip(edges0.len()) {
+            cost = cost + self.edge_insertions[e1];
+        }
+        cost
+    }
+
+    /// The cost of the edges between `a` and `c` of `g0`, given their images in `g1`, or
+    /// `usize::MAX` if deleted.
+    fn edges_cost(&mut self, a: usize, c: usize, b: usize, d: usize) -> K {
+        let mapped = b != usize::MAX && d != usize::MAX;
+        let mut cost = K::default();
+        let mut directions = vec![(a, c, b, d)];
+        if self.directed && a != c {
+            directions.push((c, a, d, b));
+        }
+        for (a, c, b, d) in directions {
+            let edges0 = self.g0.between(a, c).to_vec();
+            let edges1 = if mapped {
+                self.g1.between(b, d).to_vec()
+            } else {
+                Vec::new()
+            };
+            cost = cost + self.pair_cost(&edges0, &edges1);
+        }
+        cost
+    }
+
+    /// A lower bound on the cost of editing the `remaining` nodes of `g0` and the nodes of `g1`
+    /// that are not `used`: every node needs at least its cheapest edit.
+    fn estimate(&self, remaining: &[usize], used: &FixedBitSet) -> K {
+        let unused: Vec<usize> = (0..self.g1.len()).filter(|&b| !used[b]).collect();
+        let mut from0 = K::default();
+        for &a in remaining {
+            let cheapest = unused
+                .iter()
+                .map(|&b| self.substitutions[a][b])
+                .fold(self.deletions[a], min);
+            from0 = from0 + cheapest;
+        }
+        let mut from1 = K::default();
+        for &b in &unused {
+            let cheapest = remaining
+                .iter()
+                .map(|&a| self.substitutions[a][b])
+                .fold(self.insertions[b], min);
+            from1 = from1 + cheapest;
+        }
+        if from0 < from1 { from1 } else { from0 }
+    }
+
+    /// Find a mapping of the nodes of `g0` with an edit path cheaper than `upper`, and of least
+    /// cost, with an A* search.
+    fn search(&mut self, upper: K) -> Option<Vec<usize>> {
+        let (n0, n1) = (self.g0.len(), self.g1.len());
+        let mut order: Vec<usize> = (0..n0).collect();
+        order.sort_by_key(|&a| {
+            core::cmp::Reverse(
+                self.g0.outgoing[a].len() + self.g0.incoming.get(a).map_or(0, Vec::len),
+            )
+        });
+
+        let mut steps = vec![Step {
+            parent: usize::MAX,
+            image: usize::MAX,
+        }];
+        let mut heap = BinaryHeap::new();
+        heap.push(MinScored(K::default(), (K::default(), 0, 0)));
+        while let Some(MinScored(_, (cost, depth, step))) = heap.pop() {
+            if depth > n0 {
+                let mut mapping = vec![usize::MAX; n0];
+                let mut step = steps[step].parent;
+                for &a in order.iter().rev() {
+                    mapping[a] = steps[step].image;
+                    step = steps[step].parent;
+                }
+                return Some(mapping);
+            }
+
+            // The images of the nodes mapped so far.
+            let mut images = vec![usize::MAX; n0];
+            let mut used = FixedBitSet::with_capacity(n1);
+            let mut current = step;
+            for &a in order[..depth].iter().rev() {
+                images[a] = steps[current].image;
+                if images[a] != usize::MAX {
+                    used.insert(images[a]);
+                }
+                current = steps[current].parent;
+            }
+
+            if depth == n0 {
+                // Insert the remaining nodes of `g1` and their edges.
+                let mut total = cost;
+                for b in (0..n1).filter(|&b| !used[b]) {
+                    total = total + self.insertions[b];
+                }
+                for (e, &(b, d)) in self.g1.ends.iter().enumerate() {
+                    if !used[b] || !used[d] {
+                        total = total + self.edge_insertions[e];
+                    }
+                }
+                if total < upper {
+                    steps.push(Step {
+                        parent: step,
+                        image: usize::MAX,
+                    });
+                    heap.push(MinScored(total, (total, depth + 1, steps.len() - 1)));
+                }
+                continue;
+            }
+
+            let a = order[depth];
+            let remaining = &order[depth + 1..];
+            let candidates: Vec<usize> = (0..n1).filter(|&b| !used[b]).collect();
+            for b in candidates.into_iter().chain([usize::MAX]) {
+                let mut next = cost
+                    + if b == usize::MAX {
+                        self.deletions[a]
+                    } else {
+                        self.substitutions[a][b]
+                    };
+                next = next + self.edges_cost(a, a, b, b);
+                for &c in &order[..depth] {
+                    next = next + self.edges_cost(a, c, b, images[c]);
+                }
+                if b != usize::MAX {
+                    used.insert(b);
+                }
+                let estimate = next + self.estimate(remaining, &used);
+                if b != usize::MAX {
+                    used.set(b, false);
+                }
+                if estimate < upper {
+                    steps.push(Step {
+                        parent: step,
+                        image: b,
+                    });
+                    heap.push(MinScored(estimate, (next, depth + 1, steps.len() - 1)));
+                }
+            }
+        }
+        None
+    }
+
+    /// The cheapest edits of the edges incident to `a` and `b`, or deleted or inserted with
+    /// them if the other node is `usize::MAX`.
+    fn incident_cost(&mut self, a: usize, b: usize) -> K {
+        let mut cost = K::default();
+        for incoming in [false, true] {
+            if incoming && !self.directed {
+                break;
+            }
+            let edges0 = incident(&self.g0, a, incoming);
+            let edges1 = incident(&self.g1, b, incoming);
+            let (m0, m1) = (edges0.len(), edges1.len());
+            if m0 == 0 || m1 == 0 {
+                for &e0 in &edges0 {
+                    cost = cost + self.edge_deletions[e0];
+                }
+                for &e1 in &edges1 {
+                    cost = cost + self.edge_insertions[e1];
+                }
+                continue;
+            }
+            let mut costs = vec![vec![K::default(); m0 + m1]; m0 + m1];
+            for (i, &e0) in edges0.iter().enumerate() {
+                for (j, &e1) in edges1.iter().enumerate() {
+                    costs[i][j] =
+                        (self.edge_cost)(Some(self.g0.edges[e0]), Some(self.g1.edges[e1]));
+                }
+                for j in m1..m0 + m1 {
+                    costs[i][j] = self.edge_deletions[e0];
+                }
+            }
+            for (j, &e1) in edges1.iter().enumerate() {
+                for row in &mut costs[m0..] {
+                    row[j] = self.edge_insertions[e1];
+                }
+            }
+            let assignment = min_cost_assignment(&costs);
+            for (i, &j) in assignment.iter().enumerate() {
+                cost = cost + costs[i][j];
+            }
+        }
+        cost
+    }
+
+    /// The image of every node of `g0`, or `usize::MAX` if deleted, by an assignment of the nodes
+    /// and their incident edges.
+    fn approximate_mapping(&mut self) -> Vec<usize> {
+        let (n0, n1) = (self.g0.len(), self.g1.len());
+        let mut costs = vec![vec![K::default(); n0 + n1]; n0 + n1];
+        for a in 0..n0 {
+            for b in 0..n1 {
+                costs[a][b] = self.substitutions[a][b] + self.incident_cost(a, b);
+            }
+            let deletion = self.deletions[a] + self.incident_cost(a, usize::MAX);
+            for cost in &mut costs[a][n1..] {
+                *cost = deletion;
+            }
+        }
+        for b in 0..n1 {
+            let insertion = self.insertions[b] + self.incident_cost(usize::MAX, b);
+            for row in &mut costs[n0..] {
+                row[b] = insertion;
+            }
+        }
+        min_cost_assignment(&costs)
+            .into_iter()
+            .take(n0)
+            .map(|b| if b < n1 { b } else { usize::MAX })
+            .collect()
+    }
+
+    /// The edit path that substitutes every node of `g0` by its image, or deletes it if
+    /// `usize::MAX`, and inserts the other nodes of `g1`.
+    #[allow(clippy::type_complexity)]
+    fn edit_path(&mut self, mapping: &[usize]) -> EditPath<N0, N1, E0::EdgeId, E1::EdgeId, K> {
+        let (n0, n1) = (self.g0.len(), self.g1.len());
+        let mut cost = K::default();
+        let mut nodes = Vec::with_capacity(n0 + n1);
+        let mut preimages = vec![usize::MAX; n1];
+        for (a, &b) in mapping.iter().enumerate() {
+            if b == usize::MAX {
+                cost = cost + self.deletions[a];
+                nodes.push((Some(self.g0.nodes[a]), None));
+            } else {
+                cost = cost + self.substitutions[a][b];
+                nodes.push((Some(self.g0.nodes[a]), Some(self.g1.nodes[b])));
+                preimages[b] = a;
+            }
+        }
+        for b in (0..n1).filter(|&b| preimages[b] == usize::MAX) {
+            cost = cost + self.insertions[b];
+            nodes.push((None, Some(self.g1.nodes[b])));
+        }
+
+        // The edges of `g0` between every pair of nodes, and the edges between their images.
+        let mut edges = Vec::with_capacity(self.g0.edges.len() + self.g1.edges.len());
+        for a in 0..n0 {
+            let outgoing = self.g0.outgoing[a].clone();
+            for group in outgoing.chunk_by(|x, y| x.0 == y.0) {
+                let c = group[0].0;
+                if !self.directed && c < a {
+                    continue;
+                }
+                let (b, d) = (mapping[a], mapping[c]);
+                let images = if b != usize::MAX && d != usize::MAX {
+                    self.g1.between(b, d).to_vec()
+                } else {
+                    Vec::new()
+                };
+                cost = cost + self.pair_cost(group, &images);
+                for (i, &(_, e0)) in group.iter().enumerate() {
+                    let e1 = images.get(i).map(|&(_, e1)| self.g1.edges[e1].id());
+                    edges.push((Some(self.g0.edges[e0].id()), e1));
+                }
+                for &(_, e1) in images.iter().skip(group.len()) {
+                    edges.push((None, Some(self.g1.edges[e1].id())));
+                }
+            }
+        }
+        // The edges of `g1` between nodes whose preimages are not joined.
+        for b in 0..n1 {
+            for group in self.g1.outgoing[b].chunk_by(|x, y| x.0 == y.0) {
+                let d = group[0].0;
+                if !self.directed && d < b {
+                    continue;
+                }
+                let (a, c) = (preimages[b], preimages[d]);
+                if a != usize::MAX && c != usize::MAX && !self.g0.between(a, c).is_empty() {
+                    continue;
+                }
+                for &(_, e1) in group {
+                    cost = cost + self.edge_insertions[e1];
+                    edges.push((None, Some(self.g1.edges[e1].id())));
+                }
+            }
+        }
+        EditPath { cost, nodes, edges }
+    }
+}
+
+/// The positions of the outgoing or incoming edges of `a`, or none if `a` is `usize::MAX`.
+fn incident<N, E>(side: &Side<N, E>, a: usize, incoming: bool) -> Vec<usize> {
+    let lists = if incoming {
+        &side.incoming
+    } else {
+        &side.outgoing
+    };
+    match lists.get(a) {
+        Some(list) => list.iter().map(|&(_, e)| e).collect(),
+        None => Vec::new(),
+    }
+}
+
+fn min<K: PartialOrd>(a: K, b: K) -> K {
+    if b < a { b } else { a }
+}
+
+/// Solve the assignment problem on a square matrix of non-negative costs with the
+/// [Hungarian algorithm][1], and return the column assigned to every row.
+///
+/// The potentials are kept so that no intermediate value is negative, which allows unsigned
+/// costs.
+///
+/// [1]: https://en.wikipedia.org/wiki/Hungarian_algorithm
+fn min_cost_assignment<K>(costs: &[Vec<K>]) -> Vec<usize>
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let n = costs.len();
+    // The reduced cost of row `i` and column `j` is `costs[i][j] + columns[j] - rows[i]`. Rows
+    // and columns are numbered from one, and column zero holds the row being inserted.
+    let mut rows = vec![K::default(); n + 1];
+    let mut columns = vec![K::default(); n + 1];
+    let mut matched = vec![0; n + 1];
+    let mut way = vec![0; n + 1];
+    for i in 1..=n {
+        matched[0] = i;
+        let mut j0 = 0;
+        let mut slack: Vec<Option<K>> = vec![None; n + 1];
+        let mut used = vec![false; n + 1];
+        loop {
+            used[j0] = true;
+            let i0 = matched[j0];
+            let mut delta: Option<K> = None;
+            let mut j1 = 0;
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+                let reduced = costs[i0 - 1][j - 1] + columns[j] - rows[i0];
+                if slack[j].is_none_or(|s| reduced < s) {
+                    slack[j] = Some(reduced);
+                    way[j] = j0;
+                }
+                if delta.is_none_or(|d| slack[j].is_some_and(|s| s < d)) {
+                    delta = slack[j];
+                    j1 = j;
+                }
+            }
+            let delta = delta.unwrap();
+            for j in 0..=n {
+                if used[j] {
+                    rows[matched[j]] = rows[matched[j]] + delta;
+                    columns[j] = columns[j] + delta;
+                } else if let Some(s) = slack[j] {
+                    slack[j] = Some(s - delta);
+                }
+            }
+            j0 = j1;
+            if matched[j0] == 0 {
+                break;
+            }
+        }
+        while j0 != 0 {
+            let j1 = way[j0];
+            matched[j0] = matched[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for j in 1..=n {
+        assignment[matched[j] - 1] = j - 1;
+    }
+    assignment
+}
@@ -0,0 +1,309 @@
+//! Spanning tree counting and sampling.
+
+use alloc::{vec, vec::Vec};
+
+use fixedbitset::FixedBitSet;
+
+use super::UnitMeasure;
+use crate::{
+    rng::SplitMix64,
+    unionfind::UnionFind,
+    visit::{EdgeRef, IntoEdgeReferences, IntoEdges, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// Build the reduced Laplacian matrix of `graph`, i.e. its Laplacian matrix without the row and
+/// column of the first node. Nodes are numbered in the order of `node_identifiers`.
+///
+/// Returns `None` if the graph has no nodes.
+fn reduced_laplacian<G>(graph: G) -> Option<Vec<Vec<i128>>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut compact = vec![usize::MAX; graph.node_bound()];
+    let mut node_count = 0;
+    for node in graph.node_identifiers() {
+        compact[graph.to_index(node)] = node_count;
+        node_count += 1;
+    }
+    if node_count == 0 {
+        return None;
+    }
+
+    let mut laplacian = vec![vec![0; node_count]; node_count];
+    for edge in graph.edge_references() {
+        let a = compact[graph.to_index(edge.source())];
+        let b = compact[graph.to_index(edge.target())];
+        if a == b {
+            // Self loops never belong to a spanning tree.
+            continue;
+        }
+        laplacian[a][a] += 1;
+        laplacian[b][b] += 1;
+        laplacian[a][b] -= 1;
+        laplacian[b][a] -= 1;
+    }
+
+    laplacian.remove(0);
+    for row in &mut laplacian {
+        row.remove(0);
+    }
+    Some(laplacian)
+}
+
+/// Count the spanning trees of a graph using [Kirchhoff's theorem][1].
+///
+/// The graph is treated as if undirected. Parallel edges count as distinct, so that two nodes
+/// joined by two edges have two spanning trees, and self loops are ignored.
+///
+/// The count is computed exactly as the determinant of the reduced Laplacian matrix, using the
+/// fraction-free [Bareiss algorithm][2]. See [`spanning_tree_count_float`] for an approximate
+/// count that does not overflow on large graphs.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+///
+/// # Returns
+/// * `Some(count)`: the number of spanning trees of the graph, which is `0` if the graph is not
+///   connected or has no nodes.
+/// * `None`: if an intermediate value of the computation overflows `i128`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|³)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// [1]: https://en.wikipedia.org/wiki/Kirchhoff%27s_theorem
+/// [2]: https://en.wikipedia.org/wiki/Bareiss_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::spanning_tree_count, graph::UnGraph};
+///
+/// // A square with one diagonal.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+/// assert_eq!(spanning_tree_count(&graph), Some(8));
+/// ```
+pub fn spanning_tree_count<G>(graph: G) -> Option<u128>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let Some(mut matrix) = reduced_laplacian(graph) else {
+        return Some(0);
+    };
+    let n = matrix.len();
+    let mut negate = false;
+    let mut previous_pivot = 1;
+
+    for k in 0..n {
+        if matrix[k][k] == 0 {
+            let Some(swap) = (k + 1..n).find(|&i| matrix[i][k] != 0) else {
+                return Some(0);
+            };
+            matrix.swap(k, swap);
+            negate = !negate;
+        }
+        let pivot = matrix[k][k];
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let value = matrix[i][j]
+                    .checked_mul(pivot)?
+                    .checked_sub(matrix[i][k].checked_mul(matrix[k][j])?)?;
+                matrix[i][j] = value / previous_pivot;
+            }
+        }
+        previous_pivot = pivot;
+    }
+
+    let determinant = if n == 0 { 1 } else { matrix[n - 1][n - 1] };
+    let determinant = if negate { -determinant } else { determinant };
+    u128::try_from(determinant).ok()
+}
+
+/// Count the spanning trees of a graph using [Kirchhoff's theorem][1] in floating-point
+/// arithmetic.
+///
+/// The graph is treated as if undirected. Parallel edges count as distinct and self loops are
+/// ignored.
+///
+/// The count is the determinant of the reduced Laplacian matrix, computed with Gaussian
+/// elimination and partial pivoting. The result is subject to rounding errors, but remains
+/// meaningful for graphs whose number of spanning trees exceeds the range of
+/// [`spanning_tree_count`].
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+///
+/// # Returns
+/// * `D`: the approximate number of spanning trees of the graph, which is `0` if the graph is not
+///   connected or has no nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|³)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// [1]: https://en.wikipedia.org/wiki/Kirchhoff%27s_theorem
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::spanning_tree_count_float, graph::UnGraph};
+///
+/// // The complete graph on 30 nodes has 30^28 spanning trees by Cayley's formula.
+/// let mut graph = UnGraph::<(), ()>::default();
+/// let nodes = (0..30).map(|_| graph.add_node(())).collect::<Vec<_>>();
+/// for (i, &a) in nodes.iter().enumerate() {
+///     for &b in &nodes[i + 1..] {
+///         graph.add_edge(a, b, ());
+///     }
+/// }
+/// let count: f64 = spanning_tree_count_float(&graph);
+/// assert!((count / 30f64.powi(28) - 1.).abs() < 1e-9);
+/// ```
+pub fn spanning_tree_count_float<G, D>(graph: G) -> D
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy,
+{
+    let Some(laplacian) = reduced_laplacian(graph) else {
+        return D::zero();
+    };
+    let mut matrix = laplacian
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|x| {
+                    let value = D::from_usize(x.unsigned_abs() as usize);
+                    if x < 0 { D::zero() - value } else { value }
+                })
+                .collect::<Vec<D>>()
+        })
+        .collect::<Vec<_>>();
+    let n = matrix.len();
+    let abs = |x: D| if x < D::zero() { D::zero() - x } else { x };
+
+    let mut determinant = D::one();
+    for k in 0..n {
+        let pivot_row = (k..n)
+            .max_by(|&i, &j| {
+                abs(matrix[i][k])
+                    .partial_cmp(&abs(matrix[j][k]))
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            })
+            .unwrap_or(k);
+        if matrix[pivot_row][k] == D::zero() {
+            return D::zero();
+        }
+        if pivot_row != k {
+            matrix.swap(k, pivot_row);
+            determinant = D::zero() - determinant;
+        }
+        let pivot = matrix[k][k];
+        determinant = determinant * pivot;
+        for i in k + 1..n {
+            let factor = matrix[i][k] / pivot;
+            for j in k + 1..n {
+                matrix[i][j] = matrix[i][j] - factor * matrix[k][j];
+            }
+        }
+    }
+    determinant
+}
+
+/// Sample a uniformly random spanning tree of a graph using [Wilson's algorithm][1].
+///
+/// The graph must be undirected, i.e. [`IntoEdges::edges`] must report every edge incident to a
+/// node. Every spanning tree is returned with the same probability. Parallel edges count as
+/// distinct, so a pair of nodes joined by two edges is twice as likely to be connected in the
+/// sampled tree. If the graph is not connected, a uniformly random spanning tree of each connected
+/// component is returned.
+///
+/// The randomness is drawn from a pseudo-random generator seeded with `seed`, so that the same
+/// seed always produces the same tree for the same graph.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+/// * `seed`: the seed of the pseudo-random generator.
+///
+/// # Returns
+/// * `Vec<G::EdgeId>`: the edges of the spanning forest, **|V| - c** of them, where **c** is the
+///   number of connected components.
+///
+/// # Complexity
+/// * Time complexity: **O(τ + |E|)** expected, where **τ** is the mean hitting time of the graph,
+///   which is **O(|V||E|)** in the worst case.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://doi.org/10.1145/237814.237880
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::random_spanning_tree, graph::UnGraph};
+///
+/// // A 4x4 grid, e.g. to generate a random maze.
+/// let mut graph = UnGraph::<(), ()>::default();
+/// let nodes = (0..16).map(|_| graph.add_node(())).collect::<Vec<_>>();
+/// for i in 0..16 {
+///     if i % 4 != 3 {
+///         graph.add_edge(nodes[i], nodes[i + 1], ());
+///     }
+///     if i < 12 {
+///         graph.add_edge(nodes[i], nodes[i + 4], ());
+///     }
+/// }
+///
+/// let tree = random_spanning_tree(&graph, 42);
+/// assert_eq!(tree.len(), 15);
+/// assert_eq!(tree, random_spanning_tree(&graph, 42));
+/// ```
+pub fn random_spanning_tree<G>(graph: G, seed: u64) -> Vec<G::EdgeId>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+{
+    let node_bound = graph.node_bound();
+    let mut rng = SplitMix64::new(seed);
+
+    let adjacency = (0..node_bound)
+        .map(|i| graph.edges(graph.from_index(i)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    // The first node of every connected component is the root of its tree.
+    let mut components = UnionFind::<usize>::new(node_bound);
+    for edges in &adjacency {
+        for edge in edges {
+            components.union(graph.to_index(edge.source()), graph.to_index(edge.target()));
+        }
+    }
+    let mut in_tree = FixedBitSet::with_capacity(node_bound);
+    let mut has_root = FixedBitSet::with_capacity(node_bound);
+    for node in graph.node_identifiers() {
+        let i = graph.to_index(node);
+        if !has_root.put(components.find_mut(i)) {
+            in_tree.insert(i);
+        }
+    }
+
+    // Loop-erased random walks: `next[i]` is the last edge taken when leaving node `i`.
+    let mut next = vec![None; node_bound];
+    let mut tree = Vec::new();
+    for node in graph.node_identifiers() {
+        let start = graph.to_index(node);
+        let mut i = start;
+        while !in_tree[i] {
+            let edge = adjacency[i][rng.gen_index(adjacency[i].len())];
+            next[i] = Some(edge);
+            i = graph.to_index(edge.target());
+        }
+
+        let mut i = start;
+        while !in_tree.put(i) {
+            let edge = next[i].unwrap();
+            tree.push(edge.id());
+            i = graph.to_index(edge.target());
+        }
+    }
+    tree
+}
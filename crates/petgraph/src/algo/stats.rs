@@ -0,0 +1,329 @@
+//! Summary statistics of the structure of a graph: degree distribution, density, degree
+//! assortativity, reciprocity and average neighbor degree.
+//!
+//! The degree of a node is the number of neighbors reported by
+//! [`IntoNeighborsDirected::neighbors_directed`], so parallel edges all count and a self loop
+//! counts once. The nodes of an undirected graph have a single degree, and [`DegreeKind`] is
+//! ignored for them.
+
+use alloc::{vec, vec::Vec};
+
+use super::DegreeKind;
+use crate::{
+    Direction::{Incoming, Outgoing},
+    visit::{
+        EdgeCount, GraphProp, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+    },
+};
+
+/// Histogram of the degrees of the nodes.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `kind`: the degree to count for a directed graph; ignored for undirected graphs.
+///
+/// # Returns
+/// * `Vec<usize>`: the number of nodes of every degree, indexed by degree, up to the maximum
+///   degree. It is empty for a graph without nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::{DegreeKind, stats::degree_histogram},
+///     graph::DiGraph,
+/// };
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (3, 2)]);
+/// assert_eq!(degree_histogram(&graph, DegreeKind::In), vec![2, 1, 0, 1]);
+/// assert_eq!(degree_histogram(&graph, DegreeKind::Out), vec![1, 2, 1]);
+/// assert_eq!(
+///     degree_histogram(&graph, DegreeKind::Total),
+///     vec![0, 1, 2, 1]
+/// );
+/// ```
+pub fn degree_histogram<G>(graph: G, kind: DegreeKind) -> Vec<usize>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let degrees = degrees(graph, kind);
+    let mut histogram = Vec::new();
+    for node in graph.node_identifiers() {
+        let degree = degrees[graph.to_index(node)];
+        if degree >= histogram.len() {
+            histogram.resize(degree + 1, 0);
+        }
+        histogram[degree] += 1;
+    }
+    histogram
+}
+
+/// [Density][1] of a graph: its number of edges divided by the largest possible number of edges
+/// between distinct nodes.
+///
+/// The density of a graph without parallel edges or self loops is between zero and one. The
+/// density of a graph with fewer than two nodes is zero.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+///
+/// # Returns
+/// * `f64`: `|E| / (|V|(|V| - 1))` for a directed graph, and twice that for an undirected graph.
+///
+/// # Complexity
+/// * Time complexity: **O(1)**.
+/// * Auxiliary space: **O(1)**.
+///
+/// [1]: https://en.wikipedia.org/wiki/Dense_graph
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::stats::density, graph::UnGraph};
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(density(&graph), 0.5);
+/// ```
+pub fn density<G>(graph: G) -> f64
+where
+    G: NodeCount + EdgeCount + GraphProp,
+{
+    let n = graph.node_count();
+    if n < 2 {
+        return 0.;
+    }
+    let pairs = (n * (n - 1)) as f64;
+    let edges = graph.edge_count() as f64;
+    if graph.is_directed() {
+        edges / pairs
+    } else {
+        2. * edges / pairs
+    }
+}
+
+/// [Degree assortativity coefficient][1] of a graph.
+///
+/// This is the Pearson correlation coefficient of the degrees of the two ends of the edges,
+/// between -1 and 1. It is positive when nodes tend to be adjacent to nodes of similar degree,
+/// and negative when high degree nodes tend to be adjacent to low degree ones.
+///
+/// For a directed graph, the out-degree of the source of every edge is correlated with the
+/// in-degree of its target, as defined by [Newman][2]. Every edge of an undirected graph is
+/// counted in both directions.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+///
+/// # Returns
+/// * `Some(f64)`: the degree assortativity coefficient.
+/// * `None`: if the graph has no edges, or if the degrees of the sources or of the targets of the
+///   edges are all equal, as in a regular graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Assortativity
+/// [2]: https://arxiv.org/abs/cond-mat/0209450
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::stats::degree_assortativity, graph::UnGraph};
+///
+/// // In a star, the center is only adjacent to leaves.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(degree_assortativity(&graph), Some(-1.));
+/// ```
+pub fn degree_assortativity<G>(graph: G) -> Option<f64>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let out_degrees = degrees(graph, DegreeKind::Out);
+    let in_degrees = degrees(graph, DegreeKind::In);
+
+    let mut count = 0.;
+    let (mut sum_x, mut sum_y) = (0., 0.);
+    let (mut sum_xx, mut sum_yy, mut sum_xy) = (0., 0., 0.);
+    for a in graph.node_identifiers() {
+        let x = out_degrees[graph.to_index(a)] as f64;
+        for b in graph.neighbors_directed(a, Outgoing) {
+            let y = in_degrees[graph.to_index(b)] as f64;
+            count += 1.;
+            sum_x += x;
+            sum_y += y;
+            sum_xx += x * x;
+            sum_yy += y * y;
+            sum_xy += x * y;
+        }
+    }
+
+    let covariance = count * sum_xy - sum_x * sum_y;
+    let variance_x = count * sum_xx - sum_x * sum_x;
+    let variance_y = count * sum_yy - sum_y * sum_y;
+    if count == 0. || variance_x <= 0. || variance_y <= 0. {
+        return None;
+    }
+    Some(covariance / sqrt(variance_x * variance_y))
+}
+
+/// [Reciprocity][1] of a directed graph: the fraction of its edges whose reverse edge is also in
+/// the graph.
+///
+/// Self loops count as edges that are not reciprocated. Every edge of an undirected graph other
+/// than a self loop is its own reverse.
+///
+/// # Arguments
+/// * `graph`: a directed graph.
+///
+/// # Returns
+/// * `Some(f64)`: the reciprocity, between zero and one.
+/// * `None`: if the graph has no edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Reciprocity_(network_science)
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::stats::reciprocity, graph::DiGraph};
+///
+/// // Only the edges between 0 and 1 are reciprocated.
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2), (2, 3)]);
+/// assert_eq!(reciprocity(&graph), Some(0.5));
+/// ```
+pub fn reciprocity<G>(graph: G) -> Option<f64>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut successors = vec![Vec::new(); graph.node_bound()];
+    for a in graph.node_identifiers() {
+        let list = &mut successors[graph.to_index(a)];
+        list.extend(
+            graph
+                .neighbors_directed(a, Outgoing)
+                .map(|b| graph.to_index(b)),
+        );
+        list.sort_unstable();
+    }
+
+    let mut edges = 0;
+    let mut reciprocated = 0;
+    for (a, list) in successors.iter().enumerate() {
+        edges += list.len();
+        reciprocated += list
+            .iter()
+            .filter(|&&b| b != a && successors[b].binary_search(&a).is_ok())
+            .count();
+    }
+    if edges == 0 {
+        None
+    } else {
+        Some(reciprocated as f64 / edges as f64)
+    }
+}
+
+/// Average degree of the neighbors of every node.
+///
+/// For a directed graph, `kind` selects both the neighbors and their degree: the in-degree of the
+/// predecessors, the out-degree of the successors, or the total degree of all neighbors. A
+/// neighbor adjacent through several parallel edges counts once per edge. The average neighbor
+/// degree of a node without neighbors is zero.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `kind`: the neighbors and degree to use for a directed graph; ignored for undirected graphs.
+///
+/// # Returns
+/// * `Vec<f64>`: the average neighbor degree of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::{DegreeKind, stats::average_neighbor_degree},
+///     graph::UnGraph,
+/// };
+///
+/// // A star with a pendant node on one leaf.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (3, 4)]);
+/// assert_eq!(
+///     average_neighbor_degree(&graph, DegreeKind::Total),
+///     vec![4. / 3., 3., 3., 2., 2.]
+/// );
+/// ```
+pub fn average_neighbor_degree<G>(graph: G, kind: DegreeKind) -> Vec<f64>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let degrees = degrees(graph, kind);
+    let mut averages = vec![0.; graph.node_bound()];
+    for a in graph.node_identifiers() {
+        let (mut sum, mut count) = (0, 0);
+        for b in neighbors(graph, a, kind) {
+            sum += degrees[graph.to_index(b)];
+            count += 1;
+        }
+        if count > 0 {
+            averages[graph.to_index(a)] = sum as f64 / count as f64;
+        }
+    }
+    averages
+}
+
+/// The neighbors of `a` of the given kind, each listed once per edge.
+fn neighbors<G>(graph: G, a: G::NodeId, kind: DegreeKind) -> impl Iterator<Item = G::NodeId>
+where
+    G: IntoNeighborsDirected + GraphProp,
+{
+    let (incoming, outgoing) = match kind {
+        _ if !graph.is_directed() => (false, true),
+        DegreeKind::In => (true, false),
+        DegreeKind::Out => (false, true),
+        DegreeKind::Total => (true, true),
+    };
+    let incoming = incoming.then(|| graph.neighbors_directed(a, Incoming));
+    let outgoing = outgoing.then(|| graph.neighbors_directed(a, Outgoing));
+    incoming
+        .into_iter()
+        .flatten()
+        .chain(outgoing.into_iter().flatten())
+}
+
+/// The degree of every node, indexed by [`NodeIndexable::to_index`].
+fn degrees<G>(graph: G, kind: DegreeKind) -> Vec<usize>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let mut degrees = vec![0; graph.node_bound()];
+    for a in graph.node_identifiers() {
+        degrees[graph.to_index(a)] = neighbors(graph, a, kind).count();
+    }
+    degrees
+}
+
+/// Square root of a positive number by Newton's method, as `f64::sqrt` needs `std`.
+fn sqrt(x: f64) -> f64 {
+    let mut root = if x > 1. { x } else { 1. };
+    loop {
+        let next = 0.5 * (root + x / root);
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
@@ -1,10 +1,10 @@
+//! Kou's Steiner tree approximation on [`UnGraph`], returning a [`StableGraph`].
+
 use alloc::vec::Vec;
 use core::{fmt::Debug, hash::Hash};
 
 use hashbrown::{HashMap, HashSet};
 
-#[cfg(feature = "stable_graph")]
-use crate::stable_graph::StableGraph;
 use crate::{
     Undirected,
     algo::{
@@ -12,6 +12,7 @@ use crate::{
     },
     data::FromElements,
     graph::{IndexType, NodeIndex, UnGraph},
+    stable_graph::StableGraph,
     visit::{
         Data, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges, IntoNeighbors,
         IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeIndexable, Visitable,
@@ -135,6 +136,10 @@ where
 /// [Kou's algorithm][2]. Implementation details are the same as in the [NetworkX
 /// implementation][3].
 ///
+/// See also [`steiner_tree_kou`](super::steiner_tree_kou) and
+/// [`steiner_tree_mehlhorn`](super::steiner_tree_mehlhorn), which work on any undirected graph
+/// and return the ids of the selected edges instead of a new graph.
+///
 /// ## Arguments
 /// * `graph`: The undirected graph in which to find the Steiner tree.
 /// * `terminals`: A slice of node indices representing the terminals for which the Steiner tree is
@@ -177,7 +182,6 @@ where
 /// let tree = steiner_tree(&graph, &terminals);
 /// assert_eq!(tree.edge_weights().sum::<i32>(), 12);
 /// ```
-#[cfg(feature = "stable_graph")]
 pub fn steiner_tree<N, E, Ix>(
     graph: &UnGraph<N, E, Ix>,
     terminals: &[NodeIndex<Ix>],
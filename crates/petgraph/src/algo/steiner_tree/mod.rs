@@ -0,0 +1,362 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    algo::Measure,
+    scored::MinScored,
+    unionfind::UnionFind,
+    visit::{EdgeRef, IntoEdges, NodeIndexable},
+};
+
+#[cfg(feature = "stable_graph")]
+mod legacy;
+
+#[cfg(feature = "stable_graph")]
+pub use legacy::steiner_tree;
+
+/// Shortest path forest grown simultaneously from a set of source nodes.
+///
+/// All vectors are indexed by [`NodeIndexable::to_index`].
+struct ShortestPathForest<E, K> {
+    /// Distance from the closest source, `None` if no source reaches the node.
+    dist: Vec<Option<K>>,
+    /// Index of the closest source.
+    base: Vec<usize>,
+    /// Last edge on the shortest path from the closest source.
+    pred: Vec<Option<E>>,
+}
+
+impl<E, K> ShortestPathForest<E, K>
+where
+    E: EdgeRef,
+{
+    /// Walk the shortest path from the closest source to the node at index `i` backwards,
+    /// calling `f` on each of its edges.
+    ///
+    /// The walk stops early as soon as `f` returns `false`.
+    fn walk_path<G, F>(&self, graph: G, mut i: usize, mut f: F)
+    where
+        G: NodeIndexable<NodeId = E::NodeId>,
+        F: FnMut(E) -> bool,
+    {
+        while let Some(edge) = self.pred[i] {
+            if !f(edge) {
+                break;
+            }
+            i = graph.to_index(edge.source());
+        }
+    }
+}
+
+/// Multi-source Dijkstra: compute, for every node, its closest node of `sources` together with
+/// the shortest path leading to it.
+fn shortest_path_forest<G, F, K>(
+    graph: G,
+    sources: &[G::NodeId],
+    edge_cost: &mut F,
+) -> ShortestPathForest<G::EdgeRef, K>
+where
+    G: IntoEdges + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let node_bound = graph.node_bound();
+    let mut dist = vec![None; node_bound];
+    let mut base = vec![usize::MAX; node_bound];
+    let mut pred = vec![None; node_bound];
+    let mut finished = FixedBitSet::with_capacity(node_bound);
+    let mut visit_next = BinaryHeap::new();
+
+    for &source in sources {
+        let i = graph.to_index(source);
+        dist[i] = Some(K::default());
+        base[i] = i;
+        visit_next.push(MinScored(K::default(), i));
+    }
+
+    while let Some(MinScored(score, i)) = visit_next.pop() {
+        if finished.put(i) {
+            continue;
+        }
+        for edge in graph.edges(graph.from_index(i)) {
+            let j = graph.to_index(edge.target());
+            if finished.contains(j) {
+                continue;
+            }
+            let next_score = score + edge_cost(edge);
+            if dist[j].as_ref().is_none_or(|d| next_score < *d) {
+                dist[j] = Some(next_score);
+                base[j] = base[i];
+                pred[j] = Some(edge);
+                visit_next.push(MinScored(next_score, j));
+            }
+        }
+    }
+
+    ShortestPathForest { dist, base, pred }
+}
+
+/// Compute a minimum spanning forest of the `candidates` edges and repeatedly remove leaves that
+/// are not terminals from it.
+fn prune_steiner_tree<G, F, K>(
+    graph: G,
+    terminals: &[G::NodeId],
+    candidates: Vec<G::EdgeRef>,
+    edge_cost: &mut F,
+) -> Vec<G::EdgeId>
+where
+    G: IntoEdges + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let node_bound = graph.node_bound();
+    let mut sort_edges = candidates
+        .into_iter()
+        .map(|edge| MinScored(edge_cost(edge), edge))
+        .collect::<BinaryHeap<_>>();
+
+    let mut subgraphs = UnionFind::<usize>::new(node_bound);
+    let mut tree = Vec::new();
+    let mut incident = vec![Vec::new(); node_bound];
+    while let Some(MinScored(_, edge)) = sort_edges.pop() {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if subgraphs.union(a, b) {
+            incident[a].push(tree.len());
+            incident[b].push(tree.len());
+            tree.push((edge, true));
+        }
+    }
+
+    let mut is_terminal = FixedBitSet::with_capacity(node_bound);
+    for &terminal in terminals {
+        is_terminal.insert(graph.to_index(terminal));
+    }
+    let mut degree = incident.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut leaves = (0..node_bound)
+        .filter(|&i| degree[i] == 1 && !is_terminal[i])
+        .collect::<Vec<_>>();
+
+    while let Some(leaf) = leaves.pop() {
+        let Some(&k) = incident[leaf].iter().find(|&&k| tree[k].1) else {
+            continue;
+        };
+        let (edge, alive) = &mut tree[k];
+        *alive = false;
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        let other = if a == leaf { b } else { a };
+        degree[leaf] -= 1;
+        degree[other] -= 1;
+        if degree[other] == 1 && !is_terminal[other] {
+            leaves.push(other);
+        }
+    }
+
+    tree.into_iter()
+        .filter(|&(_, alive)| alive)
+        .map(|(edge, _)| edge.id())
+        .collect()
+}
+
+/// [Steiner Tree][1] approximation via [Kou's algorithm][2].
+///
+/// Computes a tree of small total cost connecting all `terminals` of an undirected graph, using
+/// the metric closure of the terminals. The result is at most `2 - 2/|S|` times the cost of an
+/// optimal Steiner tree.
+///
+/// Unlike [`steiner_tree`], this function works on any graph implementing the visitor traits and
+/// only returns the selected edges. If the terminals are not all connected, a Steiner tree is
+/// computed for each group of connected terminals.
+///
+/// See also [`steiner_tree_mehlhorn`], which computes a tree with the same approximation
+/// guarantee, but is faster for large numbers of terminals.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+/// * `terminals`: the nodes that the Steiner tree must connect.
+/// * `edge_cost`: closure that returns the cost of a particular edge. Edge costs must be
+///   non-negative.
+///
+/// # Returns
+/// * `Vec<G::EdgeId>`: the edges of the Steiner tree, in no particular order.
+///
+/// # Complexity
+/// * Time complexity: **O(|S| (|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|S| |V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **|S|** is the
+/// number of terminals.
+///
+/// [1]: https://en.wikipedia.org/wiki/Steiner_tree_problem
+/// [2]: https://doi.org/10.1007/BF00288961
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::steiner_tree_kou, graph::UnGraph};
+///
+/// let mut graph = UnGraph::<(), i32>::default();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// graph.extend_with_edges([
+///     (a, b, 7),
+///     (a, f, 6),
+///     (b, c, 1),
+///     (b, f, 5),
+///     (c, d, 1),
+///     (c, e, 3),
+///     (d, e, 1),
+///     (d, f, 4),
+///     (e, f, 10),
+/// ]);
+/// let terminals = [a, c, e, f];
+/// let tree = steiner_tree_kou(&graph, &terminals, |edge| *edge.weight());
+/// assert_eq!(tree.len(), 4);
+/// assert_eq!(tree.iter().map(|&edge| graph[edge]).sum::<i32>(), 12);
+/// ```
+pub fn steiner_tree_kou<G, F, K>(
+    graph: G,
+    terminals: &[G::NodeId],
+    mut edge_cost: F,
+) -> Vec<G::EdgeId>
+where
+    G: IntoEdges + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let forests = terminals
+        .iter()
+        .map(|&terminal| shortest_path_forest(graph, &[terminal], &mut edge_cost))
+        .collect::<Vec<_>>();
+
+    // Minimum spanning tree of the metric closure of the terminals.
+    let mut closure = BinaryHeap::new();
+    for (a, forest) in forests.iter().enumerate() {
+        for (b, &terminal) in terminals.iter().enumerate().skip(a + 1) {
+            if let Some(dist) = forest.dist[graph.to_index(terminal)] {
+                closure.push(MinScored(dist, (a, b)));
+            }
+        }
+    }
+
+    let mut components = UnionFind::<usize>::new(terminals.len());
+    let mut candidates = Vec::new();
+    while let Some(MinScored(_, (a, b))) = closure.pop() {
+        if components.union(a, b) {
+            forests[a].walk_path(graph, graph.to_index(terminals[b]), |edge| {
+                candidates.push(edge);
+                true
+            });
+        }
+    }
+
+    prune_steiner_tree(graph, terminals, candidates, &mut edge_cost)
+}
+
+/// [Steiner Tree][1] approximation via [Mehlhorn's algorithm][2].
+///
+/// Computes a tree of small total cost connecting all `terminals` of an undirected graph. Instead
+/// of the full metric closure used by [`steiner_tree_kou`], the terminals are connected along the
+/// boundaries of their Voronoi regions, which are found with a single multi-source Dijkstra.
+/// The result is at most `2 - 2/|S|` times the cost of an optimal Steiner tree.
+///
+/// If the terminals are not all connected, a Steiner tree is computed for each group of connected
+/// terminals.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+/// * `terminals`: the nodes that the Steiner tree must connect.
+/// * `edge_cost`: closure that returns the cost of a particular edge. Edge costs must be
+///   non-negative.
+///
+/// # Returns
+/// * `Vec<G::EdgeId>`: the edges of the Steiner tree, in no particular order.
+///
+/// # Complexity
+/// * Time complexity: **O((|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Steiner_tree_problem
+/// [2]: https://doi.org/10.1016/0020-0190(88)90066-X
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::steiner_tree_mehlhorn, graph::UnGraph};
+///
+/// let mut graph = UnGraph::<(), i32>::default();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// graph.extend_with_edges([
+///     (a, b, 7),
+///     (a, f, 6),
+///     (b, c, 1),
+///     (b, f, 5),
+///     (c, d, 1),
+///     (c, e, 3),
+///     (d, e, 1),
+///     (d, f, 4),
+///     (e, f, 10),
+/// ]);
+/// let terminals = [a, c, e, f];
+/// let tree = steiner_tree_mehlhorn(&graph, &terminals, |edge| *edge.weight());
+/// assert_eq!(tree.len(), 4);
+/// assert_eq!(tree.iter().map(|&edge| graph[edge]).sum::<i32>(), 12);
+/// ```
+pub fn steiner_tree_mehlhorn<G, F, K>(
+    graph: G,
+    terminals: &[G::NodeId],
+    mut edge_cost: F,
+) -> Vec<G::EdgeId>
+where
+    G: IntoEdges + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let forest = shortest_path_forest(graph, terminals, &mut edge_cost);
+
+    // Every edge between two Voronoi regions yields a path between their terminals.
+    let mut bridges = BinaryHeap::new();
+    for (i, dist) in forest.dist.iter().enumerate() {
+        let Some(dist) = *dist else {
+            continue;
+        };
+        for edge in graph.edges(graph.from_index(i)) {
+            let j = graph.to_index(edge.target());
+            if let Some(other_dist) = forest.dist[j] {
+                if forest.base[i] < forest.base[j] {
+                    bridges.push(MinScored(dist + edge_cost(edge) + other_dist, edge));
+                }
+            }
+        }
+    }
+
+    let mut components = UnionFind::<usize>::new(graph.node_bound());
+    let mut in_tree = FixedBitSet::with_capacity(graph.node_bound());
+    let mut candidates = Vec::new();
+    while let Some(MinScored(_, edge)) = bridges.pop() {
+        let (i, j) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if components.union(forest.base[i], forest.base[j]) {
+            candidates.push(edge);
+            for end in [i, j] {
+                if in_tree.put(end) {
+                    continue;
+                }
+                forest.walk_path(graph, end, |edge| {
+                    candidates.push(edge);
+                    !in_tree.put(graph.to_index(edge.source()))
+                });
+            }
+        }
+    }
+
+    prune_steiner_tree(graph, terminals, candidates, &mut edge_cost)
+}
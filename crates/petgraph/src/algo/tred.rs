@@ -1,4 +1,4 @@
-//! Compute the transitive reduction and closure of a directed acyclic graph
+//! Compute the transitive reduction and closure of a directed graph
 //!
 //! ## Transitive reduction and closure
 //! The *transitive closure* of a graph **G = (V, E)** is the graph **Gc = (V, Ec)**
@@ -7,6 +7,10 @@
 //! = (V, Er)** such that **Er** is minimal wrt. inclusion in **E** and the transitive
 //! closure of **Gr** is the same as that of **G**.
 //! The transitive reduction is well-defined for acyclic graphs only.
+//!
+//! The low-level [`dag_transitive_reduction_closure`] works on a toposorted adjacency list of a
+//! DAG. The other functions take any directed graph and handle cycles by working on its
+//! strongly connected components: every node of a component reaches every other one.
 
 use alloc::{vec, vec::Vec};
 
@@ -15,8 +19,12 @@ use fixedbitset::FixedBitSet;
 use crate::{
     Direction,
     adj::{List, UnweightedList},
-    graph::IndexType,
-    visit::{GraphBase, IntoNeighbors, IntoNeighborsDirected, NodeCompactIndexable, NodeCount},
+    algo::tarjan_scc,
+    graph::{DiGraph, IndexType, NodeIndex},
+    visit::{
+        EdgeRef, GraphBase, IntoEdgeReferences, IntoNeighbors, IntoNeighborsDirected,
+        IntoNodeIdentifiers, NodeCompactIndexable, NodeCount, NodeIndexable,
+    },
 };
 
 /// Creates a representation of the same graph respecting topological order for use in
@@ -151,6 +159,391 @@ pub fn dag_transitive_reduction_closure<E, Ix: IndexType>(
     (tred, tclos)
 }
 
+/// The strongly connected components of a graph, numbered in reverse topological order, and the
+/// edges between them.
+struct Components {
+    /// The component of every node, by [`NodeIndexable::to_index`].
+    of: Vec<usize>,
+    /// The nodes of every component, by [`NodeIndexable::to_index`].
+    members: Vec<Vec<usize>>,
+    /// The components reachable from every component by a non-empty path. A component reaches
+    /// itself if it has several nodes or a self loop.
+    reach: Vec<FixedBitSet>,
+}
+
+impl Components {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let sccs = tarjan_scc(graph);
+        let mut of = vec![usize::MAX; graph.node_bound()];
+        let members: Vec<Vec<usize>> = sccs
+            .iter()
+            .map(|scc| scc.iter().map(|&a| graph.to_index(a)).collect())
+            .collect();
+        for (c, scc) in members.iter().enumerate() {
+            for &a in scc {
+                of[a] = c;
+            }
+        }
+
+        // Every component only has edges to components found before it.
+        let mut reach: Vec<FixedBitSet> = Vec::with_capacity(members.len());
+        for (c, scc) in members.iter().enumerate() {
+            let mut reachable = FixedBitSet::with_capacity(members.len());
+            for &a in scc {
+                for b in graph.neighbors(graph.from_index(a)) {
+                    let d = of[graph.to_index(b)];
+                    if d == c {
+                        reachable.insert(c);
+                    } else if !reachable[d] {
+                        reachable.insert(d);
+                        reachable.union_with(&reach[d]);
+                    }
+                }
+            }
+            reach.push(reachable);
+        }
+        Components { of, members, reach }
+    }
+}
+
+/// A transitive closure of a directed graph, stored as a bit matrix over its strongly connected
+/// components, which answers reachability queries in constant time.
+///
+/// Created with [`reachability_matrix`].
+pub struct ReachabilityMatrix<G> {
+    graph: G,
+    components: Components,
+}
+
+impl<G> ReachabilityMatrix<G>
+where
+    G: NodeIndexable,
+{
+    /// Return `true` if there is a path from `a` to `b`, with the same meaning as
+    /// [`has_path_connecting`](crate::algo::has_path_connecting): a node always reaches itself.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn reachable(&self, a: G::NodeId, b: G::NodeId) -> bool {
+        let (a, b) = (self.graph.to_index(a), self.graph.to_index(b));
+        let (c, d) = (self.components.of[a], self.components.of[b]);
+        a == b || self.components.reach[c][d]
+    }
+
+    /// Return the nodes reachable from `a` by a path of at least one edge, which include `a` if it
+    /// is on a cycle.
+    pub fn reachable_from(&self, a: G::NodeId) -> impl Iterator<Item = G::NodeId> + '_ {
+        let c = self.components.of[self.graph.to_index(a)];
+        self.components.reach[c]
+            .ones()
+            .flat_map(move |d| &self.components.members[d])
+            .map(move |&b| self.graph.from_index(b))
+    }
+
+    /// Return the number of strongly connected components of the graph, which is the size of the
+    /// matrix.
+    pub fn component_count(&self) -> usize {
+        self.components.members.len()
+    }
+}
+
+/// Compute the reachability matrix of a directed graph, which may have cycles.
+///
+/// The strongly connected components are computed with [`tarjan_scc`], and the components
+/// reachable from every component are the union of the components reachable from its successors,
+/// in reverse topological order. Once built, the matrix answers reachability queries in constant
+/// time, instead of a traversal by query with
+/// [`has_path_connecting`](crate::algo::has_path_connecting).
+///
+/// # Arguments
+/// * `graph`: a directed graph.
+///
+/// # Returns
+/// * [`ReachabilityMatrix`]: the transitive closure of the graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| · |C| / 64)**.
+/// * Auxiliary space: **O(|V| + |C|² / 64)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges and **|C|** the number of
+/// strongly connected components.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::reachability_matrix, graph::DiGraph};
+///
+/// // A cycle between 1 and 2, reachable from 0, and leading to 3.
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 1), (2, 3)]);
+/// let matrix = reachability_matrix(&graph);
+/// assert!(matrix.reachable(0.into(), 3.into()));
+/// assert!(matrix.reachable(2.into(), 1.into()));
+/// assert!(!matrix.reachable(3.into(), 0.into()));
+/// assert_eq!(matrix.component_count(), 3);
+///
+/// let mut from_1: Vec<_> = matrix.reachable_from(1.into()).map(|n| n.index()).collect();
+/// from_1.sort();
+/// assert_eq!(from_1, vec![1, 2, 3]);
+/// ```
+pub fn reachability_matrix<G>(graph: G) -> ReachabilityMatrix<G>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    ReachabilityMatrix {
+        graph,
+        components: Components::new(graph),
+    }
+}
+
+/// Compute the transitive closure of a directed graph, which may have cycles, as a new graph.
+///
+/// The new graph has an edge from `a` to `b` if there is a path of at least one edge from `a` to
+/// `b` in `graph`, so it has a self loop on every node of a cycle.
+///
+/// # Arguments
+/// * `graph`: a directed graph.
+///
+/// # Returns
+/// * `DiGraph<G::NodeId, ()>`: the transitive closure. Its nodes are weighted by the nodes of
+///   `graph`, in the order of [`IntoNodeIdentifiers::node_identifiers`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| · |C| / 64 + |Ec|)**.
+/// * Auxiliary space: **O(|V| + |C|² / 64)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges, **|C|** the number of
+/// strongly connected components and **|Ec|** the number of edges of the closure.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::transitive_closure, graph::DiGraph};
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let closure = transitive_closure(&graph);
+/// assert_eq!(closure.edge_count(), 3);
+/// assert!(closure.contains_edge(0.into(), 2.into()));
+/// ```
+pub fn transitive_closure<G>(graph: G) -> DiGraph<G::NodeId, ()>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let components = Components::new(graph);
+    let (mut closure, positions) = node_copy(graph);
+    for a in graph.node_identifiers() {
+        let a = graph.to_index(a);
+        for d in components.reach[components.of[a]].ones() {
+            for &b in &components.members[d] {
+                closure.add_edge(positions[a], positions[b], ());
+            }
+        }
+    }
+    closure
+}
+
+/// Compute a transitive reduction of a directed graph, which may have cycles, as a new graph.
+///
+/// Every strongly connected component of several nodes is replaced by a cycle through its nodes,
+/// and the components are joined by the edges of the transitive reduction of the condensation of
+/// the graph, each between the ends of one of the edges of `graph` that join the components.
+/// A self loop is kept on a node that is not on another cycle. The result has the same transitive
+/// closure as `graph` and the fewest possible edges, and is `graph` without its redundant edges if
+/// `graph` is acyclic.
+///
+/// # Arguments
+/// * `graph`: a directed graph.
+///
+/// # Returns
+/// * `DiGraph<G::NodeId, ()>`: the transitive reduction. Its nodes are weighted by the nodes of
+///   `graph`, in the order of [`IntoNodeIdentifiers::node_identifiers`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E| + |E| · |C| / 64)**.
+/// * Auxiliary space: **O(|V| + |E| + |C|² / 64)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges and **|C|** the number of
+/// strongly connected components.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::transitive_reduction, graph::DiGraph};
+///
+/// // The cycle 0 -> 1 -> 2 -> 0 with a chord, and a shortcut from 0 to 4.
+/// let graph =
+///     DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (0, 2), (2, 3), (3, 4), (0, 4)]);
+/// let reduction = transitive_reduction(&graph);
+/// assert_eq!(reduction.edge_count(), 5);
+/// assert!(!reduction.contains_edge(0.into(), 4.into()));
+/// ```
+pub fn transitive_reduction<G>(graph: G) -> DiGraph<G::NodeId, ()>
+where
+    G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let components = Components::new(graph);
+    let (mut reduction, positions) = node_copy(graph);
+    for scc in &components.members {
+        if scc.len() > 1 {
+            for (i, &a) in scc.iter().enumerate() {
+                let b = scc[(i + 1) % scc.len()];
+                reduction.add_edge(positions[a], positions[b], ());
+            }
+        }
+    }
+    for (edge, keep) in reduced_edges(graph, &components) {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if keep {
+            reduction.add_edge(positions[a], positions[b], ());
+        }
+    }
+    reduction
+}
+
+/// Compute which edges of a directed graph, which may have cycles, to keep and to remove so that
+/// the remaining edges have the same transitive closure.
+///
+/// Between strongly connected components, the kept edges are one edge of `graph` for every edge of
+/// the transitive reduction of the condensation of the graph. Within a component of several nodes,
+/// the kept edges are those of a breadth-first out-tree and of a breadth-first in-tree from one of
+/// its nodes: at most twice the fewest edges that keep the component strongly connected, which is
+/// NP-hard to find. A self loop is kept on a node that is not on another cycle. If `graph` is
+/// acyclic, the kept edges are its unique transitive reduction.
+///
+/// # Arguments
+/// * `graph`: a directed graph.
+///
+/// # Returns
+/// A tuple of:
+/// * `Vec<G::EdgeId>`: the edges to keep, in the order of [`IntoEdgeReferences::edge_references`].
+/// * `Vec<G::EdgeId>`: the edges to remove, in the same order.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E| + |E| · |C| / 64)**.
+/// * Auxiliary space: **O(|V| + |E| + |C|² / 64)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges and **|C|** the number of
+/// strongly connected components.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::transitive_reduction_edges, graph::DiGraph};
+///
+/// let mut graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2)]);
+/// let (keep, remove) = transitive_reduction_edges(&graph);
+/// assert_eq!(keep.len(), 2);
+/// for edge in remove.into_iter().rev() {
+///     graph.remove_edge(edge);
+/// }
+/// assert!(!graph.contains_edge(0.into(), 2.into()));
+/// ```
+pub fn transitive_reduction_edges<G>(graph: G) -> (Vec<G::EdgeId>, Vec<G::EdgeId>)
+where
+    G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let components = Components::new(graph);
+    let mut keep = reduced_edges(graph, &components);
+
+    // Breadth-first out-trees and in-trees of every component of several nodes.
+    let mut within = vec![Vec::new(); graph.node_bound()];
+    let mut within_reversed = vec![Vec::new(); graph.node_bound()];
+    for (i, &(edge, _)) in keep.iter().enumerate() {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if a != b && components.of[a] == components.of[b] {
+            within[a].push((b, i));
+            within_reversed[b].push((a, i));
+        }
+    }
+    let mut visited = FixedBitSet::with_capacity(graph.node_bound());
+    for scc in components.members.iter().filter(|scc| scc.len() > 1) {
+        for adjacency in [&within, &within_reversed] {
+            for &a in scc {
+                visited.set(a, false);
+            }
+            visited.insert(scc[0]);
+            let mut queue = vec![scc[0]];
+            let mut next = 0;
+            while let Some(&a) = queue.get(next) {
+                next += 1;
+                for &(b, i) in &adjacency[a] {
+                    if !visited.put(b) {
+                        keep[i].1 = true;
+                        queue.push(b);
+                    }
+                }
+            }
+        }
+    }
+
+    let (mut kept, mut removed) = (Vec::new(), Vec::new());
+    for (edge, keep) in keep {
+        if keep {
+            kept.push(edge.id());
+        } else {
+            removed.push(edge.id());
+        }
+    }
+    (kept, removed)
+}
+
+/// The edges of `graph`, and whether they are kept as the edge between two components of the
+/// transitive reduction of the condensation, or as a self loop of a single node.
+fn reduced_edges<G>(graph: G, components: &Components) -> Vec<(G::EdgeRef, bool)>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut edges: Vec<(G::EdgeRef, bool)> = graph.edge_references().map(|e| (e, false)).collect();
+    let pairs: Vec<(usize, usize)> = edges
+        .iter()
+        .map(|(e, _)| {
+            (
+                components.of[graph.to_index(e.source())],
+                components.of[graph.to_index(e.target())],
+            )
+        })
+        .collect();
+
+    // The edges between components, from every component to the closest components first.
+    let mut order: Vec<usize> = (0..edges.len())
+        .filter(|&i| pairs[i].0 != pairs[i].1)
+        .collect();
+    order.sort_by_key(|&i| (pairs[i].0, core::cmp::Reverse(pairs[i].1)));
+    let mut reached = FixedBitSet::with_capacity(components.members.len());
+    for group in order.chunk_by(|&i, &j| pairs[i].0 == pairs[j].0) {
+        reached.clear();
+        for &i in group {
+            let d = pairs[i].1;
+            if !reached[d] {
+                reached.insert(d);
+                reached.union_with(&components.reach[d]);
+                edges[i].1 = true;
+            }
+        }
+    }
+
+    // Only the first self loop of a node that is not on another cycle.
+    let mut looped = FixedBitSet::with_capacity(graph.node_bound());
+    for (edge, keep) in &mut edges {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if a == b && components.members[components.of[a]].len() == 1 {
+            *keep = !looped.put(a);
+        }
+    }
+    edges
+}
+
+/// A new graph with the nodes of `graph` as weights, and the index of every node of `graph` in
+/// the new graph.
+fn node_copy<G>(graph: G) -> (DiGraph<G::NodeId, ()>, Vec<NodeIndex>)
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut copy = DiGraph::new();
+    let mut positions = vec![NodeIndex::end(); graph.node_bound()];
+    for a in graph.node_identifiers() {
+        positions[graph.to_index(a)] = copy.add_node(a);
+    }
+    (copy, positions)
+}
+
 #[cfg(test)]
 #[test]
 fn test_easy_tred() {
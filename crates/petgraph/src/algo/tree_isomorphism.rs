@@ -0,0 +1,350 @@
+//! Linear-time isomorphism and canonical encodings of trees, with the algorithm of
+//! [Aho, Hopcroft and Ullman][1].
+//!
+//! The encoding of a rooted tree is a string of balanced parentheses: every node is written as an
+//! opening parenthesis, the encodings of its children in canonical order and a closing
+//! parenthesis. Two rooted trees are isomorphic if and only if their encodings are equal. An
+//! unrooted tree is encoded as rooted at its center, or at the center that gives the smaller
+//! encoding if it has two.
+//!
+//! The canonical order of the children is computed level by level from the leaves up, by sorting
+//! the sequences of ranks of the children of the nodes of every level in linear time, so the
+//! encoding of a tree of **|V|** nodes takes **O(|V|)** time, unlike general graph isomorphism.
+//!
+//! [1]: https://dl.acm.org/doi/10.5555/578775
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// Compute the canonical encoding of the rooted tree of the nodes reachable from `root`.
+///
+/// # Arguments
+/// * `graph`: an undirected graph, or a directed graph whose edges go from parents to children.
+/// * `root`: the root of the tree.
+///
+/// # Returns
+/// * `Some(String)`: the encoding, a string of `2 |V|` balanced parentheses, where **|V|** is the
+///   number of nodes reachable from `root`.
+/// * `None`: if the nodes reachable from `root` do not form a tree, because a node can be reached
+///   in two ways.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::rooted_tree_encoding, graph::UnGraph};
+///
+/// // A path of three nodes, rooted at one end or in the middle.
+/// let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// assert_eq!(rooted_tree_encoding(&path, 0.into()).unwrap(), "((()))");
+/// assert_eq!(rooted_tree_encoding(&path, 1.into()).unwrap(), "(()())");
+///
+/// let triangle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// assert_eq!(rooted_tree_encoding(&triangle, 0.into()), None);
+/// ```
+pub fn rooted_tree_encoding<G>(graph: G, root: G::NodeId) -> Option<String>
+where
+    G: IntoNeighbors + NodeIndexable + GraphProp,
+{
+    encode(graph, graph.to_index(root), None)
+}
+
+/// Compute the canonical encoding of an unrooted tree, rooted at its center.
+///
+/// The center is found by removing the leaves of the tree until one or two nodes remain. The
+/// encoding is the same for every relabeling of the tree, and differs between non-isomorphic
+/// trees.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+///
+/// # Returns
+/// * `Some(String)`: the encoding, a string of `2 |V|` balanced parentheses.
+/// * `None`: if the graph is not a tree, because it is empty, disconnected or has a cycle.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::tree_encoding, graph::UnGraph};
+///
+/// // The same path of three nodes, labeled in two ways.
+/// let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let relabeled = UnGraph::<(), ()>::from_edges([(0, 2), (2, 1)]);
+/// assert_eq!(tree_encoding(&path), tree_encoding(&relabeled));
+/// assert_eq!(tree_encoding(&path).unwrap(), "(()())");
+/// ```
+pub fn tree_encoding<G>(graph: G) -> Option<String>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let mut degrees = vec![0; graph.node_bound()];
+    let mut node_count = 0;
+    let mut leaves = Vec::new();
+    for a in graph.node_identifiers() {
+        let index = graph.to_index(a);
+        node_count += 1;
+        degrees[index] = graph.neighbors(a).count();
+        if degrees[index] <= 1 {
+            leaves.push(index);
+        }
+    }
+    if node_count == 0 || degrees.iter().sum::<usize>() != 2 * (node_count - 1) {
+        return None;
+    }
+
+    // Remove the leaves layer by layer, until at most two nodes remain.
+    let mut remaining = node_count;
+    while remaining > 2 {
+        if leaves.is_empty() {
+            // The remaining nodes contain a cycle.
+            return None;
+        }
+        let mut next = Vec::new();
+        for &leaf in &leaves {
+            remaining -= 1;
+            degrees[leaf] = 0;
+            for b in graph.neighbors(graph.from_index(leaf)) {
+                let b = graph.to_index(b);
+                if degrees[b] > 0 {
+                    degrees[b] -= 1;
+                    if degrees[b] == 1 {
+                        next.push(b);
+                    }
+                }
+            }
+        }
+        leaves = next;
+    }
+
+    leaves
+        .into_iter()
+        .map(|center| encode(graph, center, Some(node_count)))
+        .min()
+        .flatten()
+}
+
+/// Check whether the rooted trees of the nodes reachable from `r0` in `g0` and from `r1` in `g1`
+/// are isomorphic, with the roots mapped to each other.
+///
+/// # Arguments
+/// * `g0`: an undirected graph, or a directed graph whose edges go from parents to children.
+/// * `r0`: the root of the first tree.
+/// * `g1`: an undirected graph, or a directed graph whose edges go from parents to children.
+/// * `r1`: the root of the second tree.
+///
+/// # Returns
+/// * `true`: if both are trees, and they are isomorphic.
+/// * `false`: otherwise.
+///
+/// # Complexity
+/// * Time complexity: **O(|V₀| + |E₀| + |V₁| + |E₁|)**.
+/// * Auxiliary space: **O(|V₀| + |V₁|)**.
+///
+/// where **|V₀|**, **|E₀|** are the numbers of nodes and edges of `g0` and **|V₁|**, **|E₁|**
+/// those of `g1`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::is_isomorphic_rooted_trees, graph::UnGraph};
+///
+/// let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// assert!(is_isomorphic_rooted_trees(&path, 0.into(), &path, 2.into()));
+/// assert!(!is_isomorphic_rooted_trees(
+///     &path,
+///     0.into(),
+///     &path,
+///     1.into()
+/// ));
+/// ```
+pub fn is_isomorphic_rooted_trees<G0, G1>(g0: G0, r0: G0::NodeId, g1: G1, r1: G1::NodeId) -> bool
+where
+    G0: IntoNeighbors + NodeIndexable + GraphProp,
+    G1: IntoNeighbors + NodeIndexable + GraphProp,
+{
+    match rooted_tree_encoding(g0, r0) {
+        Some(encoding) => rooted_tree_encoding(g1, r1) == Some(encoding),
+        None => false,
+    }
+}
+
+/// Check whether two unrooted trees are isomorphic.
+///
+/// # Arguments
+/// * `g0`: an undirected graph.
+/// * `g1`: an undirected graph.
+///
+/// # Returns
+/// * `true`: if both graphs are trees, and they are isomorphic.
+/// * `false`: otherwise.
+///
+/// # Complexity
+/// * Time complexity: **O(|V₀| + |E₀| + |V₁| + |E₁|)**.
+/// * Auxiliary space: **O(|V₀| + |V₁|)**.
+///
+/// where **|V₀|**, **|E₀|** are the numbers of nodes and edges of `g0` and **|V₁|**, **|E₁|**
+/// those of `g1`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::is_isomorphic_trees, graph::UnGraph};
+///
+/// let star = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+/// let relabeled = UnGraph::<(), ()>::from_edges([(3, 0), (3, 1), (3, 2)]);
+/// let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+/// assert!(is_isomorphic_trees(&star, &relabeled));
+/// assert!(!is_isomorphic_trees(&star, &path));
+/// ```
+pub fn is_isomorphic_trees<G0, G1>(g0: G0, g1: G1) -> bool
+where
+    G0: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G1: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    match tree_encoding(g0) {
+        Some(encoding) => tree_encoding(g1) == Some(encoding),
+        None => false,
+    }
+}
+
+/// The encoding of the tree rooted at `root`, or `None` if it is not a tree or does not have
+/// `node_count` nodes.
+fn encode<G>(graph: G, root: usize, node_count: Option<usize>) -> Option<String>
+where
+    G: IntoNeighbors + NodeIndexable + GraphProp,
+{
+    // Breadth-first search, which lists the nodes level by level.
+    let undirected = !graph.is_directed();
+    let mut parents = vec![usize::MAX; graph.node_bound()];
+    let mut visited = vec![false; graph.node_bound()];
+    let mut order = vec![root];
+    visited[root] = true;
+    let mut next = 0;
+    while next < order.len() {
+        let a = order[next];
+        next += 1;
+        let mut skipped_parent = false;
+        for b in graph.neighbors(graph.from_index(a)) {
+            let b = graph.to_index(b);
+            // The undirected edge from the parent is listed from both ends.
+            if undirected && b == parents[a] && !skipped_parent {
+                skipped_parent = true;
+                continue;
+            }
+            if visited[b] {
+                return None;
+            }
+            visited[b] = true;
+            parents[b] = a;
+            order.push(b);
+        }
+    }
+    if node_count.is_some_and(|count| count != order.len()) {
+        return None;
+    }
+    let mut depths = vec![0; graph.node_bound()];
+    for &a in &order[1..] {
+        depths[a] = depths[parents[a]] + 1;
+    }
+    let mut levels = vec![0];
+    for i in 1..order.len() {
+        if depths[order[i]] != depths[order[i - 1]] {
+            levels.push(i);
+        }
+    }
+    levels.push(order.len());
+
+    // From the deepest level up, rank the nodes by the sorted ranks of their children, and list
+    // the children of every node by rank.
+    let mut ranks = vec![0; graph.node_bound()];
+    let mut child_ranks: Vec<Vec<usize>> = vec![Vec::new(); graph.node_bound()];
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); graph.node_bound()];
+    let mut alphabet = 0;
+    for level in levels.windows(2).rev() {
+        let nodes = &order[level[0]..level[1]];
+        let sequences: Vec<&[usize]> = nodes.iter().map(|&a| &child_ranks[a][..]).collect();
+        let sorted = sort_sequences(&sequences, alphabet);
+        let mut rank = 0;
+        for (i, &k) in sorted.iter().enumerate() {
+            if i > 0 && sequences[k] != sequences[sorted[i - 1]] {
+                rank += 1;
+            }
+            ranks[nodes[k]] = rank;
+        }
+        alphabet = rank + 1;
+        for &k in &sorted {
+            let a = nodes[k];
+            if a != root {
+                child_ranks[parents[a]].push(ranks[a]);
+                children[parents[a]].push(a);
+            }
+        }
+    }
+
+    let mut encoding = String::with_capacity(2 * order.len());
+    let mut stack = vec![(root, 0)];
+    encoding.push('(');
+    while let Some((a, i)) = stack.pop() {
+        if let Some(&b) = children[a].get(i) {
+            stack.push((a, i + 1));
+            stack.push((b, 0));
+            encoding.push('(');
+        } else {
+            encoding.push(')');
+        }
+    }
+    Some(encoding)
+}
+
+/// Sort sequences of symbols less than `alphabet` lexicographically, a prefix of a sequence
+/// first, in time linear in their total length and `alphabet`, and return the order of their
+/// indices.
+fn sort_sequences(sequences: &[&[usize]], alphabet: usize) -> Vec<usize> {
+    let max_len = sequences.iter().map(|s| s.len()).max().unwrap_or(0);
+
+    // The distinct symbols at every position, in increasing order.
+    let mut positions = vec![Vec::new(); alphabet];
+    for sequence in sequences {
+        for (j, &symbol) in sequence.iter().enumerate() {
+            positions[symbol].push(j);
+        }
+    }
+    let mut symbols: Vec<Vec<usize>> = vec![Vec::new(); max_len];
+    for (symbol, positions) in positions.iter().enumerate() {
+        for &j in positions {
+            if symbols[j].last() != Some(&symbol) {
+                symbols[j].push(symbol);
+            }
+        }
+    }
+
+    let mut by_length = vec![Vec::new(); max_len + 1];
+    for (i, sequence) in sequences.iter().enumerate() {
+        by_length[sequence.len()].push(i);
+    }
+
+    // Radix sort from the last position, where the sequences that end are placed first.
+    let mut queue = Vec::new();
+    let mut buckets = vec![Vec::new(); alphabet];
+    for j in (0..max_len).rev() {
+        let mut longer = core::mem::take(&mut by_length[j + 1]);
+        longer.append(&mut queue);
+        for i in longer {
+            buckets[sequences[i][j]].push(i);
+        }
+        for &symbol in &symbols[j] {
+            queue.append(&mut buckets[symbol]);
+        }
+    }
+    let mut sorted = core::mem::take(&mut by_length[0]);
+    sorted.append(&mut queue);
+    sorted
+}
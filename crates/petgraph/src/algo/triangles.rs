@@ -0,0 +1,310 @@
+//! Triangle counting and the clustering coefficients derived from it.
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::{
+    Undirected,
+    csr::{Csr, IndexType},
+    visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// Number of triangles that contain every node.
+///
+/// This is the compact-forward algorithm of [Latapy][1]: the nodes are ranked by degree, every
+/// edge is oriented towards the node of higher rank, and the triangles are found by intersecting
+/// the sorted out-neighbors of both ends of every edge. Self loops and parallel edges are
+/// ignored.
+///
+/// See [`csr_triangles`] for a variant that works directly on the sorted rows of a [`Csr`].
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+///
+/// # Returns
+/// * `Vec<usize>`: the number of triangles of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|E|^(3/2) + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://doi.org/10.1016/j.tcs.2008.07.017
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::triangles, graph::UnGraph};
+///
+/// // Two triangles sharing the edge (1, 2), with a pendant node.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+/// assert_eq!(triangles(&graph), vec![1, 2, 2, 1, 0]);
+/// ```
+pub fn triangles<G>(graph: G) -> Vec<usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    count_triangles(&simple_neighbors(graph))
+}
+
+/// Number of triangles of every node, given its sorted neighbors.
+fn count_triangles(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    compact_forward(neighbors.len(), |v| neighbors[v].iter().copied())
+}
+
+/// Number of triangles that contain every node of an undirected [`Csr`].
+///
+/// This is the compact-forward algorithm of [Latapy][1], like [`triangles`], but it reads the
+/// neighbors of every node directly from the rows of the graph, which are already sorted and
+/// free of parallel edges, instead of collecting and deduplicating them first. Self loops are
+/// ignored.
+///
+/// See [`triangles`] for other graph types.
+///
+/// # Arguments
+/// * `graph`: an undirected [`Csr`].
+///
+/// # Returns
+/// * `Vec<usize>`: the number of triangles of every node, indexed by node index.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|^(3/2) + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://doi.org/10.1016/j.tcs.2008.07.017
+///
+/// # Example
+/// ```rust
+/// use petgraph::{Undirected, algo::csr_triangles, csr::Csr};
+///
+/// // Two triangles sharing the edge (1, 2), with a pendant node.
+/// let mut graph = Csr::<(), (), Undirected>::with_nodes(5);
+/// for (a, b) in [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)] {
+///     graph.add_edge(a, b, ());
+/// }
+/// assert_eq!(csr_triangles(&graph), vec![1, 2, 2, 1, 0]);
+/// ```
+pub fn csr_triangles<N, E, Ix>(graph: &Csr<N, E, Undirected, Ix>) -> Vec<usize>
+where
+    Ix: IndexType,
+{
+    compact_forward(graph.node_count(), |v| {
+        graph
+            .neighbors_slice(Ix::new(v))
+            .iter()
+            .map(|u| u.index())
+            .filter(move |&u| u != v)
+    })
+}
+
+/// Compact-forward triangle counting over `n` nodes, given the distinct neighbors of every node,
+/// without self loops.
+fn compact_forward<I>(n: usize, neighbors: impl Fn(usize) -> I) -> Vec<usize>
+where
+    I: Iterator<Item = usize>,
+{
+    // Rank the nodes by increasing degree, so that every node has few out-neighbors.
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_cached_key(|&v| neighbors(v).count());
+    let mut rank = vec![0; n];
+    for (r, &v) in order.iter().enumerate() {
+        rank[v] = r;
+    }
+    let forward: Vec<Vec<usize>> = order
+        .iter()
+        .map(|&v| {
+            let mut out: Vec<usize> = neighbors(v)
+                .map(|u| rank[u])
+                .filter(|&r| r > rank[v])
+                .collect();
+            out.sort_unstable();
+            out
+        })
+        .collect();
+
+    let mut counts = vec![0; n];
+    for (r, out) in forward.iter().enumerate() {
+        for &s in out {
+            for_each_common(out, &forward[s], |t| {
+                counts[r] += 1;
+                counts[s] += 1;
+                counts[t] += 1;
+            });
+        }
+    }
+
+    let mut triangles = vec![0; n];
+    for (r, &v) in order.iter().enumerate() {
+        triangles[v] = counts[r];
+    }
+    triangles
+}
+
+/// [Local clustering coefficient][1] of every node.
+///
+/// The clustering coefficient of a node is the fraction of pairs of its neighbors that are
+/// adjacent, that is its number of triangles divided by `d(d - 1) / 2`, where `d` is its degree.
+/// It is zero for nodes with fewer than two neighbors. Self loops and parallel edges are ignored.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+///
+/// # Returns
+/// * `Vec<f64>`: the clustering coefficient of every node, indexed by [`NodeIndexable::to_index`].
+///
+/// # Complexity
+/// * Time complexity: **O(|E|^(3/2) + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Clustering_coefficient#Local_clustering_coefficient
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::clustering, graph::UnGraph};
+///
+/// // Two triangles sharing the edge (1, 2), with a pendant node.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+/// assert_eq!(clustering(&graph), vec![1., 2. / 3., 2. / 3., 1. / 3., 0.]);
+/// ```
+pub fn clustering<G>(graph: G) -> Vec<f64>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let neighbors = simple_neighbors(graph);
+    count_triangles(&neighbors)
+        .into_iter()
+        .zip(neighbors.iter().map(Vec::len))
+        .map(|(t, d)| {
+            if d < 2 {
+                0.
+            } else {
+                2. * t as f64 / (d * (d - 1)) as f64
+            }
+        })
+        .collect()
+}
+
+/// Average of the [local clustering coefficients](clustering) of all nodes.
+///
+/// Nodes with fewer than two neighbors count as zero. The average clustering of a graph without
+/// nodes is zero.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+///
+/// # Returns
+/// * `f64`: the average clustering coefficient.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|^(3/2) + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::average_clustering, graph::UnGraph};
+///
+/// // Two triangles sharing the edge (1, 2), with a pendant node.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+/// assert!((average_clustering(&graph) - 8. / 15.).abs() < 1e-12);
+/// ```
+pub fn average_clustering<G>(graph: G) -> f64
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let coefficients = clustering(graph);
+    let (sum, count) = graph
+        .node_identifiers()
+        .map(|node| coefficients[graph.to_index(node)])
+        .fold((0., 0usize), |(sum, count), c| (sum + c, count + 1));
+    if count == 0 { 0. } else { sum / count as f64 }
+}
+
+/// [Global clustering coefficient][1], or transitivity, of a graph.
+///
+/// The transitivity is the fraction of paths of length two that are closed by an edge, that is
+/// three times the number of triangles divided by the number of such paths. It is zero for
+/// graphs without any path of length two. Self loops and parallel edges are ignored.
+///
+/// # Arguments
+/// * `graph`: an undirected graph. The direction of the edges of a directed graph is ignored.
+///
+/// # Returns
+/// * `f64`: the transitivity of the graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|^(3/2) + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Clustering_coefficient#Global_clustering_coefficient
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::transitivity, graph::UnGraph};
+///
+/// // Two triangles sharing the edge (1, 2), with a pendant node.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+/// assert_eq!(transitivity(&graph), 0.6);
+/// ```
+pub fn transitivity<G>(graph: G) -> f64
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let neighbors = simple_neighbors(graph);
+    let closed: usize = count_triangles(&neighbors).into_iter().sum();
+    let paths: usize = neighbors
+        .iter()
+        .map(|list| list.len() * list.len().saturating_sub(1) / 2)
+        .sum();
+    if paths == 0 {
+        0.
+    } else {
+        closed as f64 / paths as f64
+    }
+}
+
+/// Sorted neighbors of every node, without self loops, parallel edges or edge directions.
+fn simple_neighbors<G>(graph: G) -> Vec<Vec<usize>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut neighbors = vec![Vec::new(); graph.node_bound()];
+    for a in graph.node_identifiers() {
+        let ia = graph.to_index(a);
+        for b in graph.neighbors(a) {
+            let ib = graph.to_index(b);
+            if ia != ib {
+                neighbors[ia].push(ib);
+                neighbors[ib].push(ia);
+            }
+        }
+    }
+    for list in &mut neighbors {
+        list.sort_unstable();
+        list.dedup();
+    }
+    neighbors
+}
+
+/// Call `f` with every element common to the sorted slices `a` and `b`.
+fn for_each_common<T: Copy + Ord>(a: &[T], b: &[T], mut f: impl FnMut(T)) {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                f(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,591 @@
+//! The [VF2++][1] algorithm for graph isomorphism, induced subgraph isomorphism and subgraph
+//! monomorphism.
+//!
+//! Compared to the VF2 matcher of [`isomorphism`](super::isomorphism), the nodes of the pattern
+//! are matched in an order computed upfront, by breadth-first search from the rarest and most
+//! connected nodes, so that every node is matched as early as possible next to nodes that are
+//! already matched. Candidates for a node are then only taken among the neighbors of the image of
+//! one of its matched neighbors. The graphs do not need an adjacency matrix, and may have
+//! parallel edges and self loops.
+//!
+//! [1]: https://doi.org/10.1016/j.dam.2018.02.018
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Reverse;
+
+use super::isomorphism::has_perfect_matching;
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// How the pattern graph must appear in the target graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatchMode {
+    /// The graphs are isomorphic: the mapping is a bijection between their nodes, and two nodes
+    /// of the pattern are joined by as many edges as their images.
+    Isomorphism,
+    /// The pattern is isomorphic to an induced subgraph of the target: two nodes of the pattern
+    /// are joined by as many edges as their images, so the images of non-adjacent nodes are not
+    /// adjacent.
+    InducedSubgraph,
+    /// The pattern is isomorphic to a subgraph of the target, which is not necessarily induced:
+    /// two nodes of the pattern are joined by at most as many edges as their images.
+    Monomorphism,
+}
+
+/// Iterator over the matches of a pattern graph in a target graph, created by [`vf2pp_matches`].
+pub struct Vf2ppMatches<G0, G1, NM, EM>
+where
+    G0: IntoEdgeReferences,
+    G1: IntoEdgeReferences,
+{
+    pattern: Side<G0::NodeId, G0::EdgeRef>,
+    target: Side<G1::NodeId, G1::EdgeRef>,
+    pattern_bound: usize,
+    directed: bool,
+    mode: MatchMode,
+    node_match: NM,
+    edge_match: EM,
+    /// The nodes of the pattern, in the order in which they are matched.
+    order: Vec<usize>,
+    /// For every position of `order`, a neighbor earlier in the order, and whether the edge goes
+    /// from the node to that neighbor.
+    parents: Vec<Option<(usize, bool)>>,
+    /// The image of every node of the pattern, or `usize::MAX`.
+    mapping: Vec<usize>,
+    /// The preimage of every node of the target, or `usize::MAX`.
+    reverse: Vec<usize>,
+    stack: Vec<Frame>,
+    /// Whether the empty mapping, the only match of an empty pattern, is still to be returned.
+    empty_pending: bool,
+}
+
+/// The candidates of one node of the pattern.
+struct Frame {
+    candidates: Vec<usize>,
+    next: usize,
+    current: Option<usize>,
+}
+
+/// The nodes of a graph, numbered compactly, with their edges sorted by the other end.
+///
+/// Shared with the [`similarity`](super::similarity) algorithms.
+pub(crate) struct Side<N, E> {
+    pub(crate) nodes: Vec<N>,
+    /// The index of every node, by [`NodeIndexable::to_index`].
+    pub(crate) indices: Vec<usize>,
+    pub(crate) edges: Vec<E>,
+    /// The compact indices of the ends of every edge.
+    pub(crate) ends: Vec<(usize, usize)>,
+    /// The outgoing edges of every node, or all of its edges if undirected, as the other end and
+    /// the position of the edge in `edges`.
+    pub(crate) outgoing: Vec<Vec<(usize, usize)>>,
+    /// The incoming edges of every node if directed.
+    pub(crate) incoming: Vec<Vec<(usize, usize)>>,
+}
+
+impl<N: Copy, E: EdgeRef<NodeId = N>> Side<N, E> {
+    pub(crate) fn new<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences<NodeId = N, EdgeRef = E> + IntoNodeIdentifiers + NodeIndexable,
+        G: GraphProp,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let indices: Vec<usize> = nodes.iter().map(|&node| graph.to_index(node)).collect();
+        let mut compact = vec![usize::MAX; graph.node_bound()];
+        for (i, &index) in indices.iter().enumerate() {
+            compact[index] = i;
+        }
+
+        let directed = graph.is_directed();
+        let mut edges = Vec::new();
+        let mut ends = Vec::new();
+        let mut outgoing = vec![Vec::new(); nodes.len()];
+        let mut incoming = vec![Vec::new(); if directed { nodes.len() } else { 0 }];
+        for edge in graph.edge_references() {
+            let a = compact[graph.to_index(edge.source())];
+            let b = compact[graph.to_index(edge.target())];
+            let e = edges.len();
+            edges.push(edge);
+            ends.push((a, b));
+            outgoing[a].push((b, e));
+            if directed {
+                incoming[b].push((a, e));
+            } else if a != b {
+                outgoing[b].push((a, e));
+            }
+        }
+        for list in outgoing.iter_mut().chain(&mut incoming) {
+            list.sort_by_key(|&(other, _)| other);
+        }
+        Side {
+            nodes,
+            indices,
+            edges,
+            ends,
+            outgoing,
+            incoming,
+        }
+    }
+}
+
+impl<N, E> Side<N, E> {
+    pub(crate) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(crate) fn degree(&self, a: usize) -> usize {
+        self.outgoing[a].len() + self.incoming.get(a).map_or(0, Vec::len)
+    }
+
+    /// The neighbors of `a` in both directions, once per edge.
+    fn neighbors(&self, a: usize) -> impl Iterator<Item = usize> + '_ {
+        self.outgoing[a]
+            .iter()
+            .chain(self.incoming.get(a).into_iter().flatten())
+            .map(|&(b, _)| b)
+    }
+
+    /// The edges from `a` to `b`, or between them if undirected.
+    pub(crate) fn between(&self, a: usize, b: usize) -> &[(usize, usize)] {
+        edges_to(&self.outgoing[a], b)
+    }
+}
+
+/// The edges of a sorted edge list whose other end is `b`.
+fn edges_to<E>(list: &[(usize, E)], b: usize) -> &[(usize, E)] {
+    let start = list.partition_point(|&(other, _)| other < b);
+    let end = start + list[start..].partition_point(|&(other, _)| other == b);
+    &list[start..end]
+}
+
+/// Iterate over the matches of the pattern graph `g0` in the target graph `g1` with the
+/// [VF2++][1] algorithm.
+///
+/// The nodes of the pattern are ordered by breadth-first search from the node with the fewest
+/// candidates, and within every level of the search by decreasing number of neighbors already
+/// ordered, then by decreasing degree. Every node is then matched to a neighbor of the image of
+/// an already matched neighbor, if any, while the number of edges between matched nodes and the
+/// degrees allow it. Parallel edges are compared by number, and the edges of the pattern between
+/// two nodes must match distinct edges between the images of their ends.
+///
+/// See [`MatchMode`] for the kinds of matches. Use [`MatchMode::Monomorphism`] to find a pattern
+/// in a graph regardless of the other edges between the nodes that it matches.
+///
+/// # Arguments
+/// * `g0`: the pattern graph.
+/// * `g1`: the target graph, with the same direction as the pattern.
+/// * `mode`: the kind of matches.
+/// * `node_match`: closure that returns whether a node of the pattern may be mapped to a node of
+///   the target. Use `|_, _| true` to only match the structure.
+/// * `edge_match`: closure that returns whether an edge of the pattern may be mapped to an edge of
+///   the target. Use `|_, _| true` to only match the structure.
+///
+/// # Returns
+/// * [`Vf2ppMatches`]: an iterator over the mappings. Every mapping is indexed by the
+///   [`NodeIndexable::to_index`] of the nodes of the pattern and contains the index of their image,
+///   or `usize::MAX` for the indices that are not nodes.
+///
+/// # Complexity
+/// * Time complexity: exponential in the worst case, and **O(|V₀| |V₁|)** to order the pattern.
+/// * Auxiliary space: **O(|V₀| + |E₀| + |V₁| + |E₁|)**, and **O(|V₀| |V₁|)** for the candidates.
+///
+/// where **|V₀|**, **|E₀|** are the numbers of nodes and edges of the pattern and **|V₁|**,
+/// **|E₁|** those of the target.
+///
+/// [1]: https://doi.org/10.1016/j.dam.2018.02.018
+///
+/// # Example
+/// ```rust
+/// use petgraph::{
+///     algo::{MatchMode, vf2pp_matches},
+///     graph::UnGraph,
+/// };
+///
+/// // A path of three nodes in a triangle.
+/// let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let triangle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+///
+/// // The triangle has no induced path, but the path is a subgraph in six ways.
+/// let induced = vf2pp_matches(
+///     &path,
+///     &triangle,
+///     MatchMode::InducedSubgraph,
+///     |_, _| true,
+///     |_, _| true,
+/// );
+/// assert_eq!(induced.count(), 0);
+/// let mut monomorphisms: Vec<_> = vf2pp_matches(
+///     &path,
+///     &triangle,
+///     MatchMode::Monomorphism,
+///     |_, _| true,
+///     |_, _| true,
+/// )
+/// .collect();
+/// assert_eq!(monomorphisms.len(), 6);
+/// monomorphisms.sort();
+/// assert_eq!(monomorphisms[0], vec![0, 1, 2]);
+/// ```
+pub fn vf2pp_matches<G0, G1, NM, EM>(
+    g0: G0,
+    g1: G1,
+    mode: MatchMode,
+    mut node_match: NM,
+    edge_match: EM,
+) -> Vf2ppMatches<G0, G1, NM, EM>
+where
+    G0: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G1: IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = G0::EdgeType>,
+    NM: FnMut(G0::NodeId, G1::NodeId) -> bool,
+    EM: FnMut(G0::EdgeRef, G1::EdgeRef) -> bool,
+{
+    let pattern = Side::new(g0);
+    let target = Side::new(g1);
+    let (n0, n1) = (pattern.len(), target.len());
+    let possible = match mode {
+        MatchMode::Isomorphism => n0 == n1 && pattern.edges.len() == target.edges.len(),
+        MatchMode::InducedSubgraph | MatchMode::Monomorphism => {
+            n0 <= n1 && pattern.edges.len() <= target.edges.len()
+        }
+    };
+
+    // The number of candidates of every node of the pattern, by match and degrees.
+    let degrees_allow = |u: usize, v: usize| {
+        let (out0, out1) = (pattern.outgoing[u].len(), target.outgoing[v].len());
+        let in0 = pattern.incoming.get(u).map_or(0, Vec::len);
+        let in1 = target.incoming.get(v).map_or(0, Vec::len);
+        match mode {
+            MatchMode::Isomorphism => out0 == out1 && in0 == in1,
+            _ => out0 <= out1 && in0 <= in1,
+        }
+    };
+    let mut rarity = vec![0; n0];
+    if possible {
+        for (u, rarity) in rarity.iter_mut().enumerate() {
+            *rarity = (0..n1)
+                .filter(|&v| degrees_allow(u, v) && node_match(pattern.nodes[u], target.nodes[v]))
+                .count();
+        }
+    }
+    let possible = possible && rarity.iter().all(|&r| r > 0);
+
+    let order = matching_order(&pattern, &rarity);
+    let mut position = vec![0; n0];
+    for (i, &u) in order.iter().enumerate() {
+        position[u] = i;
+    }
+    let parents = order
+        .iter()
+        .enumerate()
+        .map(|(i, &u)| {
+            let earlier = |&&(w, _): &&(usize, usize)| position[w] < i;
+            pattern.outgoing[u]
+                .iter()
+                .find(earlier)
+                .map(|&(w, _)| (w, true))
+                .or_else(|| {
+                    pattern
+                        .incoming
+                        .get(u)
+                        .and_then(|list| list.iter().find(earlier))
+                        .map(|&(w, _)| (w, false))
+                })
+        })
+        .collect();
+
+    let mut matches = Vf2ppMatches {
+        pattern_bound: g0.node_bound(),
+        directed: g0.is_directed(),
+        mode,
+        node_match,
+        edge_match,
+        order,
+        parents,
+        mapping: vec![usize::MAX; n0],
+        reverse: vec![usize::MAX; n1],
+        stack: Vec::new(),
+        empty_pending: possible && n0 == 0,
+        pattern,
+        target,
+    };
+    if possible && n0 > 0 {
+        let candidates = matches.candidates(0);
+        matches.stack.push(Frame {
+            candidates,
+            next: 0,
+            current: None,
+        });
+    }
+    matches
+}
+
+/// Return `true` if the graphs `g0` and `g1` are isomorphic, with the [VF2++][1] algorithm.
+///
+/// Only the structure of the graphs is compared. Use [`vf2pp_matches`] with
+/// [`MatchMode::Isomorphism`] to also compare the weights of the nodes and edges.
+///
+/// # Arguments
+/// * `g0`: a directed or undirected graph, which may have parallel edges and self loops.
+/// * `g1`: a graph with the same direction as `g0`.
+///
+/// # Returns
+/// * `true` if the graphs are isomorphic, `false` otherwise.
+///
+/// # Complexity
+/// * Time complexity: exponential in the worst case, and **O(|V|²)** to order the nodes.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// [1]: https://doi.org/10.1016/j.dam.2018.02.018
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::vf2pp_is_isomorphic, graph::DiGraph};
+///
+/// let a = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// let b = DiGraph::<(), ()>::from_edges([(1, 0), (0, 2), (2, 1)]);
+/// let c = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2)]);
+/// assert!(vf2pp_is_isomorphic(&a, &b));
+/// assert!(!vf2pp_is_isomorphic(&a, &c));
+/// ```
+pub fn vf2pp_is_isomorphic<G0, G1>(g0: G0, g1: G1) -> bool
+where
+    G0: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G1: IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = G0::EdgeType>,
+{
+    vf2pp_matches(g0, g1, MatchMode::Isomorphism, |_, _| true, |_, _| true)
+        .next()
+        .is_some()
+}
+
+/// The order in which the nodes of the pattern are matched.
+fn matching_order<N, E>(pattern: &Side<N, E>, rarity: &[usize]) -> Vec<usize> {
+    let n = pattern.len();
+    let mut visited = vec![false; n];
+    let mut connections = vec![0; n];
+    let mut order = Vec::with_capacity(n);
+    while order.len() < n {
+        let root = (0..n)
+            .filter(|&u| !visited[u])
+            .min_by_key(|&u| (rarity[u], Reverse(pattern.degree(u))))
+            .unwrap();
+        visited[root] = true;
+        let mut level = vec![root];
+        while !level.is_empty() {
+            let mut next_level = Vec::new();
+            for &u in &level {
+                for w in pattern.neighbors(u) {
+                    if !visited[w] {
+                        visited[w] = true;
+                        next_level.push(w);
+                    }
+                }
+            }
+            while !level.is_empty() {
+                let k = (0..level.len())
+                    .max_by_key(|&k| {
+                        let u = level[k];
+                        (connections[u], pattern.degree(u), Reverse(rarity[u]))
+                    })
+                    .unwrap();
+                let u = level.swap_remove(k);
+                order.push(u);
+                for w in pattern.neighbors(u) {
+                    connections[w] += 1;
+                }
+            }
+            level = next_level;
+        }
+    }
+    order
+}
+
+impl<G0, G1, NM, EM> Vf2ppMatches<G0, G1, NM, EM>
+where
+    G0: IntoEdgeReferences,
+    G1: IntoEdgeReferences,
+    NM: FnMut(G0::NodeId, G1::NodeId) -> bool,
+    EM: FnMut(G0::EdgeRef, G1::EdgeRef) -> bool,
+{
+    /// The candidate images of the node at position `depth` of the order, given the mapping of
+    /// the previous nodes.
+    fn candidates(&self, depth: usize) -> Vec<usize> {
+        let Some((w, outgoing)) = self.parents[depth] else {
+            return (0..self.target.len())
+                .filter(|&v| self.reverse[v] == usize::MAX)
+                .collect();
+        };
+        let image = self.mapping[w];
+        // An edge from the node to its parent maps to an edge to the image of the parent.
+        let list = if outgoing && self.directed {
+            &self.target.incoming[image]
+        } else {
+            &self.target.outgoing[image]
+        };
+        let mut candidates: Vec<usize> = list
+            .iter()
+            .map(|&(v, _)| v)
+            .filter(|&v| self.reverse[v] == usize::MAX)
+            .collect();
+        candidates.dedup();
+        candidates
+    }
+
+    fn is_feasible(&mut self, u: usize, v: usize) -> bool {
+        let (pattern, target) = (&self.pattern, &self.target);
+        if !(self.node_match)(pattern.nodes[u], target.nodes[v]) {
+            return false;
+        }
+        let state = State {
+            u,
+            v,
+            mode: self.mode,
+            mapping: &self.mapping,
+            reverse: &self.reverse,
+        };
+        let edge_match = &mut |e0, e1| (self.edge_match)(pattern.edges[e0], target.edges[e1]);
+        state.edges_allow(&pattern.outgoing[u], &target.outgoing[v], edge_match)
+            && (!self.directed
+                || state.edges_allow(&pattern.incoming[u], &target.incoming[v], edge_match))
+    }
+
+    fn current_mapping(&self) -> Vec<usize> {
+        let mut mapping = vec![usize::MAX; self.pattern_bound];
+        for (u, &v) in self.mapping.iter().enumerate() {
+            mapping[self.pattern.indices[u]] = self.target.indices[v];
+        }
+        mapping
+    }
+}
+
+/// The pair of nodes whose feasibility is checked, with the current mapping.
+struct State<'a> {
+    u: usize,
+    v: usize,
+    mode: MatchMode,
+    mapping: &'a [usize],
+    reverse: &'a [usize],
+}
+
+impl State<'_> {
+    /// Whether the edges of `u` and `v` in one direction allow mapping `u` to `v`.
+    fn edges_allow<E0: Copy, E1: Copy>(
+        &self,
+        list0: &[(usize, E0)],
+        list1: &[(usize, E1)],
+        edge_match: &mut impl FnMut(E0, E1) -> bool,
+    ) -> bool {
+        let exact = self.mode != MatchMode::Monomorphism;
+        if list0.len() > list1.len()
+            || (self.mode == MatchMode::Isomorphism && list0.len() != list1.len())
+        {
+            return false;
+        }
+
+        // The edges of `u` to matched nodes must have images.
+        let mut matched0 = 0;
+        let mut i = 0;
+        while i < list0.len() {
+            let w = list0[i].0;
+            let run0 = edges_to(&list0[i..], w);
+            i += run0.len();
+            let image = if w == self.u { self.v } else { self.mapping[w] };
+            if image == usize::MAX {
+                continue;
+            }
+            let run1 = edges_to(list1, image);
+            if run0.len() > run1.len() || (exact && run0.len() != run1.len()) {
+                return false;
+            }
+            let allowed = if let [(_, e0)] = *run0 {
+                run1.iter().any(|&(_, e1)| edge_match(e0, e1))
+            } else {
+                // Parallel edges must match distinct edges.
+                let compatible: Vec<Vec<bool>> = run0
+                    .iter()
+                    .map(|&(_, e0)| run1.iter().map(|&(_, e1)| edge_match(e0, e1)).collect())
+                    .collect();
+                has_perfect_matching(&compatible)
+            };
+            if !allowed {
+                return false;
+            }
+            matched0 += run0.len();
+        }
+
+        // Without extra edges between matched nodes if exact, and with enough edges left.
+        let matched1 = list1
+            .iter()
+            .filter(|&&(y, _)| y == self.v || self.reverse[y] != usize::MAX)
+            .count();
+        if exact && matched1 != matched0 {
+            return false;
+        }
+        let (free0, free1) = (list0.len() - matched0, list1.len() - matched1);
+        if self.mode == MatchMode::Isomorphism {
+            free0 == free1
+        } else {
+            free0 <= free1
+        }
+    }
+}
+
+impl<G0, G1, NM, EM> Iterator for Vf2ppMatches<G0, G1, NM, EM>
+where
+    G0: IntoEdgeReferences,
+    G1: IntoEdgeReferences,
+    NM: FnMut(G0::NodeId, G1::NodeId) -> bool,
+    EM: FnMut(G0::EdgeRef, G1::EdgeRef) -> bool,
+{
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.empty_pending {
+            self.empty_pending = false;
+            return Some(self.current_mapping());
+        }
+        while let Some(depth) = self.stack.len().checked_sub(1) {
+            let u = self.order[depth];
+            if let Some(v) = self.stack[depth].current.take() {
+                self.mapping[u] = usize::MAX;
+                self.reverse[v] = usize::MAX;
+            }
+
+            let mut found = None;
+            while let Some(&v) = {
+                let frame = &self.stack[depth];
+                frame.candidates.get(frame.next)
+            } {
+                self.stack[depth].next += 1;
+                if self.is_feasible(u, v) {
+                    found = Some(v);
+                    break;
+                }
+            }
+            let Some(v) = found else {
+                self.stack.pop();
+                continue;
+            };
+
+            self.mapping[u] = v;
+            self.reverse[v] = u;
+            self.stack[depth].current = Some(v);
+            if depth + 1 == self.order.len() {
+                return Some(self.current_mapping());
+            }
+            let candidates = self.candidates(depth + 1);
+            self.stack.push(Frame {
+                candidates,
+                next: 0,
+                current: None,
+            });
+        }
+        None
+    }
+}
@@ -0,0 +1,273 @@
+//! [Weisfeiler-Lehman][1] color refinement and graph hashing.
+//!
+//! Every node starts with a label, and at every iteration the label of a node is replaced by a
+//! combination of its own label with the multiset of the labels of its neighbors and of the edges
+//! to them. Isomorphic graphs get equal labels, so different labels prove that two graphs are not
+//! isomorphic, but equal labels do not prove that they are.
+//!
+//! The hashes are deterministic: they only depend on the labels given by the user and on the
+//! structure of the graph, so they can be stored and compared across runs and platforms.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Weisfeiler_Leman_graph_isomorphism_test
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    rng::{GOLDEN_GAMMA, mix64},
+    visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeIndexable},
+};
+
+/// Stable coloring of the nodes by the 1-dimensional Weisfeiler-Lehman algorithm, also known as
+/// color refinement.
+///
+/// The nodes are first colored by label, and the colors are refined until two nodes have the
+/// same color only if they have the same number of edges of every label to the nodes of every
+/// color. The refinement compares colors exactly rather than by hash, so no collision can merge
+/// two colors. For a directed graph, a node is distinguished by its outgoing edges.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `node_label`: closure that returns the initial label of a node.
+/// * `edge_label`: closure that returns the label of an edge.
+///
+/// # Returns
+/// * `Vec<usize>`: the color of every node, indexed by [`NodeIndexable::to_index`]. The colors are
+///   numbered from zero, in an order that only depends on the labels and the structure of the
+///   graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| (|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::color_refinement, graph::UnGraph};
+///
+/// // A path of five nodes: the nodes at the same distance from the middle are equivalent.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let colors = color_refinement(&graph, |_| 0, |_| 0);
+/// assert_eq!(colors[0], colors[4]);
+/// assert_eq!(colors[1], colors[3]);
+/// assert_ne!(colors[0], colors[1]);
+/// assert_ne!(colors[1], colors[2]);
+/// ```
+pub fn color_refinement<G, NL, EL>(graph: G, node_label: NL, edge_label: EL) -> Vec<usize>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    NL: FnMut(G::NodeId) -> u64,
+    EL: FnMut(G::EdgeRef) -> u64,
+{
+    let (nodes, labels, neighbors) = labeled_neighbors(graph, node_label, edge_label);
+
+    let mut colors = labels.clone();
+    let mut count = 0;
+    loop {
+        let signatures: Vec<(u64, Vec<(u64, u64)>)> = nodes
+            .iter()
+            .map(|&v| {
+                let mut signature: Vec<(u64, u64)> = neighbors[v]
+                    .iter()
+                    .map(|&(u, label)| (label, colors[u]))
+                    .collect();
+                signature.sort_unstable();
+                (colors[v], signature)
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+        order.sort_by(|&a, &b| signatures[a].cmp(&signatures[b]));
+
+        let mut next = 0;
+        for (i, &k) in order.iter().enumerate() {
+            if i > 0 && signatures[k] != signatures[order[i - 1]] {
+                next += 1;
+            }
+            colors[nodes[k]] = next;
+        }
+        let refined = if nodes.is_empty() { 0 } else { next + 1 };
+        if refined == count {
+            return colors.into_iter().map(|c| c as usize).collect();
+        }
+        count = refined;
+    }
+}
+
+/// [Weisfeiler-Lehman subtree hash][1] of a whole graph.
+///
+/// The labels of the nodes are refined `iterations` times, and the hash combines the multisets
+/// of the labels of all nodes after every iteration. Isomorphic graphs have equal hashes, so
+/// graphs with different hashes are not isomorphic, which makes the hash a fast pre-filter before
+/// an exact test such as [`is_isomorphic_matching`](super::is_isomorphic_matching). For a
+/// directed graph, a node aggregates the labels of its successors.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `node_label`: closure that returns the initial label of a node, for example a hash of its
+///   weight. Use `|_| 0` to ignore the nodes' weights.
+/// * `edge_label`: closure that returns the label of an edge. Use `|_| 0` to ignore the edges'
+///   weights.
+/// * `iterations`: the number of refinement iterations, which is the depth of the subtrees around
+///   every node that the hash takes into account.
+///
+/// # Returns
+/// * `u64`: the hash of the graph.
+///
+/// # Complexity
+/// * Time complexity: **O(iterations (|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://www.jmlr.org/papers/v12/shervashidze11a.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::weisfeiler_lehman_graph_hash, graph::UnGraph};
+///
+/// let triangle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// let relabeled = UnGraph::<(), ()>::from_edges([(2, 1), (0, 2), (1, 0)]);
+/// let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let hash = |graph: &UnGraph<(), ()>| weisfeiler_lehman_graph_hash(graph, |_| 0, |_| 0, 3);
+/// assert_eq!(hash(&triangle), hash(&relabeled));
+/// assert_ne!(hash(&triangle), hash(&path));
+/// ```
+pub fn weisfeiler_lehman_graph_hash<G, NL, EL>(
+    graph: G,
+    node_label: NL,
+    edge_label: EL,
+    iterations: usize,
+) -> u64
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    NL: FnMut(G::NodeId) -> u64,
+    EL: FnMut(G::EdgeRef) -> u64,
+{
+    let (nodes, mut labels, neighbors) = labeled_neighbors(graph, node_label, edge_label);
+    let mut hash = combine(0, nodes.len() as u64);
+    for iteration in 0..=iterations {
+        if iteration > 0 {
+            labels = refine(&nodes, &labels, &neighbors);
+        }
+        let mut histogram: Vec<u64> = nodes.iter().map(|&v| labels[v]).collect();
+        histogram.sort_unstable();
+        hash = histogram.into_iter().fold(hash, combine);
+    }
+    hash
+}
+
+/// [Weisfeiler-Lehman subtree hashes][1] of the neighborhood of every node.
+///
+/// The labels of the nodes are refined `iterations` times, and the label of a node after `i`
+/// iterations is a hash of the subtree of depth `i` of the walks from it. The hashes of the same
+/// node at increasing depths can be used as features of the node, and nodes of isomorphic graphs
+/// that correspond to each other have equal hashes. For a directed graph, a node aggregates the
+/// labels of its successors.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `node_label`: closure that returns the initial label of a node, for example a hash of its
+///   weight. Use `|_| 0` to ignore the nodes' weights.
+/// * `edge_label`: closure that returns the label of an edge. Use `|_| 0` to ignore the edges'
+///   weights.
+/// * `iterations`: the number of refinement iterations.
+///
+/// # Returns
+/// * `Vec<Vec<u64>>`: for every node, indexed by [`NodeIndexable::to_index`], its hashes after each
+///   of the `iterations` iterations, by increasing depth.
+///
+/// # Complexity
+/// * Time complexity: **O(iterations (|V| + |E|) log |V|)**.
+/// * Auxiliary space: **O(iterations |V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+///
+/// [1]: https://www.jmlr.org/papers/v12/shervashidze11a.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::{algo::weisfeiler_lehman_subgraph_hashes, graph::UnGraph};
+///
+/// // A star: the leaves are equivalent, and differ from the center from the first iteration.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+/// let hashes = weisfeiler_lehman_subgraph_hashes(&graph, |_| 0, |_| 0, 2);
+/// assert_eq!(hashes[1].len(), 2);
+/// assert_eq!(hashes[1], hashes[2]);
+/// assert_ne!(hashes[0][0], hashes[1][0]);
+/// ```
+pub fn weisfeiler_lehman_subgraph_hashes<G, NL, EL>(
+    graph: G,
+    node_label: NL,
+    edge_label: EL,
+    iterations: usize,
+) -> Vec<Vec<u64>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    NL: FnMut(G::NodeId) -> u64,
+    EL: FnMut(G::EdgeRef) -> u64,
+{
+    let (nodes, mut labels, neighbors) = labeled_neighbors(graph, node_label, edge_label);
+    let mut hashes = vec![Vec::with_capacity(iterations); graph.node_bound()];
+    for _ in 0..iterations {
+        labels = refine(&nodes, &labels, &neighbors);
+        for &v in &nodes {
+            hashes[v].push(labels[v]);
+        }
+    }
+    hashes
+}
+
+/// The indices of the nodes, their labels and the neighbors and edge labels of every node, all
+/// indexed by [`NodeIndexable::to_index`].
+#[allow(clippy::type_complexity)]
+fn labeled_neighbors<G, NL, EL>(
+    graph: G,
+    mut node_label: NL,
+    mut edge_label: EL,
+) -> (Vec<usize>, Vec<u64>, Vec<Vec<(usize, u64)>>)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    NL: FnMut(G::NodeId) -> u64,
+    EL: FnMut(G::EdgeRef) -> u64,
+{
+    let mut nodes = Vec::new();
+    let mut labels = vec![0; graph.node_bound()];
+    let mut neighbors = vec![Vec::new(); graph.node_bound()];
+    for a in graph.node_identifiers() {
+        let index = graph.to_index(a);
+        nodes.push(index);
+        labels[index] = node_label(a);
+        for edge in graph.edges(a) {
+            let b = if edge.source() == a {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            neighbors[index].push((graph.to_index(b), edge_label(edge)));
+        }
+    }
+    (nodes, labels, neighbors)
+}
+
+/// One iteration of refinement: the new label of every node hashes its label with the sorted
+/// labels of its neighbors and edges.
+fn refine(nodes: &[usize], labels: &[u64], neighbors: &[Vec<(usize, u64)>]) -> Vec<u64> {
+    let mut refined = vec![0; labels.len()];
+    let mut multiset = Vec::new();
+    for &v in nodes {
+        multiset.clear();
+        multiset.extend(
+            neighbors[v]
+                .iter()
+                .map(|&(u, label)| combine(label, labels[u])),
+        );
+        multiset.sort_unstable();
+        refined[v] = multiset.iter().copied().fold(labels[v], combine);
+    }
+    refined
+}
+
+/// Hash `value` into `seed`, with the finalizer of SplitMix64.
+fn combine(seed: u64, value: u64) -> u64 {
+    mix64((seed.rotate_left(29) ^ value).wrapping_add(GOLDEN_GAMMA))
+}
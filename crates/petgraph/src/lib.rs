@@ -516,6 +516,7 @@ mod iter_utils;
 pub mod matrix_graph;
 #[cfg(feature = "quickcheck")]
 mod quickcheck;
+mod rng;
 #[cfg(feature = "serde-1")]
 mod serde_utils;
 mod traits_graph;
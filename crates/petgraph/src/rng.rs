@@ -0,0 +1,54 @@
+//! A small, seedable pseudo-random number generator for randomized algorithms.
+//!
+//! `petgraph` does not depend on `rand`; algorithms that need randomness take a `u64` seed
+//! instead and draw from this generator, which makes their results reproducible.
+
+/// The increment of the state of [`SplitMix64`], the golden ratio in 64-bit fixed point.
+pub(crate) const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The finalizer of [`SplitMix64`]: a bijection of `u64` that spreads every input bit over the
+/// whole output, usable as a fast non-cryptographic hash of integers.
+pub(crate) const fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// The [SplitMix64][1] generator.
+///
+/// It is fast and has a state of a single `u64`, but it is not cryptographically secure.
+///
+/// [1]: https://prng.di.unimi.it/splitmix64.c
+#[derive(Clone, Debug)]
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Create a new generator from `seed`.
+    pub(crate) const fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    /// Return the next pseudo-random `u64`.
+    pub(crate) const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        mix64(self.state)
+    }
+
+    /// Return a pseudo-random index in `0..n`.
+    ///
+    /// **Panics** if `n` is zero.
+    pub(crate) fn gen_index(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot sample from an empty range");
+        // Lemire's multiply-shift reduction; the bias is negligible for graph sizes.
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// Shuffle `items` in place, with the Fisher-Yates algorithm.
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.gen_index(i + 1));
+        }
+    }
+}
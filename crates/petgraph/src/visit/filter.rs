@@ -7,8 +7,8 @@ use crate::{
     data::DataMap,
     prelude::*,
     visit::{
-        Data, EdgeIndexable, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
-        IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+        Data, EdgeIndexable, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences,
+        IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
         IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeIndexable, NodeRef, VisitMap,
         Visitable,
     },
@@ -357,6 +357,22 @@ where
     }
 }
 
+impl<G, F> GetAdjacencyMatrix for NodeFiltered<G, F>
+where
+    G: GetAdjacencyMatrix,
+    F: FilterNode<G::NodeId>,
+{
+    type AdjMatrix = G::AdjMatrix;
+
+    fn adjacency_matrix(&self) -> Self::AdjMatrix {
+        self.0.adjacency_matrix()
+    }
+
+    fn is_adjacent(&self, matrix: &Self::AdjMatrix, a: Self::NodeId, b: Self::NodeId) -> bool {
+        self.1.include_node(a) && self.1.include_node(b) && self.0.is_adjacent(matrix, a, b)
+    }
+}
+
 macro_rules! access0 {
     ($e:expr) => {
         $e.0
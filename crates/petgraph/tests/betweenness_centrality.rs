@@ -0,0 +1,282 @@
+#[cfg(feature = "rayon")]
+use petgraph::algo::{parallel_betweenness_centrality, parallel_weighted_betweenness_centrality};
+use petgraph::{
+    EdgeType, Graph,
+    algo::{
+        betweenness_centrality, edge_betweenness_centrality, weighted_betweenness_centrality,
+        weighted_edge_betweenness_centrality,
+    },
+    graph::{DiGraph, UnGraph},
+    visit::EdgeRef,
+};
+use rand::Rng;
+use utils::{random_graph, seeded};
+
+mod utils;
+
+/// Distances and numbers of shortest paths between all pairs of nodes.
+fn all_pairs_shortest_paths<Ty: EdgeType>(
+    graph: &Graph<(), u32, Ty>,
+    weighted: bool,
+) -> (Vec<Vec<Option<u32>>>, Vec<Vec<f64>>) {
+    let n = graph.node_count();
+    let mut arcs = Vec::new();
+    for e in graph.edge_references() {
+        let (a, b) = (e.source().index(), e.target().index());
+        let w = if weighted { *e.weight() } else { 1 };
+        if a != b {
+            arcs.push((a, b, w));
+            if !Ty::is_directed() {
+                arcs.push((b, a, w));
+            }
+        }
+    }
+
+    let mut dist = vec![vec![None; n]; n];
+    for (s, row) in dist.iter_mut().enumerate() {
+        row[s] = Some(0);
+    }
+    for &(a, b, w) in &arcs {
+        if dist[a][b].is_none_or(|d| w < d) {
+            dist[a][b] = Some(w);
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if let (Some(x), Some(y)) = (dist[i][k], dist[k][j])
+                    && dist[i][j].is_none_or(|d| x + y < d)
+                {
+                    dist[i][j] = Some(x + y);
+                }
+            }
+        }
+    }
+
+    let mut sigma = vec![vec![0.; n]; n];
+    for s in 0..n {
+        let mut order = (0..n).filter(|&t| dist[s][t].is_some()).collect::<Vec<_>>();
+        order.sort_by_key(|&t| dist[s][t]);
+        sigma[s][s] = 1.;
+        for &t in &order[1..] {
+            for &(u, v, w) in &arcs {
+                if v == t && dist[s][u].map(|d| d + w) == dist[s][t] {
+                    sigma[s][t] += sigma[s][u];
+                }
+            }
+        }
+    }
+    (dist, sigma)
+}
+
+fn brute_force_node_betweenness<Ty: EdgeType>(
+    graph: &Graph<(), u32, Ty>,
+    weighted: bool,
+) -> Vec<f64> {
+    let n = graph.node_count();
+    let (dist, sigma) = all_pairs_shortest_paths(graph, weighted);
+    let mut scores = vec![0.; n];
+    for (v, score) in scores.iter_mut().enumerate() {
+        for s in (0..n).filter(|&s| s != v) {
+            for t in (0..n).filter(|&t| t != v && t != s) {
+                if let (Some(a), Some(b), Some(d)) = (dist[s][v], dist[v][t], dist[s][t])
+                    && a + b == d
+                {
+                    *score += sigma[s][v] * sigma[v][t] / sigma[s][t];
+                }
+            }
+        }
+        if !Ty::is_directed() {
+            *score /= 2.;
+        }
+    }
+    scores
+}
+
+fn brute_force_edge_betweenness<Ty: EdgeType>(
+    graph: &Graph<(), u32, Ty>,
+    weighted: bool,
+) -> Vec<f64> {
+    let n = graph.node_count();
+    let (dist, sigma) = all_pairs_shortest_paths(graph, weighted);
+    graph
+        .edge_references()
+        .map(|e| {
+            let w = if weighted { *e.weight() } else { 1 };
+            let (a, b) = (e.source().index(), e.target().index());
+            if a == b {
+                return 0.;
+            }
+            let orientations = if Ty::is_directed() { 1 } else { 2 };
+            let mut score = 0.;
+            for (a, b) in [(a, b), (b, a)].into_iter().take(orientations) {
+                for s in 0..n {
+                    for t in 0..n {
+                        if let (Some(x), Some(y), Some(d)) = (dist[s][a], dist[b][t], dist[s][t])
+                            && x + w + y == d
+                        {
+                            score += sigma[s][a] * sigma[b][t] / sigma[s][t];
+                        }
+                    }
+                }
+            }
+            score / orientations as f64
+        })
+        .collect()
+}
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn star_graph() {
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4)]);
+    assert_close(
+        &betweenness_centrality(&graph, false, None),
+        &[6., 0., 0., 0., 0.],
+    );
+    assert_close(
+        &betweenness_centrality(&graph, true, None),
+        &[1., 0., 0., 0., 0.],
+    );
+    assert_close(&edge_betweenness_centrality(&graph, false, None), &[4.; 4]);
+    assert_close(&edge_betweenness_centrality(&graph, true, None), &[0.4; 4]);
+}
+
+#[test]
+fn directed_path() {
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+    assert_close(
+        &betweenness_centrality(&graph, false, None),
+        &[0., 2., 2., 0.],
+    );
+    assert_close(
+        &betweenness_centrality(&graph, true, None),
+        &[0., 1. / 3., 1. / 3., 0.],
+    );
+    assert_close(
+        &edge_betweenness_centrality(&graph, false, None),
+        &[3., 4., 3.],
+    );
+}
+
+#[test]
+fn split_shortest_paths() {
+    // Two shortest paths from 0 to 3, one through 1 and one through 2.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 3), (2, 3)]);
+    assert_close(&betweenness_centrality(&graph, false, None), &[0.5; 4]);
+}
+
+#[test]
+fn trivial_graphs() {
+    let graph = UnGraph::<(), ()>::default();
+    assert!(betweenness_centrality(&graph, true, None).is_empty());
+
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+    assert_close(&betweenness_centrality(&graph, true, None), &[0., 0.]);
+    assert_close(&edge_betweenness_centrality(&graph, true, None), &[1.]);
+}
+
+#[test]
+fn compare_with_brute_force() {
+    for seed in 0..10 {
+        let graph = random_graph::<_, _, petgraph::Directed>(
+            &mut seeded(seed),
+            12,
+            30,
+            |_| (),
+            |rng| rng.gen_range(1, 4),
+        );
+        assert_close(
+            &betweenness_centrality(&graph, false, None),
+            &brute_force_node_betweenness(&graph, false),
+        );
+        assert_close(
+            &weighted_betweenness_centrality(&graph, |e| *e.weight(), false, None),
+            &brute_force_node_betweenness(&graph, true),
+        );
+        assert_close(
+            &edge_betweenness_centrality(&graph, false, None),
+            &brute_force_edge_betweenness(&graph, false),
+        );
+        assert_close(
+            &weighted_edge_betweenness_centrality(&graph, |e| *e.weight(), false, None),
+            &brute_force_edge_betweenness(&graph, true),
+        );
+
+        let graph = random_graph::<_, _, petgraph::Undirected>(
+            &mut seeded(seed),
+            12,
+            20,
+            |_| (),
+            |rng| rng.gen_range(1, 4),
+        );
+        assert_close(
+            &betweenness_centrality(&graph, false, None),
+            &brute_force_node_betweenness(&graph, false),
+        );
+        assert_close(
+            &weighted_betweenness_centrality(&graph, |e| *e.weight(), false, None),
+            &brute_force_node_betweenness(&graph, true),
+        );
+        assert_close(
+            &edge_betweenness_centrality(&graph, false, None),
+            &brute_force_edge_betweenness(&graph, false),
+        );
+        assert_close(
+            &weighted_edge_betweenness_centrality(&graph, |e| *e.weight(), false, None),
+            &brute_force_edge_betweenness(&graph, true),
+        );
+    }
+}
+
+#[test]
+fn sampled_sources() {
+    let graph = random_graph::<_, _, petgraph::Undirected>(
+        &mut seeded(42),
+        20,
+        40,
+        |_| (),
+        |rng| rng.gen_range(1, 4),
+    );
+    let all = graph.node_indices().collect::<Vec<_>>();
+    assert_close(
+        &betweenness_centrality(&graph, true, Some(&all)),
+        &betweenness_centrality(&graph, true, None),
+    );
+
+    let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    graph.add_node(());
+    let sources = [0.into(), 1.into()];
+    // Only the paths from node 0 go through node 1, and the two sources stand for all four nodes.
+    assert_close(
+        &betweenness_centrality(&graph, false, Some(&sources)),
+        &[0., 1., 0., 0.],
+    );
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel() {
+    for seed in 0..5 {
+        let graph = random_graph::<_, _, petgraph::Directed>(
+            &mut seeded(seed),
+            50,
+            200,
+            |_| (),
+            |rng| rng.gen_range(1, 4),
+        );
+        assert_close(
+            &parallel_betweenness_centrality(&graph, true, None),
+            &betweenness_centrality(&graph, true, None),
+        );
+        assert_close(
+            &parallel_weighted_betweenness_centrality(&graph, |e| *e.weight(), true, None),
+            &weighted_betweenness_centrality(&graph, |e| *e.weight(), true, None),
+        );
+    }
+}
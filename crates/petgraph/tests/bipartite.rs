@@ -0,0 +1,153 @@
+use petgraph::{
+    Directed, EdgeType, Graph, Undirected,
+    algo::{
+        bipartite::{Bipartition, OddCycle},
+        bipartition, is_bipartite_undirected,
+    },
+    graph::{NodeIndex, UnGraph},
+};
+use rand::{ChaChaRng, Rng};
+use utils::{random_bipartite_graph, seeded};
+
+mod utils;
+
+/// Add `count` edges between nodes drawn uniformly.
+fn add_random_edges<Ty: EdgeType>(
+    rng: &mut ChaChaRng,
+    graph: &mut Graph<(), (), Ty>,
+    count: usize,
+) {
+    let n = graph.node_count();
+    for _ in 0..count {
+        let a = NodeIndex::new(rng.gen_range(0, n));
+        let b = NodeIndex::new(rng.gen_range(0, n));
+        graph.add_edge(a, b, ());
+    }
+}
+
+fn adjacent<Ty: EdgeType>(graph: &Graph<(), (), Ty>, a: NodeIndex, b: NodeIndex) -> bool {
+    graph.contains_edge(a, b) || graph.contains_edge(b, a)
+}
+
+fn assert_bipartition<Ty: EdgeType>(graph: &Graph<(), (), Ty>, sides: &Bipartition<NodeIndex>) {
+    let mut side = vec![None; graph.node_count()];
+    for &node in &sides.left {
+        assert_eq!(side[node.index()].replace(true), None);
+    }
+    for &node in &sides.right {
+        assert_eq!(side[node.index()].replace(false), None);
+    }
+    for edge in graph.raw_edges() {
+        assert_ne!(side[edge.source().index()], side[edge.target().index()]);
+    }
+    assert!(side.iter().all(Option::is_some));
+}
+
+fn assert_odd_cycle<Ty: EdgeType>(graph: &Graph<(), (), Ty>, cycle: &[NodeIndex]) {
+    assert_eq!(cycle.len() % 2, 1);
+    let mut sorted = cycle.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), cycle.len());
+    for (i, &a) in cycle.iter().enumerate() {
+        assert!(adjacent(graph, a, cycle[(i + 1) % cycle.len()]));
+    }
+}
+
+/// Whether every component is bipartite, checked with `is_bipartite_undirected`.
+fn is_bipartite(graph: &UnGraph<(), ()>) -> bool {
+    graph
+        .node_indices()
+        .all(|node| is_bipartite_undirected(graph, node))
+}
+
+#[test]
+fn bipartition_random() {
+    for seed in 0..50 {
+        let mut rng = seeded(seed);
+        let mut graph = random_bipartite_graph::<Undirected>(&mut rng, 12, 18, 30);
+        add_random_edges(&mut rng, &mut graph, seed as usize % 3);
+        match bipartition(&graph) {
+            Ok(sides) => {
+                assert!(is_bipartite(&graph));
+                assert_bipartition(&graph, &sides);
+            }
+            Err(OddCycle(cycle)) => {
+                assert!(!is_bipartite(&graph));
+                assert_odd_cycle(&graph, &cycle);
+            }
+        }
+    }
+}
+
+#[test]
+fn bipartition_directed() {
+    // The direction of the edges is ignored.
+    for seed in 0..20 {
+        let graph = random_bipartite_graph::<Directed>(&mut seeded(seed), 10, 10, 20);
+        assert_bipartition(&graph, &bipartition(&graph).unwrap());
+
+        let mut rng = seeded(seed);
+        let mut graph = random_bipartite_graph::<Directed>(&mut rng, 10, 10, 20);
+        add_random_edges(&mut rng, &mut graph, 5);
+        if let Err(OddCycle(cycle)) = bipartition(&graph) {
+            assert_odd_cycle(&graph, &cycle);
+        }
+    }
+
+    let graph = Graph::<(), (), Directed>::from_edges([(0, 1), (2, 1), (2, 0)]);
+    let OddCycle(cycle) = bipartition(&graph).unwrap_err();
+    assert_odd_cycle(&graph, &cycle);
+}
+
+#[test]
+fn bipartition_trivial() {
+    let graph = UnGraph::<(), ()>::default();
+    assert_eq!(
+        bipartition(&graph),
+        Ok(Bipartition {
+            left: vec![],
+            right: vec![]
+        })
+    );
+
+    let mut graph = UnGraph::<(), ()>::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    assert_eq!(
+        bipartition(&graph),
+        Ok(Bipartition {
+            left: vec![a, b],
+            right: vec![]
+        })
+    );
+
+    // A self loop is an odd cycle.
+    graph.add_edge(a, b, ());
+    graph.add_edge(b, b, ());
+    assert_eq!(bipartition(&graph), Err(OddCycle(vec![b])));
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn bipartition_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    // A pentagon that becomes a path after removing a node.
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    for i in 0..5 {
+        graph.add_edge(nodes[i], nodes[(i + 1) % 5], ());
+    }
+    let OddCycle(cycle) = bipartition(&graph).unwrap_err();
+    assert_eq!(cycle.len(), 5);
+
+    graph.remove_node(nodes[0]);
+    assert_eq!(
+        bipartition(&graph),
+        Ok(Bipartition {
+            left: vec![nodes[1], nodes[3]],
+            right: vec![nodes[2], nodes[4]]
+        })
+    );
+}
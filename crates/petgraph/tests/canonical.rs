@@ -0,0 +1,351 @@
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+
+use hashbrown::HashSet;
+use petgraph::{
+    Directed, EdgeType, Graph, Undirected,
+    algo::{canonical::CanonicalForm, canonical_form, canonical_form_with, is_isomorphic},
+    graph::{NodeIndex, UnGraph},
+    visit::EdgeRef,
+};
+use rand::Rng;
+use utils::{random_graph, random_permutation, seeded};
+
+mod utils;
+
+const PETERSEN: [(u32, u32); 15] = [
+    (0, 1),
+    (1, 2),
+    (2, 3),
+    (3, 4),
+    (4, 0),
+    (0, 5),
+    (1, 6),
+    (2, 7),
+    (3, 8),
+    (4, 9),
+    (5, 7),
+    (7, 9),
+    (9, 6),
+    (6, 8),
+    (8, 5),
+];
+
+/// The graph with its nodes permuted: node `i` becomes node `permutation[i]`.
+fn permuted<Ty: EdgeType>(graph: &Graph<u8, u8, Ty>, permutation: &[usize]) -> Graph<u8, u8, Ty> {
+    let mut inverse = vec![0; permutation.len()];
+    for (i, &j) in permutation.iter().enumerate() {
+        inverse[j] = i;
+    }
+    let mut result = Graph::default();
+    for &i in &inverse {
+        result.add_node(graph[NodeIndex::new(i)]);
+    }
+    for edge in graph.edge_references() {
+        result.add_edge(
+            NodeIndex::new(permutation[edge.source().index()]),
+            NodeIndex::new(permutation[edge.target().index()]),
+            *edge.weight(),
+        );
+    }
+    result
+}
+
+/// The sorted edges of the graph, with colors, after mapping node `i` to `map[i]`.
+fn mapped_edges<Ty: EdgeType>(graph: &Graph<u8, u8, Ty>, map: &[usize]) -> Vec<(usize, usize, u8)> {
+    let mut edges: Vec<_> = graph
+        .edge_references()
+        .map(|edge| {
+            let (a, b) = (map[edge.source().index()], map[edge.target().index()]);
+            if Ty::is_directed() || a <= b {
+                (a, b, *edge.weight())
+            } else {
+                (b, a, *edge.weight())
+            }
+        })
+        .collect();
+    edges.sort();
+    edges
+}
+
+fn is_automorphism<Ty: EdgeType>(graph: &Graph<u8, u8, Ty>, map: &[usize]) -> bool {
+    let identity: Vec<usize> = (0..map.len()).collect();
+    graph
+        .node_indices()
+        .all(|node| graph[node] == graph[NodeIndex::new(map[node.index()])])
+        && mapped_edges(graph, map) == mapped_edges(graph, &identity)
+}
+
+fn all_permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    let mut result = Vec::new();
+    for permutation in all_permutations(n - 1) {
+        for i in 0..n {
+            let mut extended = permutation.clone();
+            extended.insert(i, n - 1);
+            result.push(extended);
+        }
+    }
+    result
+}
+
+/// Every element of the group generated by the permutations.
+fn group_closure(n: usize, generators: &[Vec<usize>]) -> HashSet<Vec<usize>> {
+    let identity: Vec<usize> = (0..n).collect();
+    let mut group = HashSet::from([identity.clone()]);
+    let mut queue = VecDeque::from([identity]);
+    while let Some(element) = queue.pop_front() {
+        for generator in generators {
+            let product: Vec<usize> = element.iter().map(|&i| generator[i]).collect();
+            if group.insert(product.clone()) {
+                queue.push_back(product);
+            }
+        }
+    }
+    group
+}
+
+fn colored_form(graph: &Graph<u8, u8, impl EdgeType>) -> CanonicalForm<NodeIndex, u8, u8> {
+    canonical_form_with(graph, |node| graph[node], |edge| *edge.weight())
+}
+
+#[test]
+fn canonical_form_relabeled() {
+    let mut rng = seeded(1);
+    for _ in 0..100 {
+        let n = rng.gen_range(1, 12);
+        let m = rng.gen_range(0, 3 * n);
+        let graph = random_graph::<u8, u8, Undirected>(
+            &mut rng,
+            n,
+            m,
+            |rng| rng.gen_range(0, 2),
+            |rng| rng.gen_range(0, 2),
+        );
+        let other = permuted(&graph, &random_permutation(&mut rng, n));
+        let (form, other_form) = (colored_form(&graph), colored_form(&other));
+        assert_eq!(form.certificate, other_form.certificate);
+
+        // Matching the canonical orders is an isomorphism.
+        let mut map = vec![0; n];
+        for (a, b) in form.order.iter().zip(&other_form.order) {
+            map[a.index()] = b.index();
+        }
+        let identity: Vec<usize> = (0..n).collect();
+        assert_eq!(mapped_edges(&graph, &map), mapped_edges(&other, &identity));
+
+        let graph = random_graph::<u8, u8, Directed>(
+            &mut rng,
+            n,
+            m,
+            |rng| rng.gen_range(0, 2),
+            |rng| rng.gen_range(0, 2),
+        );
+        let other = permuted(&graph, &random_permutation(&mut rng, n));
+        assert_eq!(
+            colored_form(&graph).certificate,
+            colored_form(&other).certificate
+        );
+    }
+}
+
+#[test]
+fn canonical_form_compare_with_vf2() {
+    let mut rng = seeded(2);
+    for _ in 0..300 {
+        // Small simple graphs, many of which are isomorphic.
+        let mut graphs: Vec<UnGraph<(), ()>> = Vec::new();
+        for _ in 0..2 {
+            let mut graph = UnGraph::default();
+            let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+            for a in 0..6 {
+                for b in a + 1..6 {
+                    if rng.gen_range(0, 3) == 0 {
+                        graph.add_edge(nodes[a], nodes[b], ());
+                    }
+                }
+            }
+            graphs.push(graph);
+        }
+        assert_eq!(
+            canonical_form(&graphs[0]).certificate == canonical_form(&graphs[1]).certificate,
+            is_isomorphic(&graphs[0], &graphs[1])
+        );
+    }
+}
+
+#[test]
+fn automorphisms_compare_with_brute_force() {
+    let mut rng = seeded(3);
+    for _ in 0..40 {
+        let n = rng.gen_range(1, 7);
+        let m = rng.gen_range(0, 2 * n);
+        let graph = random_graph::<u8, u8, Undirected>(
+            &mut rng,
+            n,
+            m,
+            |rng| rng.gen_range(0, 2),
+            |rng| rng.gen_range(0, 2),
+        );
+        let form = colored_form(&graph);
+
+        // Generators, in terms of node indices.
+        let generators: Vec<Vec<usize>> = form
+            .generators
+            .iter()
+            .map(|generator| {
+                let mut map = vec![0; n];
+                for (i, &j) in generator.iter().enumerate() {
+                    map[form.order[i].index()] = form.order[j].index();
+                }
+                map
+            })
+            .collect();
+        for generator in &generators {
+            assert!(is_automorphism(&graph, generator));
+        }
+
+        let automorphisms: HashSet<Vec<usize>> = all_permutations(n)
+            .into_iter()
+            .filter(|map| is_automorphism(&graph, map))
+            .collect();
+        assert_eq!(group_closure(n, &generators), automorphisms);
+
+        for orbit in &form.orbits {
+            for a in orbit {
+                for b in orbit {
+                    assert!(automorphisms.iter().any(|map| map[a.index()] == b.index()));
+                }
+            }
+        }
+        let orbit_count: usize = form.orbits.iter().map(Vec::len).sum();
+        assert_eq!(orbit_count, n);
+    }
+}
+
+#[test]
+fn automorphisms_petersen() {
+    let graph = UnGraph::<(), ()>::from_edges(PETERSEN);
+    let form = canonical_form(&graph);
+    assert_eq!(group_closure(10, &form.generators).len(), 120);
+    assert_eq!(form.orbits.len(), 1);
+}
+
+#[test]
+fn automorphisms_highly_symmetric() {
+    // Graphs with huge automorphism groups must not need a huge search.
+    let mut empty = UnGraph::<(), ()>::default();
+    let mut complete = UnGraph::<(), ()>::default();
+    for _ in 0..40 {
+        empty.add_node(());
+        complete.add_node(());
+    }
+    for a in complete.node_indices() {
+        for b in complete.node_indices() {
+            if a < b {
+                complete.add_edge(a, b, ());
+            }
+        }
+    }
+    for graph in [&empty, &complete] {
+        let form = canonical_form(graph);
+        assert_eq!(form.orbits.len(), 1);
+        assert!(form.generators.len() < 40);
+    }
+    assert_ne!(
+        canonical_form(&empty).certificate,
+        canonical_form(&complete).certificate
+    );
+}
+
+#[test]
+fn canonical_form_colors_and_directions() {
+    // A directed cycle is isomorphic to its reverse, but not to a path closed by a reversed edge.
+    let cycle = Graph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+    let reversed = Graph::<(), ()>::from_edges([(1, 0), (2, 1), (0, 2)]);
+    let acyclic = Graph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2)]);
+    assert_eq!(
+        canonical_form(&cycle).certificate,
+        canonical_form(&reversed).certificate
+    );
+    assert_ne!(
+        canonical_form(&cycle).certificate,
+        canonical_form(&acyclic).certificate
+    );
+    assert_eq!(canonical_form(&cycle).orbits.len(), 1);
+    assert_eq!(canonical_form(&acyclic).orbits.len(), 3);
+
+    // Edge colors break the symmetry of a square.
+    let square = UnGraph::<u8, u8>::from_edges([(0, 1, 0), (1, 2, 1), (2, 3, 0), (3, 0, 1)]);
+    assert_eq!(colored_form(&square).orbits.len(), 1);
+    assert_eq!(group_closure(4, &colored_form(&square).generators).len(), 4);
+    let path = UnGraph::<u8, u8>::from_edges([(0, 1, 0), (1, 2, 1), (2, 3, 1), (3, 0, 1)]);
+    assert_ne!(
+        colored_form(&square).certificate,
+        colored_form(&path).certificate
+    );
+
+    // Parallel edges count.
+    let single = UnGraph::<u8, u8>::from_edges([(0, 1, 0), (1, 2, 0)]);
+    let double = UnGraph::<u8, u8>::from_edges([(0, 1, 0), (0, 1, 0), (1, 2, 0)]);
+    assert_ne!(
+        colored_form(&single).certificate,
+        colored_form(&double).certificate
+    );
+    assert_eq!(colored_form(&double).orbits.len(), 3);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn canonical_form_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)] {
+        graph.add_edge(nodes[a], nodes[b], ());
+    }
+    graph.remove_node(nodes[2]);
+    let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+    let form = canonical_form(&graph);
+    assert_eq!(form.certificate, canonical_form(&path).certificate);
+    assert_eq!(form.order.len(), 4);
+    assert_eq!(
+        form.orbits,
+        vec![vec![nodes[1], nodes[3]], vec![nodes[0], nodes[4]]]
+    );
+
+    let empty = UnGraph::<(), ()>::default();
+    let form = canonical_form(&empty);
+    assert!(form.order.is_empty() && form.generators.is_empty() && form.orbits.is_empty());
+}
+
+#[test]
+fn automorphisms_vertex_transitive() {
+    // Cube, cycle, complete bipartite graph and Petersen graph, with the orders of their groups.
+    let cube: Vec<(u32, u32)> = (0..8u32)
+        .flat_map(|a| [1, 2, 4].map(|bit| (a, a ^ bit)))
+        .filter(|(a, b)| a < b)
+        .collect();
+    let cycle: Vec<(u32, u32)> = (0..8).map(|a| (a, (a + 1) % 8)).collect();
+    let bipartite: Vec<(u32, u32)> = (0..3).flat_map(|a| (3..6).map(move |b| (a, b))).collect();
+    let mut rng = seeded(4);
+    for (edges, order) in [
+        (cube, 48),
+        (cycle, 16),
+        (bipartite, 72),
+        (PETERSEN.to_vec(), 120),
+    ] {
+        let graph = Graph::<u8, u8, Undirected>::from_edges(edges.iter().map(|&(a, b)| (a, b, 0)));
+        let n = graph.node_count();
+        let form = colored_form(&graph);
+        assert_eq!(group_closure(n, &form.generators).len(), order);
+        for _ in 0..10 {
+            let other = permuted(&graph, &random_permutation(&mut rng, n));
+            assert_eq!(colored_form(&other).certificate, form.certificate);
+        }
+    }
+}
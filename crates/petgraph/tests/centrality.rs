@@ -0,0 +1,250 @@
+use petgraph::{
+    algo::{
+        IterationOptions, KatzOptions, closeness_centrality, eigenvector_centrality,
+        harmonic_centrality, hits, katz_centrality,
+    },
+    graph::{DiGraph, UnGraph},
+    visit::Reversed,
+};
+
+/// The Krackhardt kite graph.
+fn kite() -> UnGraph<(), ()> {
+    UnGraph::from_edges([
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (0, 5),
+        (1, 3),
+        (1, 4),
+        (1, 6),
+        (2, 3),
+        (2, 5),
+        (3, 4),
+        (3, 5),
+        (3, 6),
+        (4, 6),
+        (5, 6),
+        (5, 7),
+        (6, 7),
+        (7, 8),
+        (8, 9),
+    ])
+}
+
+/// A strongly connected directed graph.
+fn digraph() -> DiGraph<(), ()> {
+    DiGraph::from_edges([
+        (0, 1),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 0),
+        (2, 4),
+        (3, 4),
+        (4, 0),
+    ])
+}
+
+fn assert_close(actual: &[f64], expected: &[f64], tol: f64) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < tol, "{actual:?} != {expected:?}");
+    }
+}
+
+// Expected values are computed with NetworkX.
+
+/// Options that iterate until the scores match the expected values.
+fn converged() -> IterationOptions<f64> {
+    IterationOptions {
+        max_iter: 1000,
+        tol: 1e-12,
+    }
+}
+
+#[test]
+fn closeness() {
+    let expected = [
+        0.529411764706,
+        0.529411764706,
+        0.5,
+        0.6,
+        0.5,
+        0.642857142857,
+        0.642857142857,
+        0.6,
+        0.428571428571,
+        0.310344827586,
+    ];
+    assert_close(
+        &closeness_centrality(&kite(), |_| 1., false),
+        &expected,
+        1e-9,
+    );
+    assert_close(
+        &closeness_centrality(&digraph(), |_| 1., true),
+        &[0.666666666667, 0.666666666667, 0.666666666667, 0.4, 0.5],
+        1e-9,
+    );
+}
+
+#[test]
+fn closeness_disconnected() {
+    let mut graph = UnGraph::<(), f64>::from_edges([(0, 1, 2.)]);
+    graph.add_node(());
+    assert_eq!(
+        closeness_centrality(&graph, |e| *e.weight(), false),
+        vec![0.5, 0.5, 0.]
+    );
+    assert_eq!(
+        closeness_centrality(&graph, |e| *e.weight(), true),
+        vec![0.25, 0.25, 0.]
+    );
+
+    // Nodes only reach others along the direction of the edges.
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1)]);
+    assert_eq!(closeness_centrality(&graph, |_| 1., false), vec![1., 0.]);
+    assert_eq!(
+        closeness_centrality(Reversed(&graph), |_| 1., false),
+        vec![0., 1.]
+    );
+}
+
+#[test]
+fn harmonic() {
+    let expected = [
+        6.083333333333,
+        6.083333333333,
+        5.583333333333,
+        7.083333333333,
+        5.583333333333,
+        6.833333333333,
+        6.833333333333,
+        6.0,
+        4.666666666667,
+        3.416666666667,
+    ];
+    assert_close(&harmonic_centrality(&kite(), |_| 1.), &expected, 1e-9);
+
+    let mut graph = UnGraph::<(), f64>::from_edges([(0, 1, 2.)]);
+    graph.add_node(());
+    assert_eq!(
+        harmonic_centrality(&graph, |e| *e.weight()),
+        vec![0.5, 0.5, 0.]
+    );
+}
+
+#[test]
+fn eigenvector() {
+    // NetworkX scores rescaled to sum to one.
+    let expected = [
+        0.1254490929,
+        0.1254490929,
+        0.101808017371,
+        0.171328848355,
+        0.101808017371,
+        0.141648491072,
+        0.141648491072,
+        0.069761149754,
+        0.017122697872,
+        0.003976101334,
+    ];
+    assert_close(
+        &eigenvector_centrality(&kite(), converged()),
+        &expected,
+        1e-8,
+    );
+    assert_close(
+        &eigenvector_centrality(&digraph(), converged()),
+        &[
+            0.233620460365,
+            0.156316990728,
+            0.104592729387,
+            0.260909720115,
+            0.244560099405,
+        ],
+        1e-8,
+    );
+
+    let empty = UnGraph::<(), ()>::default();
+    assert!(eigenvector_centrality::<_, f64>(&empty, IterationOptions::default()).is_empty());
+}
+
+#[test]
+fn eigenvector_max_iter() {
+    let scores = eigenvector_centrality(
+        &kite(),
+        IterationOptions {
+            max_iter: 0,
+            tol: 1e-12,
+        },
+    );
+    assert_eq!(scores, vec![0.1; 10]);
+}
+
+#[test]
+fn katz() {
+    let expected = [
+        1.717593609247,
+        1.717593609247,
+        1.561448735679,
+        2.029322682078,
+        1.561448735679,
+        1.867571065467,
+        1.867571065467,
+        1.499774562206,
+        1.262603491132,
+        1.126260349113,
+    ];
+    assert_close(
+        &katz_centrality(
+            &kite(),
+            KatzOptions {
+                alpha: 0.1,
+                tol: 1e-12,
+                ..KatzOptions::default()
+            },
+        ),
+        &expected,
+        1e-9,
+    );
+    assert_close(
+        &katz_centrality(
+            &digraph(),
+            KatzOptions {
+                alpha: 0.2,
+                tol: 1e-12,
+                ..KatzOptions::default()
+            },
+        ),
+        &[
+            1.566068515497,
+            1.313213703099,
+            1.26264274062,
+            1.575856443719,
+            1.567699836867,
+        ],
+        1e-9,
+    );
+}
+
+#[test]
+fn hubs_and_authorities() {
+    let (hubs, authorities) = hits(&digraph(), converged());
+    assert_close(
+        &hubs,
+        &[
+            0.214285714286,
+            0.214285714286,
+            0.285714285714,
+            0.142857142857,
+            0.142857142857,
+        ],
+        1e-9,
+    );
+    assert_close(&authorities, &[0.25, 0.125, 0.125, 0.25, 0.25], 1e-9);
+
+    // Hubs and authorities coincide on undirected graphs.
+    let (hubs, authorities) = hits(&kite(), converged());
+    assert_close(&hubs, &authorities, 1e-9);
+}
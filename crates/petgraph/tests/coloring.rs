@@ -1,4 +1,70 @@
-use petgraph::{Graph, Undirected, algo::dsatur_coloring};
+use hashbrown::{HashMap, HashSet};
+use petgraph::{
+    Graph, Undirected,
+    algo::{
+        DegreeKind, bipartite_edge_coloring,
+        coloring::{ColoringError, ColoringOrder},
+        core_numbers, dsatur_coloring, exact_coloring, greedy_coloring, misra_gries_edge_coloring,
+        validate_coloring,
+    },
+    graph::{EdgeIndex, NodeIndex, UnGraph},
+    visit::EdgeRef,
+};
+use utils::{random_bipartite_graph, random_graph, remove_self_loops, seeded};
+
+mod utils;
+
+const ORDERS: [ColoringOrder; 5] = [
+    ColoringOrder::LargestFirst,
+    ColoringOrder::SmallestLast,
+    ColoringOrder::RandomSequential { seed: 0 },
+    ColoringOrder::RandomSequential { seed: 1 },
+    ColoringOrder::ConnectedSequential,
+];
+
+/// Whether the graph can be colored with `k` colors, by backtracking over the nodes in order.
+fn colorable(graph: &UnGraph<(), ()>, k: usize, colors: &mut Vec<usize>) -> bool {
+    let v = colors.len();
+    if v == graph.node_count() {
+        return true;
+    }
+    for color in 0..k {
+        if graph
+            .neighbors(NodeIndex::new(v))
+            .all(|u| u.index() >= v || colors[u.index()] != color)
+        {
+            colors.push(color);
+            if colorable(graph, k, colors) {
+                return true;
+            }
+            colors.pop();
+        }
+    }
+    false
+}
+
+/// Assert that no two edges sharing an end have the same color, and return the maximum degree.
+fn assert_proper_edge_coloring(
+    graph: &UnGraph<(), ()>,
+    coloring: &HashMap<EdgeIndex, usize>,
+) -> usize {
+    assert_eq!(coloring.len(), graph.edge_count());
+    let mut max_degree = 0;
+    for node in graph.node_indices() {
+        let mut colors: Vec<_> = graph.edges(node).map(|e| coloring[&e.id()]).collect();
+        max_degree = max_degree.max(colors.len());
+        colors.sort();
+        colors.dedup();
+        assert_eq!(colors.len(), graph.edges(node).count());
+    }
+    max_degree
+}
+
+fn brute_force_chromatic_number(graph: &UnGraph<(), ()>) -> usize {
+    (0..)
+        .find(|&k| colorable(graph, k, &mut Vec::new()))
+        .unwrap()
+}
 
 #[test]
 fn dsatur_coloring_cycle6() {
@@ -56,3 +122,236 @@ fn dsatur_coloring_bipartite() {
     let (_, nb_colors) = dsatur_coloring(&graph);
     assert_eq!(nb_colors, 2);
 }
+
+#[test]
+fn greedy_coloring_is_proper() {
+    for seed in 0..10 {
+        let mut graph =
+            random_graph::<_, _, Undirected>(&mut seeded(seed), 40, 120, |_| (), |_| ());
+        remove_self_loops(&mut graph);
+        let degeneracy = core_numbers(&graph, DegreeKind::Total)
+            .into_iter()
+            .max()
+            .unwrap();
+        for order in ORDERS {
+            let (coloring, colors) = greedy_coloring(&graph, order);
+            assert_eq!(validate_coloring(&graph, &coloring), Ok(()));
+            assert_eq!(coloring.values().max().unwrap() + 1, colors);
+            if order == ColoringOrder::SmallestLast {
+                assert!(colors <= degeneracy + 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn greedy_coloring_orders() {
+    // A crown: two rows of nodes, each adjacent to every node of the other row but the one
+    // facing it. The interleaved order colors every facing pair alike, needing four colors.
+    let mut graph = UnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..8).map(|_| graph.add_node(())).collect();
+    for a in 0..4 {
+        for b in 0..4 {
+            if a != b {
+                graph.add_edge(nodes[2 * a], nodes[2 * b + 1], ());
+            }
+        }
+    }
+    // Every node has degree 3, so the largest first order keeps the interleaved order.
+    assert_eq!(greedy_coloring(&graph, ColoringOrder::LargestFirst).1, 4);
+    assert_eq!(exact_coloring(&graph, None).colors, 2);
+
+    // On trees, the smallest last and connected sequential orders use two colors.
+    let tree = UnGraph::<(), ()>::from_edges([(0, 4), (1, 4), (2, 5), (3, 5), (4, 6), (5, 6)]);
+    assert_eq!(greedy_coloring(&tree, ColoringOrder::SmallestLast).1, 2);
+    assert_eq!(
+        greedy_coloring(&tree, ColoringOrder::ConnectedSequential).1,
+        2
+    );
+
+    let empty = UnGraph::<(), ()>::default();
+    for order in ORDERS {
+        assert_eq!(greedy_coloring(&empty, order), (HashMap::new(), 0));
+    }
+}
+
+#[test]
+fn exact_coloring_compare_with_brute_force() {
+    for seed in 0..20 {
+        let mut graph = random_graph::<_, _, Undirected>(&mut seeded(seed), 12, 30, |_| (), |_| ());
+        remove_self_loops(&mut graph);
+        let result = exact_coloring(&graph, None);
+        assert!(result.optimal);
+        assert_eq!(validate_coloring(&graph, &result.coloring), Ok(()));
+        assert_eq!(result.colors, brute_force_chromatic_number(&graph));
+    }
+}
+
+#[test]
+fn exact_coloring_known_graphs() {
+    // The Petersen graph.
+    let petersen = UnGraph::<(), ()>::from_edges([
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (0, 5),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 7),
+        (7, 9),
+        (9, 6),
+        (6, 8),
+        (8, 5),
+    ]);
+    assert_eq!(exact_coloring(&petersen, None).colors, 3);
+
+    // The Grötzsch graph has no triangle, but needs four colors.
+    let grotzsch = UnGraph::<(), ()>::from_edges([
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (5, 1),
+        (5, 4),
+        (6, 0),
+        (6, 2),
+        (7, 1),
+        (7, 3),
+        (8, 2),
+        (8, 4),
+        (9, 3),
+        (9, 0),
+        (10, 5),
+        (10, 6),
+        (10, 7),
+        (10, 8),
+        (10, 9),
+    ]);
+    let result = exact_coloring(&grotzsch, None);
+    assert_eq!(result.colors, 4);
+    assert!(result.optimal);
+    assert_eq!(validate_coloring(&grotzsch, &result.coloring), Ok(()));
+}
+
+#[test]
+fn exact_coloring_budget() {
+    let mut graph = random_graph::<_, _, Undirected>(&mut seeded(7), 40, 300, |_| (), |_| ());
+    remove_self_loops(&mut graph);
+    let complete = exact_coloring(&graph, None);
+    assert!(complete.optimal);
+    let (_, heuristic) = dsatur_coloring(&graph);
+    for budget in [1, 10, 1000] {
+        let result = exact_coloring(&graph, Some(budget));
+        assert_eq!(validate_coloring(&graph, &result.coloring), Ok(()));
+        assert!(result.colors >= complete.colors && result.colors <= heuristic);
+        if !result.optimal {
+            assert!(budget < 1000);
+        }
+    }
+}
+
+#[test]
+fn validate_coloring_errors() {
+    let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    let [a, b, c] = [0, 1, 2].map(NodeIndex::new);
+    let coloring = HashMap::from([(a, 0), (b, 1)]);
+    assert_eq!(
+        validate_coloring(&graph, &coloring),
+        Err(ColoringError::Uncolored(c))
+    );
+
+    let coloring = HashMap::from([(a, 0), (b, 1), (c, 0)]);
+    assert_eq!(validate_coloring(&graph, &coloring), Ok(()));
+    graph.add_edge(c, c, ());
+    assert_eq!(
+        validate_coloring(&graph, &coloring),
+        Err(ColoringError::Conflict(c, c))
+    );
+}
+
+#[test]
+fn misra_gries_edge_coloring_random() {
+    for seed in 0..20 {
+        let mut graph =
+            random_graph::<_, _, Undirected>(&mut seeded(seed), 30, 150, |_| (), |_| ());
+        remove_self_loops(&mut graph);
+        // Remove the parallel edges.
+        let mut seen = HashSet::new();
+        graph.retain_edges(|g, e| {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            seen.insert((a.min(b), a.max(b)))
+        });
+        let (coloring, colors) = misra_gries_edge_coloring(&graph);
+        let max_degree = assert_proper_edge_coloring(&graph, &coloring);
+        assert!(colors >= max_degree && colors <= max_degree + 1);
+    }
+}
+
+#[test]
+fn misra_gries_edge_coloring_complete() {
+    // Complete graphs of odd order need one color more than their degree.
+    for n in 1..10 {
+        let mut graph = UnGraph::<(), ()>::default();
+        let nodes: Vec<_> = (0..n).map(|_| graph.add_node(())).collect();
+        for a in 0..n {
+            for b in a + 1..n {
+                graph.add_edge(nodes[a], nodes[b], ());
+            }
+        }
+        let (coloring, colors) = misra_gries_edge_coloring(&graph);
+        assert_proper_edge_coloring(&graph, &coloring);
+        if n % 2 == 1 {
+            assert_eq!(colors, if n == 1 { 0 } else { n });
+        } else {
+            assert!(colors == n - 1 || colors == n);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn misra_gries_edge_coloring_parallel_edges() {
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 0)]);
+    misra_gries_edge_coloring(&graph);
+}
+
+#[test]
+fn bipartite_edge_coloring_random() {
+    for seed in 0..20 {
+        // Random bipartite multigraphs between two sides of 15 nodes.
+        let graph: UnGraph<(), ()> = random_bipartite_graph(&mut seeded(seed), 15, 15, 120);
+        let (coloring, colors) = bipartite_edge_coloring(&graph).unwrap();
+        let max_degree = assert_proper_edge_coloring(&graph, &coloring);
+        assert_eq!(colors, max_degree);
+    }
+
+    let graph = UnGraph::<(), ()>::from_edges([(0, 0)]);
+    assert_eq!(bipartite_edge_coloring(&graph), None);
+    let graph = UnGraph::<(), ()>::default();
+    assert_eq!(bipartite_edge_coloring(&graph), Some((HashMap::new(), 0)));
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn edge_coloring_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    // A star whose center was removed, leaving a path.
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    for (a, b) in [(0, 1), (0, 2), (1, 2), (2, 3), (3, 4)] {
+        graph.add_edge(nodes[a], nodes[b], ());
+    }
+    graph.remove_node(nodes[0]);
+    let (coloring, colors) = bipartite_edge_coloring(&graph).unwrap();
+    assert_eq!(colors, 2);
+    assert_eq!(coloring.len(), 3);
+    let (coloring, colors) = misra_gries_edge_coloring(&graph);
+    assert!(colors <= 3);
+    assert_eq!(coloring.len(), 3);
+}
@@ -0,0 +1,456 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "rayon")]
+use petgraph::algo::community::parallel_label_propagation;
+use petgraph::{
+    algo::community::{
+        asynchronous_label_propagation, fluid_communities, label_propagation, leiden, louvain,
+        modularity,
+    },
+    graph::UnGraph,
+    unionfind::UnionFind,
+    visit::EdgeRef,
+};
+use rand::{Rng, RngCore};
+use utils::{random_graph, seeded};
+
+mod utils;
+
+const KARATE_CLUB: [(u32, u32, u32); 78] = [
+    (0, 1, 4),
+    (0, 2, 5),
+    (0, 3, 3),
+    (0, 4, 3),
+    (0, 5, 3),
+    (0, 6, 3),
+    (0, 7, 2),
+    (0, 8, 2),
+    (0, 10, 2),
+    (0, 11, 3),
+    (0, 12, 1),
+    (0, 13, 3),
+    (0, 17, 2),
+    (0, 19, 2),
+    (0, 21, 2),
+    (0, 31, 2),
+    (1, 2, 6),
+    (1, 3, 3),
+    (1, 7, 4),
+    (1, 13, 5),
+    (1, 17, 1),
+    (1, 19, 2),
+    (1, 21, 2),
+    (1, 30, 2),
+    (2, 3, 3),
+    (2, 7, 4),
+    (2, 8, 5),
+    (2, 9, 1),
+    (2, 13, 3),
+    (2, 27, 2),
+    (2, 28, 2),
+    (2, 32, 2),
+    (3, 7, 3),
+    (3, 12, 3),
+    (3, 13, 3),
+    (4, 6, 2),
+    (4, 10, 3),
+    (5, 6, 5),
+    (5, 10, 3),
+    (5, 16, 3),
+    (6, 16, 3),
+    (8, 30, 3),
+    (8, 32, 3),
+    (8, 33, 4),
+    (9, 33, 2),
+    (13, 33, 3),
+    (14, 32, 3),
+    (14, 33, 2),
+    (15, 32, 3),
+    (15, 33, 4),
+    (18, 32, 1),
+    (18, 33, 2),
+    (19, 33, 1),
+    (20, 32, 3),
+    (20, 33, 1),
+    (22, 32, 2),
+    (22, 33, 3),
+    (23, 25, 5),
+    (23, 27, 4),
+    (23, 29, 3),
+    (23, 32, 5),
+    (23, 33, 4),
+    (24, 25, 2),
+    (24, 27, 3),
+    (24, 31, 2),
+    (25, 31, 7),
+    (26, 29, 4),
+    (26, 33, 2),
+    (27, 33, 4),
+    (28, 31, 2),
+    (28, 33, 2),
+    (29, 32, 4),
+    (29, 33, 2),
+    (30, 32, 3),
+    (30, 33, 3),
+    (31, 32, 4),
+    (31, 33, 4),
+    (32, 33, 5),
+];
+
+/// The club every member joined after the split.
+const KARATE_CLUB_SPLIT: [usize; 34] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1,
+];
+
+fn karate_club() -> UnGraph<(), f64> {
+    UnGraph::from_edges(KARATE_CLUB.iter().map(|&(a, b, w)| (a, b, w as f64)))
+}
+
+/// `n` cliques of `k` nodes, joined in a ring by single edges.
+fn ring_of_cliques(n: u32, k: u32) -> UnGraph<(), f64> {
+    let mut edges = Vec::new();
+    for clique in 0..n {
+        let first = clique * k;
+        for a in first..first + k {
+            for b in a + 1..first + k {
+                edges.push((a, b));
+            }
+        }
+        edges.push((first + k - 1, (first + k) % (n * k)));
+    }
+    UnGraph::from_edges(edges)
+}
+
+/// Check that every community induces a connected subgraph.
+fn assert_connected_communities(graph: &UnGraph<(), f64>, communities: &[usize]) {
+    let mut components = UnionFind::new(graph.node_count());
+    for edge in graph.edge_references() {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        if communities[a] == communities[b] {
+            components.union(a, b);
+        }
+    }
+    let components = components.into_labeling();
+    let mut roots = HashSet::new();
+    for (node, &c) in communities.iter().enumerate() {
+        roots.insert((c, components[node]));
+    }
+    assert_eq!(
+        roots.len(),
+        communities.iter().collect::<HashSet<_>>().len()
+    );
+}
+
+/// Check that the communities are numbered from zero without gaps.
+fn assert_numbered(communities: &[usize]) {
+    let count = communities.iter().collect::<HashSet<_>>().len();
+    assert!(communities.iter().all(|&c| c < count));
+}
+
+// Expected values are computed with NetworkX.
+
+#[test]
+fn modularity_karate_club() {
+    let graph = karate_club();
+    let q = modularity(&graph, |_| 1., &KARATE_CLUB_SPLIT, 1.);
+    assert!((q - 0.3582347140039448).abs() < 1e-12);
+    let q = modularity(&graph, |_| 1., &KARATE_CLUB_SPLIT, 0.5);
+    assert!((q - 0.6086045364891519).abs() < 1e-12);
+    let q = modularity(&graph, |e| *e.weight(), &KARATE_CLUB_SPLIT, 1.);
+    assert!((q - 0.39143756676224206).abs() < 1e-12);
+
+    // Labels do not need to be consecutive.
+    let labels = KARATE_CLUB_SPLIT.map(|c| 10 * c + 7);
+    let q = modularity(&graph, |_| 1., &labels, 1.);
+    assert!((q - 0.3582347140039448).abs() < 1e-12);
+}
+
+#[test]
+fn modularity_trivial_partitions() {
+    let graph = ring_of_cliques(4, 5);
+    assert!(modularity(&graph, |_| 1., &[0; 20], 1.).abs() < 1e-12);
+
+    let empty = UnGraph::<(), ()>::from_edges::<[(u32, u32); 0]>([]);
+    assert_eq!(modularity(&empty, |_| 1., &[], 1.), 0.);
+
+    let mut graph = UnGraph::<(), ()>::default();
+    graph.add_node(());
+    graph.add_node(());
+    assert_eq!(modularity(&graph, |_| 1., &[0, 1], 1.), 0.);
+}
+
+#[test]
+fn modularity_self_loops() {
+    // A self loop counts once in the weight inside a community and twice in the degree.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 0), (0, 1), (1, 2)]);
+    let q = modularity(&graph, |_| 1., &[0, 0, 1], 1.);
+    assert!((q - (2. / 3. - 25. / 36. - 1. / 36.)).abs() < 1e-12);
+}
+
+#[test]
+fn ring_of_cliques_recovered() {
+    let graph = ring_of_cliques(6, 5);
+    for seed in 0..10 {
+        for communities in [
+            louvain(&graph, |_| 1., 1., seed),
+            leiden(&graph, |_| 1., 1., seed),
+        ] {
+            assert_numbered(&communities);
+            for node in 0..30 {
+                assert_eq!(communities[node], communities[node - node % 5]);
+            }
+            assert_eq!(communities.iter().collect::<HashSet<_>>().len(), 6);
+        }
+    }
+}
+
+#[test]
+fn karate_club_partitions() {
+    let graph = karate_club();
+    for seed in 0..20 {
+        let communities = louvain(&graph, |_| 1., 1., seed);
+        assert_numbered(&communities);
+        assert!(modularity(&graph, |_| 1., &communities, 1.) > 0.40);
+
+        let communities = leiden(&graph, |_| 1., 1., seed);
+        assert_numbered(&communities);
+        assert_connected_communities(&graph, &communities);
+        assert!(modularity(&graph, |_| 1., &communities, 1.) > 0.41);
+
+        let communities = leiden(&graph, |e| *e.weight(), 1., seed);
+        assert_connected_communities(&graph, &communities);
+        assert!(modularity(&graph, |e| *e.weight(), &communities, 1.) > 0.42);
+    }
+}
+
+#[test]
+fn leiden_connected_communities_random() {
+    let mut rng = seeded(31);
+    for _ in 0..300 {
+        let n = rng.gen_range(1, 60);
+        let m = rng.gen_range(0, 3 * n);
+        let graph: UnGraph<(), f64> = random_graph(
+            &mut rng,
+            n,
+            m,
+            |_| (),
+            |rng| (1u32 << rng.gen_range(0, 8)) as f64,
+        );
+        let resolution = [0.1, 0.5, 1., 2., 5.][rng.gen_range(0, 5)];
+        let communities = leiden(&graph, |e| *e.weight(), resolution, rng.next_u64());
+        assert_numbered(&communities);
+        assert_connected_communities(&graph, &communities);
+    }
+}
+
+#[test]
+fn deterministic_seed() {
+    let graph = karate_club();
+    assert_eq!(
+        louvain(&graph, |_| 1., 1., 3),
+        louvain(&graph, |_| 1., 1., 3)
+    );
+    assert_eq!(leiden(&graph, |_| 1., 1., 3), leiden(&graph, |_| 1., 1., 3));
+}
+
+#[test]
+fn resolution_limits() {
+    let mut graph = ring_of_cliques(4, 4);
+    graph.extend_with_edges([(16, 17, 1.)]);
+
+    // With a zero resolution, the communities are the connected components.
+    for communities in [
+        louvain(&graph, |_| 1., 0., 0),
+        leiden(&graph, |_| 1., 0., 0),
+    ] {
+        assert!(communities[..16].iter().all(|&c| c == communities[0]));
+        assert_eq!(communities[16], communities[17]);
+        assert_ne!(communities[0], communities[16]);
+    }
+
+    // With a huge resolution, every node is alone.
+    for communities in [
+        louvain(&graph, |_| 1., 100., 0),
+        leiden(&graph, |_| 1., 100., 0),
+    ] {
+        assert_eq!(communities.iter().collect::<HashSet<_>>().len(), 18);
+    }
+}
+
+#[test]
+fn edge_weights() {
+    // A 4-cycle where the weights decide which pairs of nodes belong together.
+    let graph = UnGraph::<(), f64>::from_edges([(0, 1, 10.), (1, 2, 1.), (2, 3, 10.), (3, 0, 1.)]);
+    for communities in [
+        louvain(&graph, |e| *e.weight(), 1., 0),
+        leiden(&graph, |e| *e.weight(), 1., 0),
+    ] {
+        assert_eq!(communities, vec![0, 0, 1, 1]);
+    }
+    for communities in [
+        louvain(&graph, |e| 11. - *e.weight(), 1., 0),
+        leiden(&graph, |e| 11. - *e.weight(), 1., 0),
+    ] {
+        assert_eq!(communities, vec![0, 1, 1, 0]);
+    }
+}
+
+#[test]
+fn trivial_graphs() {
+    let graph = UnGraph::<(), f64>::default();
+    assert!(louvain(&graph, |_| 1., 1., 0).is_empty());
+    assert!(leiden(&graph, |_| 1., 1., 0).is_empty());
+
+    let mut graph = UnGraph::<(), f64>::default();
+    graph.add_node(());
+    graph.add_node(());
+    assert_eq!(louvain(&graph, |_| 1., 1., 0), vec![0, 1]);
+    assert_eq!(leiden(&graph, |_| 1., 1., 0), vec![0, 1]);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn stable_graph_with_holes() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    let mut graph = StableUnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 4), (4, 5)]);
+    graph.extend_with_edges([(5, 3), (2, 3)]);
+    graph.remove_node(1.into());
+
+    for communities in [
+        louvain(&graph, |_| 1., 1., 0),
+        leiden(&graph, |_| 1., 1., 0),
+    ] {
+        assert_eq!(communities.len(), 6);
+        assert_eq!(communities[1], usize::MAX);
+        assert_eq!(communities[3], communities[4]);
+        assert_eq!(communities[3], communities[5]);
+    }
+}
+
+#[test]
+fn label_propagation_ring_of_cliques() {
+    let graph = ring_of_cliques(6, 5);
+    let communities = label_propagation(&graph, |_| 1.);
+    assert_eq!(communities.iter().collect::<HashSet<_>>().len(), 6);
+
+    // Asynchronous propagation may also merge neighboring cliques.
+    let mut all = vec![communities];
+    all.extend((0..10).map(|seed| asynchronous_label_propagation(&graph, |_| 1., seed)));
+    for communities in all {
+        assert_numbered(&communities);
+        for node in 0..30 {
+            assert_eq!(communities[node], communities[node - node % 5]);
+        }
+    }
+}
+
+#[test]
+fn label_propagation_converges() {
+    // Synchronous updates would make the two sides swap their labels forever.
+    let graph = UnGraph::<(), ()>::from_edges([
+        (0, 3),
+        (0, 4),
+        (0, 5),
+        (1, 3),
+        (1, 4),
+        (1, 5),
+        (2, 3),
+        (2, 4),
+        (2, 5),
+    ]);
+    assert_eq!(label_propagation(&graph, |_| 1.), vec![0; 6]);
+    for seed in 0..10 {
+        assert_numbered(&asynchronous_label_propagation(&graph, |_| 1., seed));
+    }
+}
+
+#[test]
+fn label_propagation_weights_and_isolated_nodes() {
+    let mut graph = UnGraph::<(), f64>::from_edges([(0, 1, 3.), (1, 2, 1.), (2, 3, 3.)]);
+    graph.add_node(());
+    assert_eq!(
+        label_propagation(&graph, |e| *e.weight()),
+        vec![0, 0, 1, 1, 2]
+    );
+    assert_eq!(label_propagation(&graph, |_| 1.), vec![0, 0, 0, 0, 1]);
+
+    let graph = UnGraph::<(), f64>::default();
+    assert!(label_propagation(&graph, |_| 1.).is_empty());
+    assert!(asynchronous_label_propagation(&graph, |_| 1., 0).is_empty());
+}
+
+#[test]
+fn label_propagation_karate_club() {
+    let graph = karate_club();
+    for communities in [
+        label_propagation(&graph, |_| 1.),
+        asynchronous_label_propagation(&graph, |_| 1., 5),
+    ] {
+        assert_numbered(&communities);
+        assert!(modularity(&graph, |_| 1., &communities, 1.) > 0.);
+    }
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_label_propagation_matches() {
+    let graph = karate_club();
+    assert_eq!(
+        parallel_label_propagation(&graph, |_| 1.),
+        label_propagation(&graph, |_| 1.)
+    );
+    assert_eq!(
+        parallel_label_propagation(&graph, |e| *e.weight()),
+        label_propagation(&graph, |e| *e.weight())
+    );
+    let graph = ring_of_cliques(20, 6);
+    assert_eq!(
+        parallel_label_propagation(&graph, |_| 1.),
+        label_propagation(&graph, |_| 1.)
+    );
+}
+
+#[test]
+fn fluid_communities_partitions() {
+    let graph = ring_of_cliques(4, 6);
+    let communities = fluid_communities(&graph, 4, 100, 0);
+    for node in 0..24 {
+        assert_eq!(communities[node], communities[node - node % 6]);
+    }
+
+    let graph = karate_club();
+    for seed in 0..10 {
+        let communities = fluid_communities(&graph, 3, 100, seed);
+        assert_numbered(&communities);
+        assert_eq!(communities.iter().collect::<HashSet<_>>().len(), 3);
+        assert!(modularity(&graph, |_| 1., &communities, 1.) > 0.);
+    }
+    assert_eq!(
+        fluid_communities(&graph, 3, 100, 1),
+        fluid_communities(&graph, 3, 100, 1)
+    );
+}
+
+#[test]
+fn fluid_communities_single_and_disconnected() {
+    let graph = karate_club();
+    assert_eq!(fluid_communities(&graph, 1, 100, 0), vec![0; 34]);
+    let communities = fluid_communities(&graph, 34, 100, 0);
+    assert_eq!(communities.iter().collect::<HashSet<_>>().len(), 34);
+
+    // A single community cannot reach the other component.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+    let communities = fluid_communities(&graph, 1, 100, 0);
+    let reached = communities.iter().filter(|&&c| c == 0).count();
+    assert_eq!(reached, 2);
+    assert_eq!(communities.iter().filter(|&&c| c == usize::MAX).count(), 2);
+}
+
+#[test]
+#[should_panic]
+fn fluid_communities_too_many() {
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    fluid_communities(&graph, 4, 100, 0);
+}
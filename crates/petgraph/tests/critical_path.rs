@@ -0,0 +1,143 @@
+use petgraph::{
+    Direction::{Incoming, Outgoing},
+    acyclic::Acyclic,
+    algo::{acyclic_critical_path, bellman_ford, dag_critical_path},
+    graph::DiGraph,
+    visit::EdgeRef,
+};
+use rand::{ChaChaRng, Rng};
+use utils::{random_dag, seeded};
+
+mod utils;
+
+/// Random integer duration, zero a third of the time, and possibly negative if `negative`.
+fn random_duration(rng: &mut ChaChaRng, negative: bool) -> i64 {
+    if rng.gen_range(0, 3) == 0 {
+        0
+    } else {
+        rng.gen_range(if negative { -10 } else { 1 }, 20)
+    }
+}
+
+/// Longest paths from the nodes without predecessors, by Bellman-Ford on negated durations from
+/// an extra node joined to them, in the graph or its reverse.
+fn longest_by_bellman_ford(graph: &DiGraph<(), i64>, reverse: bool) -> Vec<i64> {
+    let mut negated = graph.map(|_, _| (), |_, &cost| -cost as f64);
+    if reverse {
+        negated.reverse();
+    }
+    let source = negated.add_node(());
+    for a in graph.externals(if reverse { Outgoing } else { Incoming }) {
+        negated.add_edge(source, a, 0.0);
+    }
+    let paths = bellman_ford(&negated, source).unwrap();
+    graph
+        .node_indices()
+        .map(|a| -paths.distances[a.index()] as i64)
+        .collect()
+}
+
+#[test]
+fn critical_path_random() {
+    let mut rng = seeded(49);
+    for _ in 0..200 {
+        let n = rng.gen_range(1, 25);
+        let m = rng.gen_range(0, 3 * n);
+        let negative = rng.gen_range(0, 2) == 0;
+        let graph = random_dag(&mut rng, n, m, |rng| random_duration(rng, negative));
+        let cpm = dag_critical_path(&graph, |e| *e.weight()).unwrap();
+
+        let earliest = longest_by_bellman_ford(&graph, false);
+        assert_eq!(cpm.earliest, earliest, "{graph:?}");
+        let sinks = graph.externals(Outgoing);
+        assert_eq!(
+            cpm.length,
+            sinks.map(|a| earliest[a.index()]).max().unwrap()
+        );
+
+        // The path goes from a source to a sink, with the length of the longest path.
+        let (first, last) = (cpm.path[0], *cpm.path.last().unwrap());
+        assert_eq!(graph.neighbors_directed(first, Incoming).count(), 0);
+        assert_eq!(graph.neighbors_directed(last, Outgoing).count(), 0);
+        let mut length = 0;
+        for pair in cpm.path.windows(2) {
+            length += graph
+                .edges_connecting(pair[0], pair[1])
+                .map(|e| *e.weight())
+                .max()
+                .unwrap();
+        }
+        assert_eq!(length, cpm.length, "{graph:?}");
+
+        // The latest time of a node leaves room for the longest path from it to a sink.
+        let tails = longest_by_bellman_ford(&graph, true);
+        for a in graph.node_indices() {
+            let i = a.index();
+            assert_eq!(cpm.latest[i], cpm.length - tails[i], "{graph:?}");
+            assert_eq!(cpm.node_slack[i], cpm.latest[i] - cpm.earliest[i]);
+            assert!(cpm.node_slack[i] >= 0);
+        }
+        for &a in &cpm.path {
+            assert_eq!(cpm.node_slack[a.index()], 0);
+        }
+        for e in graph.edge_references() {
+            let slack = cpm.edge_slack[e.id().index()];
+            assert_eq!(
+                slack,
+                cpm.latest[e.target().index()] - cpm.earliest[e.source().index()] - e.weight()
+            );
+            assert!(slack >= 0);
+        }
+    }
+}
+
+#[test]
+fn critical_path_zero_durations() {
+    // Dummy activities and milestones of duration zero at the start and at the end of the path.
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 0), (1, 2, 5)]);
+    let cpm = dag_critical_path(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(cpm.length, 5);
+    assert_eq!(cpm.path, [0.into(), 1.into(), 2.into()]);
+
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 5), (1, 2, 0)]);
+    let cpm = dag_critical_path(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(cpm.length, 5);
+    assert_eq!(cpm.path, [0.into(), 1.into(), 2.into()]);
+    assert_eq!(cpm.node_slack, [0, 0, 0]);
+
+    // Only durations of zero.
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 0), (1, 2, 0), (0, 3, 0)]);
+    let cpm = dag_critical_path(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(cpm.length, 0);
+    assert!(cpm.path == [0.into(), 1.into(), 2.into()] || cpm.path == [0.into(), 3.into()]);
+    assert_eq!(cpm.edge_slack, [0, 0, 0]);
+}
+
+#[test]
+fn critical_path_acyclic() {
+    let mut rng = seeded(50);
+    for _ in 0..50 {
+        let n = rng.gen_range(1, 25);
+        let m = rng.gen_range(0, 3 * n);
+        let graph = random_dag(&mut rng, n, m, |rng| random_duration(rng, false));
+        let acyclic = Acyclic::try_from_graph(graph.clone()).unwrap();
+        let expected = dag_critical_path(&graph, |e| *e.weight()).unwrap();
+        let cpm = acyclic_critical_path(&acyclic, |e| *e.weight());
+        assert_eq!(cpm.length, expected.length);
+        assert_eq!(cpm.earliest, expected.earliest);
+        assert_eq!(cpm.latest, expected.latest);
+        assert_eq!(cpm.node_slack, expected.node_slack);
+        assert_eq!(cpm.edge_slack, expected.edge_slack);
+    }
+}
+
+#[test]
+fn critical_path_cycle() {
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 1), (2, 1, 1)]);
+    assert!(dag_critical_path(&graph, |e| *e.weight()).is_err());
+
+    let empty = DiGraph::<(), u32>::new();
+    let cpm = dag_critical_path(&empty, |e| *e.weight()).unwrap();
+    assert_eq!(cpm.length, 0);
+    assert!(cpm.path.is_empty());
+}
@@ -0,0 +1,80 @@
+use petgraph::{
+    acyclic::Acyclic,
+    algo::{acyclic_shortest_paths, bellman_ford, dag_shortest_paths},
+    graph::NodeIndex,
+};
+use rand::Rng;
+use utils::{random_dag, seeded};
+
+mod utils;
+
+#[test]
+fn dag_shortest_paths_random() {
+    let mut rng = seeded(50);
+    for _ in 0..200 {
+        let n = rng.gen_range(1, 25);
+        let m = rng.gen_range(0, 3 * n);
+        let graph = random_dag(&mut rng, n, m, |rng| rng.gen_range(-10, 20) as f64);
+        let source = NodeIndex::new(rng.gen_range(0, n));
+        let paths = dag_shortest_paths(&graph, source, |e| *e.weight()).unwrap();
+        let expected = bellman_ford(&graph, source).unwrap();
+
+        for a in graph.node_indices() {
+            let (dist, expected_dist) = (paths.distances[a.index()], expected.distances[a.index()]);
+            if expected_dist.is_infinite() {
+                assert_eq!(dist, f64::MAX, "{a:?} from {source:?} in {graph:?}");
+                assert_eq!(paths.predecessors[a.index()], None);
+                continue;
+            }
+            assert_eq!(dist, expected_dist, "{a:?} from {source:?} in {graph:?}");
+            // The predecessor is the source of an edge along a shortest path.
+            match paths.predecessors[a.index()] {
+                None => assert_eq!(a, source),
+                Some(p) => assert!(
+                    graph
+                        .edges_connecting(p, a)
+                        .any(|e| paths.distances[p.index()] + e.weight() == dist)
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn dag_shortest_paths_acyclic() {
+    let mut rng = seeded(51);
+    for _ in 0..50 {
+        let n = rng.gen_range(1, 25);
+        let m = rng.gen_range(0, 3 * n);
+        let graph = random_dag(&mut rng, n, m, |rng| rng.gen_range(-10, 20) as f64);
+        let source = NodeIndex::new(rng.gen_range(0, n));
+        let expected = dag_shortest_paths(&graph, source, |e| *e.weight()).unwrap();
+        let acyclic = Acyclic::try_from_graph(graph).unwrap();
+        let paths = acyclic_shortest_paths(&acyclic, source, |e| *e.weight());
+        assert_eq!(paths.distances, expected.distances);
+    }
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn dag_shortest_paths_stable_graph() {
+    use petgraph::stable_graph::StableDiGraph;
+
+    let mut graph = StableDiGraph::<(), i32>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.add_edge(a, b, 3);
+    graph.add_edge(a, d, 1);
+    graph.add_edge(b, d, -5);
+    graph.add_edge(a, c, 1);
+    graph.remove_node(c);
+
+    let paths = dag_shortest_paths(&graph, a, |e| *e.weight()).unwrap();
+    assert_eq!(paths.distances, [0, 3, i32::MAX, -2]);
+    assert_eq!(paths.predecessors, [None, Some(a), None, Some(b)]);
+
+    graph.add_edge(d, a, 1);
+    assert!(dag_shortest_paths(&graph, a, |e| *e.weight()).is_err());
+}
@@ -0,0 +1,246 @@
+use petgraph::{
+    Directed, EdgeType, Graph, Undirected,
+    algo::{eccentricity, floyd_warshall, ifub_diameter, weighted_eccentricity},
+    graph::{NodeIndex, UnGraph},
+    visit::UndirectedAdaptor,
+};
+use rand::Rng;
+use utils::{random_graph, random_tree, seeded};
+
+mod utils;
+
+/// Eccentricities from all shortest path lengths.
+fn brute_force_eccentricities<Ty: EdgeType>(
+    graph: &Graph<(), u32, Ty>,
+    weighted: bool,
+) -> Vec<Option<u32>> {
+    let distances = floyd_warshall(graph, |e| if weighted { *e.weight() } else { 1 }).unwrap();
+    graph
+        .node_indices()
+        .map(|a| {
+            graph
+                .node_indices()
+                .map(|b| distances.get(&(a, b)).copied().filter(|&d| d != u32::MAX))
+                .try_fold(0, |max, d| Some(max.max(d?)))
+        })
+        .collect()
+}
+
+fn karate_club() -> UnGraph<(), ()> {
+    UnGraph::from_edges([
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (0, 4),
+        (0, 5),
+        (0, 6),
+        (0, 7),
+        (0, 8),
+        (0, 10),
+        (0, 11),
+        (0, 12),
+        (0, 13),
+        (0, 17),
+        (0, 19),
+        (0, 21),
+        (0, 31),
+        (1, 2),
+        (1, 3),
+        (1, 7),
+        (1, 13),
+        (1, 17),
+        (1, 19),
+        (1, 21),
+        (1, 30),
+        (2, 3),
+        (2, 7),
+        (2, 8),
+        (2, 9),
+        (2, 13),
+        (2, 27),
+        (2, 28),
+        (2, 32),
+        (3, 7),
+        (3, 12),
+        (3, 13),
+        (4, 6),
+        (4, 10),
+        (5, 6),
+        (5, 10),
+        (5, 16),
+        (6, 16),
+        (8, 30),
+        (8, 32),
+        (8, 33),
+        (9, 33),
+        (13, 33),
+        (14, 32),
+        (14, 33),
+        (15, 32),
+        (15, 33),
+        (18, 32),
+        (18, 33),
+        (19, 33),
+        (20, 32),
+        (20, 33),
+        (22, 32),
+        (22, 33),
+        (23, 25),
+        (23, 27),
+        (23, 29),
+        (23, 32),
+        (23, 33),
+        (24, 25),
+        (24, 27),
+        (24, 31),
+        (25, 31),
+        (26, 29),
+        (26, 33),
+        (27, 33),
+        (28, 31),
+        (28, 33),
+        (29, 32),
+        (29, 33),
+        (30, 32),
+        (30, 33),
+        (31, 32),
+        (31, 33),
+        (32, 33),
+    ])
+}
+
+#[test]
+fn eccentricity_karate_club() {
+    let graph = karate_club();
+    let eccentricity = eccentricity(&graph);
+    let expected = [
+        3, 3, 3, 3, 4, 4, 4, 4, 3, 4, 4, 4, 4, 3, 5, 5, 5, 4, 5, 3, 5, 4, 5, 5, 4, 4, 5, 4, 4, 5,
+        4, 3, 4, 4,
+    ];
+    for node in graph.node_indices() {
+        assert_eq!(
+            eccentricity.eccentricity(node),
+            Some(expected[node.index()])
+        );
+    }
+    let indices = |nodes: Vec<NodeIndex>| nodes.iter().map(|n| n.index()).collect::<Vec<_>>();
+    assert_eq!(eccentricity.diameter(), Some(5));
+    assert_eq!(eccentricity.radius(), Some(3));
+    assert_eq!(
+        indices(eccentricity.center()),
+        vec![0, 1, 2, 3, 8, 13, 19, 31]
+    );
+    assert_eq!(
+        indices(eccentricity.periphery()),
+        vec![14, 15, 16, 18, 20, 22, 23, 26, 29]
+    );
+    assert_eq!(ifub_diameter(&graph), Some(5));
+}
+
+#[test]
+fn eccentricity_compare_with_floyd_warshall() {
+    for seed in 0..10 {
+        let graph = random_graph::<_, _, Undirected>(
+            &mut seeded(seed),
+            20,
+            30,
+            |_| (),
+            |rng| rng.gen_range(1, 10),
+        );
+        let unweighted = eccentricity(&graph);
+        let weighted = weighted_eccentricity(&graph, |e| *e.weight());
+        for (node, expected) in graph
+            .node_indices()
+            .zip(brute_force_eccentricities(&graph, false))
+        {
+            assert_eq!(unweighted.eccentricity(node).map(|e| e as u32), expected);
+        }
+        for (node, expected) in graph
+            .node_indices()
+            .zip(brute_force_eccentricities(&graph, true))
+        {
+            assert_eq!(weighted.eccentricity(node), expected);
+        }
+
+        let graph = random_graph::<_, _, Directed>(
+            &mut seeded(seed),
+            15,
+            45,
+            |_| (),
+            |rng| rng.gen_range(1, 10),
+        );
+        let weighted = weighted_eccentricity(&graph, |e| *e.weight());
+        for (node, expected) in graph
+            .node_indices()
+            .zip(brute_force_eccentricities(&graph, true))
+        {
+            assert_eq!(weighted.eccentricity(node), expected);
+        }
+    }
+}
+
+#[test]
+fn ifub_diameter_compare_with_eccentricity() {
+    for seed in 0..40 {
+        // A random tree with a few extra edges.
+        let mut rng = seeded(seed);
+        let mut graph = random_tree(&mut rng, 60);
+        for _ in 0..seed / 4 {
+            let a = NodeIndex::new(rng.gen_range(0, 60));
+            let b = NodeIndex::new(rng.gen_range(0, 60));
+            graph.add_edge(a, b, ());
+        }
+        assert_eq!(ifub_diameter(&graph), eccentricity(&graph).diameter());
+    }
+
+    // Cycles and paths, whose diameter is reached by many pairs.
+    for n in 2..12 {
+        let path = UnGraph::<(), ()>::from_edges((1..n).map(|i| (i - 1, i)));
+        let mut cycle = path.clone();
+        cycle.add_edge(NodeIndex::new(0), NodeIndex::new(n as usize - 1), ());
+        assert_eq!(ifub_diameter(&path), Some(n as usize - 1));
+        assert_eq!(ifub_diameter(&cycle), Some(n as usize / 2));
+    }
+}
+
+#[test]
+fn eccentricity_disconnected() {
+    // A directed path: only the first node reaches every other node.
+    let graph = Graph::<(), u32, Directed>::from_edges([(0, 1, 1), (1, 2, 1)]);
+    let result = eccentricity(&graph);
+    let nodes: Vec<_> = graph.node_indices().collect();
+    assert_eq!(result.eccentricity(nodes[0]), Some(2));
+    assert_eq!(result.eccentricity(nodes[1]), None);
+    assert_eq!(result.diameter(), None);
+    assert_eq!(result.radius(), None);
+    assert!(result.center().is_empty());
+    assert!(result.periphery().is_empty());
+    assert_eq!(ifub_diameter(UndirectedAdaptor(&graph)), Some(2));
+
+    let mut graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+    graph.add_node(());
+    assert_eq!(ifub_diameter(&graph), None);
+    assert_eq!(ifub_diameter(&UnGraph::<(), ()>::default()), None);
+    assert_eq!(eccentricity(&UnGraph::<(), ()>::default()).diameter(), None);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn eccentricity_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    // A star whose center is removed, leaving a path through a second center.
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    for (a, b) in [(0, 1), (0, 2), (0, 3), (1, 4), (4, 2)] {
+        graph.add_edge(nodes[a], nodes[b], ());
+    }
+    graph.remove_node(nodes[3]);
+    graph.remove_node(nodes[0]);
+    let eccentricity = eccentricity(&graph);
+    assert_eq!(eccentricity.eccentricity(nodes[0]), None);
+    assert_eq!(eccentricity.diameter(), Some(2));
+    assert_eq!(eccentricity.center(), vec![nodes[4]]);
+    assert_eq!(eccentricity.periphery(), vec![nodes[1], nodes[2]]);
+    assert_eq!(ifub_diameter(&graph), Some(2));
+}
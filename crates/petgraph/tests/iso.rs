@@ -11,6 +11,8 @@ use petgraph::{
     prelude::*,
 };
 
+mod utils;
+
 /// Petersen A and B are isomorphic
 ///
 /// http://www.dharwadker.org/tevet/isomorphism/
@@ -479,15 +481,105 @@ fn iso_large() {
     assert!(petgraph::algo::is_isomorphic(&g0, &g1));
 }
 
-// isomorphism isn't correct for multigraphs.
-// Keep this testcase to document how
-#[should_panic]
 #[test]
-fn iso_multigraph_failure() {
+fn iso_multigraph() {
+    // Same adjacency, but different multiplicities.
     let g0 = Graph::<(), ()>::from_edges([(0, 0), (0, 0), (0, 1), (1, 1), (1, 1), (1, 0)]);
-
     let g1 = Graph::<(), ()>::from_edges([(0, 0), (0, 1), (0, 1), (1, 1), (1, 0), (1, 0)]);
     assert!(!is_isomorphic(&g0, &g1));
+    let g2 = Graph::<(), ()>::from_edges([(1, 0), (1, 1), (0, 1), (1, 1), (0, 0), (0, 0)]);
+    assert!(is_isomorphic(&g0, &g2));
+
+    // A netlist: two parallel resistors between the same pair of nodes.
+    let g0 = UnGraph::<(), u32>::from_edges([(0, 1, 10), (0, 1, 20), (1, 2, 10)]);
+    let g1 = UnGraph::<(), u32>::from_edges([(2, 1, 10), (0, 1, 20), (0, 1, 10)]);
+    let g2 = UnGraph::<(), u32>::from_edges([(2, 1, 20), (0, 1, 10), (0, 1, 10)]);
+    assert!(is_isomorphic(&g0, &g2));
+    assert!(is_isomorphic_matching(&g0, &g1, |_, _| true, |a, b| a == b));
+    assert!(!is_isomorphic_matching(
+        &g0,
+        &g2,
+        |_, _| true,
+        |a, b| a == b
+    ));
+
+    // Subgraphs must have the same multiplicities as well.
+    let pattern = UnGraph::<(), ()>::from_edges([(0, 1), (0, 1)]);
+    let single = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    let double = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 1)]);
+    assert!(!is_isomorphic_subgraph(&pattern, &single));
+    assert!(is_isomorphic_subgraph(&pattern, &double));
+}
+
+#[test]
+fn iso_multigraph_compare_with_vf2pp() {
+    use petgraph::algo::{MatchMode, vf2pp_matches};
+    use rand::{ChaChaRng, Rng};
+    use utils::{random_graph, random_permutation, seeded};
+
+    fn random_multigraph<Ty: EdgeType>(
+        rng: &mut ChaChaRng,
+        n: usize,
+        m: usize,
+    ) -> Graph<(), u8, Ty> {
+        random_graph(rng, n, m, |_| (), |rng| rng.gen_range(0, 2))
+    }
+
+    fn check<Ty: EdgeType>(g0: &Graph<(), u8, Ty>, g1: &Graph<(), u8, Ty>) {
+        let expected = |mode| {
+            vf2pp_matches(g0, g1, mode, |_, _| true, |_, _| true)
+                .next()
+                .is_some()
+        };
+        assert_eq!(is_isomorphic(g0, g1), expected(MatchMode::Isomorphism));
+        assert_eq!(
+            is_isomorphic_subgraph(g0, g1),
+            expected(MatchMode::InducedSubgraph)
+        );
+
+        // The edges must match as multisets of weights.
+        let expected = vf2pp_matches(
+            g0,
+            g1,
+            MatchMode::Isomorphism,
+            |_, _| true,
+            |a, b| a.weight() == b.weight(),
+        )
+        .next()
+        .is_some();
+        let actual = is_isomorphic_matching(g0, g1, |_, _| true, |a, b| a == b);
+        assert_eq!(actual, expected);
+    }
+
+    let mut rng = seeded(7);
+    for _ in 0..300 {
+        let n = rng.gen_range(1, 6);
+        let m = rng.gen_range(0, 3 * n);
+        let g0 = random_multigraph::<Directed>(&mut rng, n, m);
+        // A relabeled copy, or another random graph.
+        let g1 = if rng.gen_range(0, 2) == 0 {
+            let permutation = random_permutation(&mut rng, n);
+            let mut g1 = Graph::with_capacity(n, m);
+            for _ in 0..n {
+                g1.add_node(());
+            }
+            for edge in g0.raw_edges().iter().rev() {
+                g1.add_edge(
+                    node_index(permutation[edge.source().index()]),
+                    node_index(permutation[edge.target().index()]),
+                    edge.weight,
+                );
+            }
+            g1
+        } else {
+            random_multigraph::<Directed>(&mut rng, n, m)
+        };
+        check(&g0, &g1);
+
+        let g0 = random_multigraph::<Undirected>(&mut rng, n, m);
+        let g1 = random_multigraph::<Undirected>(&mut rng, n + 1, m + 2);
+        check(&g0, &g1);
+    }
 }
 
 #[test]
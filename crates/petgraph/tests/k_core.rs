@@ -0,0 +1,231 @@
+use std::collections::HashSet;
+
+use petgraph::{
+    EdgeType, Graph,
+    algo::{DegreeKind, core_numbers, k_core, k_truss, maximal_cliques, truss_numbers},
+    graph::{DiGraph, NodeIndex, UnGraph},
+    visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers},
+};
+use utils::{random_graph, seeded};
+
+mod utils;
+
+/// Core numbers by repeatedly removing all nodes of degree less than `k`.
+fn brute_force_cores<Ty: EdgeType>(graph: &Graph<(), (), Ty>, degree: DegreeKind) -> Vec<usize> {
+    let n = graph.node_count();
+    let mut cores = vec![0; n];
+    for k in 1..n {
+        let mut alive = vec![true; n];
+        loop {
+            let mut degrees = vec![0; n];
+            for edge in graph.edge_references() {
+                let (s, t) = (edge.source().index(), edge.target().index());
+                if s == t || !alive[s] || !alive[t] {
+                    continue;
+                }
+                if !Ty::is_directed() || degree != DegreeKind::Out {
+                    degrees[t] += 1;
+                }
+                if !Ty::is_directed() || degree != DegreeKind::In {
+                    degrees[s] += 1;
+                }
+            }
+            let before = alive.iter().filter(|&&a| a).count();
+            for v in 0..n {
+                alive[v] &= degrees[v] >= k;
+            }
+            if alive.iter().filter(|&&a| a).count() == before {
+                break;
+            }
+        }
+        for v in (0..n).filter(|&v| alive[v]) {
+            cores[v] = k;
+        }
+    }
+    cores
+}
+
+/// Truss numbers by repeatedly removing all links in fewer than `k - 2` triangles.
+fn brute_force_trusses(graph: &UnGraph<(), ()>) -> Vec<usize> {
+    let n = graph.node_count();
+    let mut links = HashSet::new();
+    for edge in graph.edge_references() {
+        let (s, t) = (edge.source().index(), edge.target().index());
+        if s != t {
+            links.insert((s.min(t), s.max(t)));
+        }
+    }
+    let link = |a: usize, b: usize| (a.min(b), a.max(b));
+
+    let mut trusses = std::collections::HashMap::new();
+    for &l in &links {
+        trusses.insert(l, 2);
+    }
+    for k in 3..n + 2 {
+        let mut alive = links.clone();
+        loop {
+            let removed: Vec<_> = alive
+                .iter()
+                .copied()
+                .filter(|&(a, b)| {
+                    let triangles = (0..n)
+                        .filter(|&c| alive.contains(&link(a, c)) && alive.contains(&link(b, c)))
+                        .count();
+                    triangles + 2 < k
+                })
+                .collect();
+            if removed.is_empty() {
+                break;
+            }
+            for l in removed {
+                alive.remove(&l);
+            }
+        }
+        for l in alive {
+            trusses.insert(l, k);
+        }
+    }
+
+    graph
+        .edge_references()
+        .map(|edge| {
+            let (s, t) = (edge.source().index(), edge.target().index());
+            if s == t { 0 } else { trusses[&link(s, t)] }
+        })
+        .collect()
+}
+
+#[test]
+fn core_numbers_small() {
+    // A 4-clique with a tail, a self loop and an isolated node.
+    let mut graph = UnGraph::<(), ()>::from_edges([
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 5),
+    ]);
+    graph.add_node(());
+    assert_eq!(
+        core_numbers(&graph, DegreeKind::Total),
+        vec![3, 3, 3, 3, 1, 1, 0]
+    );
+
+    let graph = UnGraph::<(), ()>::default();
+    assert!(core_numbers(&graph, DegreeKind::Total).is_empty());
+}
+
+#[test]
+fn core_numbers_directed() {
+    // Node 3 only has outgoing edges, node 4 only incoming ones.
+    let graph = DiGraph::<(), ()>::from_edges([
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (0, 2),
+        (1, 0),
+        (2, 1),
+        (3, 0),
+        (3, 1),
+        (0, 4),
+        (1, 4),
+    ]);
+    assert_eq!(core_numbers(&graph, DegreeKind::In), vec![2, 2, 2, 0, 2]);
+    assert_eq!(core_numbers(&graph, DegreeKind::Out), vec![2, 2, 2, 2, 0]);
+    assert_eq!(core_numbers(&graph, DegreeKind::Total), vec![4, 4, 4, 2, 2]);
+}
+
+#[test]
+fn core_numbers_compare_with_brute_force() {
+    for seed in 0..10 {
+        let graph =
+            random_graph::<_, _, petgraph::Undirected>(&mut seeded(seed), 25, 70, |_| (), |_| ());
+        assert_eq!(
+            core_numbers(&graph, DegreeKind::Total),
+            brute_force_cores(&graph, DegreeKind::Total)
+        );
+
+        let graph =
+            random_graph::<_, _, petgraph::Directed>(&mut seeded(seed), 25, 120, |_| (), |_| ());
+        for degree in [DegreeKind::In, DegreeKind::Out, DegreeKind::Total] {
+            assert_eq!(
+                core_numbers(&graph, degree),
+                brute_force_cores(&graph, degree)
+            );
+        }
+    }
+}
+
+#[test]
+fn k_core_view() {
+    let graph = random_graph::<_, _, petgraph::Undirected>(&mut seeded(3), 40, 120, |_| (), |_| ());
+    let cores = core_numbers(&graph, DegreeKind::Total);
+    for k in 0..6 {
+        let core = k_core(&graph, k, DegreeKind::Total);
+        let nodes: Vec<NodeIndex> = core.node_identifiers().collect();
+        assert_eq!(nodes.len(), cores.iter().filter(|&&c| c >= k).count());
+        // Every node of the view keeps at least `k` neighbors in it.
+        for &node in &nodes {
+            let degree = core
+                .edge_references()
+                .filter(|e| e.source() != e.target() && (e.source() == node || e.target() == node))
+                .count();
+            assert!(degree >= k);
+        }
+    }
+}
+
+#[test]
+fn k_core_before_maximal_cliques() {
+    // A 5-clique hidden among noise.
+    let mut graph =
+        random_graph::<_, _, petgraph::Undirected>(&mut seeded(11), 30, 30, |_| (), |_| ());
+    for a in 0..5 {
+        for b in a + 1..5 {
+            graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        }
+    }
+    let core = k_core(&graph, 4, DegreeKind::Total);
+    let cliques = maximal_cliques(&core);
+    assert_eq!(cliques.len(), 1);
+    assert!((0..5).all(|i| cliques[0].contains(&NodeIndex::new(i))));
+}
+
+#[test]
+fn truss_numbers_small() {
+    // A 4-clique with a triangle, a pendant edge, a parallel edge and a self loop.
+    let graph = UnGraph::<(), ()>::from_edges([
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 3),
+        (5, 6),
+        (1, 0),
+        (6, 6),
+    ]);
+    assert_eq!(
+        truss_numbers(&graph),
+        vec![4, 4, 4, 4, 4, 4, 3, 3, 3, 2, 4, 0]
+    );
+
+    let truss = k_truss(&graph, 3);
+    assert_eq!(truss.edge_references().count(), 10);
+}
+
+#[test]
+fn truss_numbers_compare_with_brute_force() {
+    for seed in 0..10 {
+        let graph =
+            random_graph::<_, _, petgraph::Undirected>(&mut seeded(seed), 15, 50, |_| (), |_| ());
+        assert_eq!(truss_numbers(&graph), brute_force_trusses(&graph));
+    }
+}
@@ -0,0 +1,191 @@
+use petgraph::{
+    Undirected,
+    algo::{maximal_cliques, maximum_clique, maximum_independent_set, minimum_vertex_cover},
+    graph::{DiGraph, NodeIndex, UnGraph},
+};
+use rand::Rng;
+use utils::{random_graph, seeded};
+
+mod utils;
+
+fn is_clique(graph: &UnGraph<u32, ()>, nodes: &[NodeIndex]) -> bool {
+    nodes.iter().enumerate().all(|(i, &a)| {
+        nodes[i + 1..]
+            .iter()
+            .all(|&b| a != b && graph.contains_edge(a, b))
+    })
+}
+
+fn is_independent(graph: &UnGraph<u32, ()>, nodes: &[NodeIndex]) -> bool {
+    nodes.iter().enumerate().all(|(i, &a)| {
+        nodes[i + 1..]
+            .iter()
+            .all(|&b| a != b && !graph.contains_edge(a, b))
+    })
+}
+
+/// The largest total weight of a subset of nodes satisfying `valid`, over all subsets.
+fn brute_force(
+    graph: &UnGraph<u32, ()>,
+    weighted: bool,
+    valid: impl Fn(&[NodeIndex]) -> bool,
+) -> u32 {
+    let n = graph.node_count();
+    (0..1u32 << n)
+        .map(|mask| {
+            (0..n)
+                .filter(|&i| mask & (1 << i) != 0)
+                .map(NodeIndex::new)
+                .collect::<Vec<_>>()
+        })
+        .filter(|nodes| valid(nodes))
+        .map(|nodes| {
+            nodes
+                .iter()
+                .map(|&node| if weighted { graph[node] } else { 1 })
+                .sum()
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn maximum_clique_compare_with_brute_force() {
+    for seed in 0..20 {
+        let graph = random_graph::<_, _, Undirected>(
+            &mut seeded(seed),
+            12,
+            40,
+            |rng| rng.gen_range(0, 10),
+            |_| (),
+        );
+        for weighted in [false, true] {
+            let weight = |node: NodeIndex| if weighted { graph[node] } else { 1 };
+            let clique = maximum_clique(&graph, weight, None);
+            assert!(clique.optimal);
+            assert!(is_clique(&graph, &clique.nodes));
+            assert_eq!(clique.weight, clique.nodes.iter().map(|&n| weight(n)).sum());
+            assert_eq!(
+                clique.weight,
+                brute_force(&graph, weighted, |nodes| is_clique(&graph, nodes))
+            );
+        }
+    }
+}
+
+#[test]
+fn maximum_independent_set_compare_with_brute_force() {
+    for seed in 0..20 {
+        let graph = random_graph::<_, _, Undirected>(
+            &mut seeded(seed),
+            12,
+            15,
+            |rng| rng.gen_range(0, 10),
+            |_| (),
+        );
+        for weighted in [false, true] {
+            let weight = |node: NodeIndex| if weighted { graph[node] } else { 1 };
+            let independent_set = maximum_independent_set(&graph, weight, None);
+            assert!(independent_set.optimal);
+            assert!(is_independent(&graph, &independent_set.nodes));
+            let best = brute_force(&graph, weighted, |nodes| is_independent(&graph, nodes));
+            assert_eq!(independent_set.weight, best);
+
+            // The complement of a maximum independent set is a minimum vertex cover.
+            let cover = minimum_vertex_cover(&graph, weight, None);
+            assert!(cover.optimal);
+            let total: u32 = graph.node_indices().map(weight).sum();
+            assert_eq!(cover.weight, total - best);
+            for edge in graph.raw_edges() {
+                let (a, b) = (edge.source(), edge.target());
+                assert!(a == b || cover.nodes.contains(&a) || cover.nodes.contains(&b));
+            }
+        }
+    }
+}
+
+#[test]
+fn maximum_clique_compare_with_maximal_cliques() {
+    for seed in 0..5 {
+        let graph = random_graph::<_, _, Undirected>(
+            &mut seeded(seed),
+            40,
+            300,
+            |rng| rng.gen_range(0, 10),
+            |_| (),
+        );
+        let largest = maximal_cliques(&graph)
+            .iter()
+            .map(|clique| clique.len())
+            .max()
+            .unwrap();
+        let clique = maximum_clique(&graph, |_| 1, None);
+        assert_eq!(clique.nodes.len(), largest);
+        assert!(is_clique(&graph, &clique.nodes));
+    }
+}
+
+#[test]
+fn maximum_clique_budget() {
+    let graph = random_graph::<_, _, Undirected>(
+        &mut seeded(3),
+        40,
+        300,
+        |rng| rng.gen_range(0, 10),
+        |_| (),
+    );
+    let complete = maximum_clique(&graph, |_| 1, None);
+    let mut previous = 0;
+    for budget in [1, 2, 5, 20, 100_000] {
+        let clique = maximum_clique(&graph, |_| 1, Some(budget));
+        assert!(is_clique(&graph, &clique.nodes));
+        assert!(clique.weight >= previous && clique.weight <= complete.weight);
+        previous = clique.weight;
+        if clique.optimal {
+            assert_eq!(clique.weight, complete.weight);
+        }
+    }
+    assert!(!maximum_clique(&graph, |_| 1, Some(1)).optimal);
+    assert!(maximum_clique(&graph, |_| 1, Some(100_000)).optimal);
+}
+
+#[test]
+fn maximum_clique_directed() {
+    // Only the pairs with edges in both directions are adjacent.
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2), (2, 1), (0, 2), (2, 3)]);
+    let clique = maximum_clique(&graph, |_| 1, None);
+    assert_eq!(clique.weight, 2);
+    let independent_set = maximum_independent_set(&graph, |_| 1, None);
+    assert_eq!(independent_set.weight, 2);
+    assert!(independent_set.nodes.contains(&NodeIndex::new(3)));
+}
+
+#[test]
+fn maximum_clique_trivial() {
+    let graph = UnGraph::<(), ()>::default();
+    let clique = maximum_clique(&graph, |_| 1, None);
+    assert!(clique.nodes.is_empty() && clique.optimal);
+    assert!(minimum_vertex_cover(&graph, |_| 1, None).nodes.is_empty());
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn maximum_clique_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    // Self loops are ignored, and removed nodes are skipped.
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[0], ());
+    graph.add_edge(nodes[0], nodes[2], ());
+    graph.add_edge(nodes[2], nodes[3], ());
+    graph.remove_node(nodes[1]);
+    let clique = maximum_clique(&graph, |_| 1.5, None);
+    assert_eq!(clique.weight, 3.);
+    let independent_set = maximum_independent_set(&graph, |_| 1, None);
+    let mut found = independent_set.nodes.clone();
+    found.sort();
+    assert_eq!(found, vec![nodes[0], nodes[3]]);
+    let cover = minimum_vertex_cover(&graph, |_| 1, None);
+    assert_eq!(cover.nodes, vec![nodes[2]]);
+}
@@ -1,6 +1,10 @@
 #[cfg(feature = "rayon")]
 use petgraph::algo::page_rank::parallel_page_rank;
-use petgraph::{Graph, algo::page_rank};
+use petgraph::{
+    Graph,
+    algo::{DanglingStrategy, PageRankOptions, page_rank, sparse_page_rank},
+    graph::{DiGraph, UnGraph},
+};
 
 fn graph_example() -> Graph<String, f32> {
     // Taken and adapted from https://github.com/neo4j-labs/graph?tab=readme-ov-file#how-to-run-algorithms
@@ -81,3 +85,182 @@ fn test_par_page_rank() {
                 || expected.is_nan())
     );
 }
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{actual:?} != {expected:?}");
+    }
+}
+
+/// A weighted directed graph where node 4 is dangling.
+fn weighted_graph_example() -> DiGraph<(), f64> {
+    let mut graph = DiGraph::new();
+    for _ in 0..5 {
+        graph.add_node(());
+    }
+    graph.extend_with_edges([
+        (0, 1, 1.),
+        (0, 2, 3.),
+        (1, 2, 1.),
+        (2, 0, 2.),
+        (2, 3, 1.),
+        (3, 4, 0.5),
+    ]);
+    graph
+}
+
+// Expected values are computed with NetworkX.
+
+/// Options that iterate until the ranks match the expected values.
+fn converged<'a>() -> PageRankOptions<'a, f64> {
+    PageRankOptions {
+        max_iter: 1000,
+        tol: 1e-14,
+        ..PageRankOptions::default()
+    }
+}
+
+#[test]
+fn test_sparse_page_rank() {
+    let graph = graph_example();
+    let output_ranks = sparse_page_rank(&graph, |_| 1., converged());
+    assert_close(
+        &output_ranks,
+        &[
+            0.03014592969,
+            0.378964211289,
+            0.336574233113,
+            0.014454653517,
+            0.036920649818,
+            0.079291751649,
+            0.036920649818,
+            0.014454653517,
+            0.014454653517,
+            0.014454653517,
+            0.014454653517,
+            0.014454653517,
+            0.014454653517,
+        ],
+    );
+}
+
+#[test]
+fn test_sparse_page_rank_personalized() {
+    let graph = weighted_graph_example();
+    let personalization = [1., 0., 0., 1., 2.];
+    let ranks = |dangling| {
+        sparse_page_rank(
+            &graph,
+            |e| *e.weight(),
+            PageRankOptions {
+                personalization: Some(&personalization),
+                dangling,
+                ..converged()
+            },
+        )
+    };
+    assert_close(
+        &ranks(DanglingStrategy::Personalization),
+        &[
+            0.221014851853,
+            0.046965656019,
+            0.180817775672,
+            0.169783148746,
+            0.381418567711,
+        ],
+    );
+    assert_close(
+        &ranks(DanglingStrategy::Uniform),
+        &[
+            0.232458198166,
+            0.091963490261,
+            0.268927191204,
+            0.156262160658,
+            0.250388959711,
+        ],
+    );
+}
+
+#[test]
+fn test_sparse_page_rank_self_loop() {
+    // Same as giving the dangling node a self loop.
+    let mut graph = weighted_graph_example();
+    let ranks = sparse_page_rank(
+        &graph,
+        |e| *e.weight(),
+        PageRankOptions {
+            dangling: DanglingStrategy::SelfLoop,
+            ..converged()
+        },
+    );
+    let expected = [
+        0.114560919719,
+        0.05434419544,
+        0.149225152445,
+        0.072280459859,
+        0.609589272537,
+    ];
+    assert_close(&ranks, &expected);
+
+    graph.add_edge(4.into(), 4.into(), 1.);
+    let ranks = sparse_page_rank(&graph, |e| *e.weight(), converged());
+    assert_close(&ranks, &expected);
+}
+
+#[test]
+fn test_sparse_page_rank_undirected() {
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (3, 4)]);
+    let ranks = sparse_page_rank(&graph, |_| 1., converged());
+    assert_close(
+        &ranks,
+        &[
+            0.240079105547,
+            0.166044826476,
+            0.240079105547,
+            0.252309321374,
+            0.101487641056,
+        ],
+    );
+}
+
+#[test]
+fn test_sparse_page_rank_trivial() {
+    let graph = DiGraph::<(), ()>::new();
+    let ranks: Vec<f64> = sparse_page_rank(
+        &graph,
+        |_| 1.,
+        PageRankOptions {
+            max_iter: 10,
+            ..PageRankOptions::default()
+        },
+    );
+    assert!(ranks.is_empty());
+
+    // Zero iterations return the initial uniform ranks.
+    let graph = weighted_graph_example();
+    let ranks = sparse_page_rank(
+        &graph,
+        |_| 1.,
+        PageRankOptions {
+            max_iter: 0,
+            ..PageRankOptions::default()
+        },
+    );
+    assert_eq!(ranks, vec![0.2; 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_sparse_page_rank_zero_personalization() {
+    let graph = weighted_graph_example();
+    sparse_page_rank(
+        &graph,
+        |_| 1.,
+        PageRankOptions {
+            personalization: Some(&[0.; 5]),
+            max_iter: 10,
+            ..PageRankOptions::default()
+        },
+    );
+}
@@ -0,0 +1,88 @@
+use petgraph::{
+    algo::{has_path_connecting, reachability_index, reachability_matrix},
+    graph::{DiGraph, NodeIndex},
+};
+use rand::Rng;
+use utils::{random_dag, random_graph, seeded};
+
+mod utils;
+
+#[test]
+fn reachability_index_random() {
+    let mut rng = seeded(48);
+    for _ in 0..200 {
+        let n = rng.gen_range(1, 30);
+        let m = rng.gen_range(0, 3 * n);
+        let acyclic = rng.gen_range(0, 2) == 0;
+        let graph = if acyclic {
+            random_dag(&mut rng, n, m, |_| ())
+        } else {
+            random_graph(&mut rng, n, m, |_| (), |_| ())
+        };
+        let index = reachability_index(&graph);
+        for a in graph.node_indices() {
+            for b in graph.node_indices() {
+                assert_eq!(
+                    index.reaches(a, b),
+                    has_path_connecting(&graph, a, b, None),
+                    "{a:?} -> {b:?} in {graph:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn reachability_index_large_dag() {
+    // A layered DAG: every node depends on a few nodes of the previous layers.
+    let mut rng = seeded(49);
+    let (layers, width) = (100, 50);
+    let n = layers * width;
+    let mut graph = DiGraph::<(), ()>::with_capacity(n, 3 * n);
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for a in width..n {
+        for _ in 0..3 {
+            let b = rng.gen_range(0, a - a % width);
+            graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        }
+    }
+    let index = reachability_index(&graph);
+    // Far smaller than the transitive closure.
+    assert!(index.label_count() < n * n / 20);
+
+    let matrix = reachability_matrix(&graph);
+    for _ in 0..20_000 {
+        let a = NodeIndex::new(rng.gen_range(0, n));
+        let b = NodeIndex::new(rng.gen_range(0, n));
+        assert_eq!(index.reaches(a, b), matrix.reachable(a, b));
+    }
+
+    // A long chain.
+    let chain = DiGraph::<(), ()>::from_edges((1..20_000u32).map(|i| (i - 1, i)));
+    let index = reachability_index(&chain);
+    assert!(index.reaches(NodeIndex::new(0), NodeIndex::new(19_999)));
+    assert!(!index.reaches(NodeIndex::new(19_999), NodeIndex::new(0)));
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn reachability_index_stable_graph() {
+    use petgraph::stable_graph::StableDiGraph;
+
+    let mut graph = StableDiGraph::<(), ()>::new();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    for i in 1..5 {
+        graph.add_edge(nodes[i - 1], nodes[i], ());
+    }
+    graph.add_edge(nodes[4], nodes[3], ());
+    graph.remove_node(nodes[2]);
+
+    let index = reachability_index(&graph);
+    assert!(index.reaches(nodes[0], nodes[1]));
+    assert!(!index.reaches(nodes[0], nodes[3]));
+    assert!(index.reaches(nodes[4], nodes[3]));
+    assert!(index.reaches(nodes[3], nodes[4]));
+    assert!(index.reaches(nodes[2], nodes[2]));
+}
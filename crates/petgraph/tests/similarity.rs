@@ -0,0 +1,350 @@
+use hashbrown::HashSet;
+use petgraph::{
+    Directed, EdgeType, Graph, Undirected,
+    algo::{approximate_graph_edit_distance, graph_edit_distance, maximum_common_induced_subgraph},
+    graph::{NodeIndex, UnGraph},
+    visit::EdgeRef,
+};
+use rand::{ChaChaRng, Rng};
+use utils::{random_graph, remove_parallel_edges, seeded};
+
+mod utils;
+
+/// Node colors from 0 to 2.
+fn node_color(rng: &mut ChaChaRng) -> u8 {
+    rng.gen_range(0, 3)
+}
+
+/// Edge colors 0 and 1.
+fn edge_color(rng: &mut ChaChaRng) -> u8 {
+    rng.gen_range(0, 2)
+}
+
+/// Every partial injective mapping of the nodes of `g0` to the nodes of `g1`.
+fn mappings(n0: usize, n1: usize) -> Vec<Vec<Option<usize>>> {
+    fn extend(
+        mapping: &mut Vec<Option<usize>>,
+        n0: usize,
+        n1: usize,
+        out: &mut Vec<Vec<Option<usize>>>,
+    ) {
+        if mapping.len() == n0 {
+            out.push(mapping.clone());
+            return;
+        }
+        for b in (0..n1).map(Some).chain([None]) {
+            if b.is_none() || !mapping.contains(&b) {
+                mapping.push(b);
+                extend(mapping, n0, n1, out);
+                mapping.pop();
+            }
+        }
+    }
+    let mut out = Vec::new();
+    extend(&mut Vec::new(), n0, n1, &mut out);
+    out
+}
+
+fn node_cost(a: Option<u8>, b: Option<u8>) -> u32 {
+    match (a, b) {
+        (Some(a), Some(b)) => u32::from(a.abs_diff(b)),
+        _ => 2,
+    }
+}
+
+fn edge_cost(a: Option<u8>, b: Option<u8>) -> u32 {
+    match (a, b) {
+        (Some(a), Some(b)) => u32::from(a != b),
+        _ => 1,
+    }
+}
+
+/// The cost of the edit path of a mapping, computed pair by pair.
+fn mapping_cost<Ty: EdgeType>(
+    g0: &Graph<u8, u8, Ty>,
+    g1: &Graph<u8, u8, Ty>,
+    mapping: &[Option<usize>],
+) -> u32 {
+    let weight1 = |a: usize, b: usize| {
+        g1.find_edge(NodeIndex::new(a), NodeIndex::new(b))
+            .map(|e| g1[e])
+    };
+    let mut cost = 0;
+    for (a, &b) in mapping.iter().enumerate() {
+        cost += node_cost(
+            Some(g0[NodeIndex::new(a)]),
+            b.map(|b| g1[NodeIndex::new(b)]),
+        );
+    }
+    for b in g1.node_indices() {
+        if !mapping.contains(&Some(b.index())) {
+            cost += node_cost(None, Some(g1[b]));
+        }
+    }
+    for edge in g0.edge_references() {
+        let image = match (
+            mapping[edge.source().index()],
+            mapping[edge.target().index()],
+        ) {
+            (Some(b), Some(d)) => weight1(b, d),
+            _ => None,
+        };
+        cost += edge_cost(Some(*edge.weight()), image);
+    }
+    for edge in g1.edge_references() {
+        let preimage = |b: NodeIndex| mapping.iter().position(|&x| x == Some(b.index()));
+        let joined = match (preimage(edge.source()), preimage(edge.target())) {
+            (Some(a), Some(c)) => g0.find_edge(NodeIndex::new(a), NodeIndex::new(c)).is_some(),
+            _ => false,
+        };
+        if !joined {
+            cost += edge_cost(None, Some(*edge.weight()));
+        }
+    }
+    cost
+}
+
+/// Substitutions are free, deletions and insertions cost 1.
+fn unit_cost<A, B>(a: Option<A>, b: Option<B>) -> u32 {
+    u32::from(a.is_none() || b.is_none())
+}
+
+fn check_edit_distance<Ty: EdgeType>(g0: &Graph<u8, u8, Ty>, g1: &Graph<u8, u8, Ty>) {
+    let costs = mappings(g0.node_count(), g1.node_count())
+        .into_iter()
+        .map(|mapping| mapping_cost(g0, g1, &mapping));
+    let expected = costs.min().unwrap();
+
+    let node =
+        |a: Option<NodeIndex>, b: Option<NodeIndex>| node_cost(a.map(|a| g0[a]), b.map(|b| g1[b]));
+    let edge = |a: Option<_>, b: Option<_>| {
+        edge_cost(
+            a.map(|a: petgraph::graph::EdgeReference<u8>| *a.weight()),
+            b.map(|b: petgraph::graph::EdgeReference<u8>| *b.weight()),
+        )
+    };
+    let exact = graph_edit_distance(g0, g1, node, edge);
+    let approximate = approximate_graph_edit_distance(g0, g1, node, edge);
+    assert_eq!(exact.cost, expected);
+    assert!(approximate.cost >= expected);
+
+    for path in [exact, approximate] {
+        // Every node and every edge is edited exactly once.
+        let mut mapping = vec![None; g0.node_count()];
+        let mut nodes1 = HashSet::new();
+        for &(a, b) in &path.nodes {
+            assert!(a.is_some() || b.is_some());
+            if let Some(a) = a {
+                assert_eq!(mapping[a.index()], None);
+                mapping[a.index()] = Some(b);
+            }
+            if let Some(b) = b {
+                assert!(nodes1.insert(b));
+            }
+        }
+        assert_eq!(nodes1.len(), g1.node_count());
+        let edges0: HashSet<_> = path.edges.iter().filter_map(|&(a, _)| a).collect();
+        let edges1: HashSet<_> = path.edges.iter().filter_map(|&(_, b)| b).collect();
+        assert_eq!(edges0.len(), g0.edge_count());
+        assert_eq!(edges1.len(), g1.edge_count());
+
+        let mapping: Vec<_> = mapping
+            .into_iter()
+            .map(|b| b.unwrap().map(|b| b.index()))
+            .collect();
+        assert_eq!(path.cost, mapping_cost(g0, g1, &mapping));
+    }
+}
+
+#[test]
+fn edit_distance_brute_force() {
+    let mut rng = seeded(45);
+    for _ in 0..150 {
+        let (n0, n1) = (rng.gen_range(0, 5), rng.gen_range(0, 5));
+        let mut g0 =
+            random_graph::<_, _, Directed>(&mut rng, n0.max(1), 2 * n0, node_color, edge_color);
+        remove_parallel_edges(&mut g0);
+        let mut g1 =
+            random_graph::<_, _, Directed>(&mut rng, n1.max(1), 2 * n1, node_color, edge_color);
+        remove_parallel_edges(&mut g1);
+        check_edit_distance(&g0, &g1);
+
+        let mut g0 =
+            random_graph::<_, _, Undirected>(&mut rng, n0.max(1), 2 * n0, node_color, edge_color);
+        remove_parallel_edges(&mut g0);
+        let mut g1 =
+            random_graph::<_, _, Undirected>(&mut rng, n1.max(1), 2 * n1, node_color, edge_color);
+        remove_parallel_edges(&mut g1);
+        check_edit_distance(&g0, &g1);
+    }
+}
+
+#[test]
+fn edit_distance_empty() {
+    let empty = UnGraph::<(), ()>::default();
+    let triangle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+    let path = graph_edit_distance(&empty, &triangle, unit_cost, unit_cost);
+    assert_eq!(path.cost, 6);
+    assert!(path.nodes.iter().all(|(a, b)| a.is_none() && b.is_some()));
+    let path = graph_edit_distance(&triangle, &empty, unit_cost, unit_cost);
+    assert_eq!(path.cost, 6);
+    let path = approximate_graph_edit_distance(&triangle, &triangle, unit_cost, unit_cost);
+    assert_eq!(path.cost, 0);
+}
+
+#[test]
+fn edit_distance_float_costs() {
+    // Renaming a step is cheaper than replacing it.
+    let v1 = Graph::<(), ()>::from_edges([(0, 1), (1, 2)])
+        .map(|i, _| ["fetch", "build", "deploy"][i.index()], |_, _| ());
+    let v2 = Graph::<(), ()>::from_edges([(0, 1), (1, 2)])
+        .map(|i, _| ["fetch", "compile", "deploy"][i.index()], |_, _| ());
+    let node = |a: Option<NodeIndex>, b: Option<NodeIndex>| match (a, b) {
+        (Some(a), Some(b)) if v1[a] == v2[b] => 0.0,
+        (Some(_), Some(_)) => 0.5,
+        _ => 1.0,
+    };
+    let edge = |a: Option<_>, b: Option<_>| if a.is_some() && b.is_some() { 0.0 } else { 1.0 };
+    let path = graph_edit_distance(&v1, &v2, node, edge);
+    assert_eq!(path.cost, 0.5);
+    assert!(path.nodes.iter().all(|(a, b)| a == b));
+    assert!(path.edges.iter().all(|(a, b)| a == b));
+}
+
+/// Whether a partial mapping pairs matching nodes and preserves the edges between them.
+fn is_common_subgraph<Ty: EdgeType>(
+    g0: &Graph<u8, u8, Ty>,
+    g1: &Graph<u8, u8, Ty>,
+    pairs: &[(usize, usize)],
+) -> bool {
+    let edge = |g: &Graph<u8, u8, Ty>, a: usize, b: usize| {
+        g.find_edge(NodeIndex::new(a), NodeIndex::new(b))
+            .map(|e| g[e])
+    };
+    pairs.iter().all(|&(a, b)| {
+        g0[NodeIndex::new(a)] == g1[NodeIndex::new(b)]
+            && pairs.iter().all(|&(c, d)| edge(g0, a, c) == edge(g1, b, d))
+    })
+}
+
+fn check_common_subgraph<Ty: EdgeType>(g0: &Graph<u8, u8, Ty>, g1: &Graph<u8, u8, Ty>) {
+    let expected = mappings(g0.node_count(), g1.node_count())
+        .into_iter()
+        .map(|mapping| {
+            let pairs: Vec<_> = mapping
+                .iter()
+                .enumerate()
+                .filter_map(|(a, &b)| Some((a, b?)))
+                .collect();
+            pairs
+        })
+        .filter(|pairs| is_common_subgraph(g0, g1, pairs))
+        .map(|pairs| pairs.len())
+        .max()
+        .unwrap();
+
+    let common = maximum_common_induced_subgraph(
+        g0,
+        g1,
+        |a, b| g0[a] == g1[b],
+        |a, b| a.weight() == b.weight(),
+    );
+    let pairs: Vec<_> = common
+        .iter()
+        .map(|&(a, b)| (a.index(), b.index()))
+        .collect();
+    assert_eq!(pairs.len(), expected);
+    assert!(is_common_subgraph(g0, g1, &pairs));
+    assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn common_subgraph_brute_force() {
+    let mut rng = seeded(46);
+    for _ in 0..150 {
+        let (n0, n1) = (rng.gen_range(1, 6), rng.gen_range(1, 6));
+        let mut g0 = random_graph::<_, _, Directed>(&mut rng, n0, 2 * n0, node_color, edge_color);
+        remove_parallel_edges(&mut g0);
+        let mut g1 = random_graph::<_, _, Directed>(&mut rng, n1, 2 * n1, node_color, edge_color);
+        remove_parallel_edges(&mut g1);
+        check_common_subgraph(&g0, &g1);
+
+        let mut g0 = random_graph::<_, _, Undirected>(&mut rng, n0, 2 * n0, node_color, edge_color);
+        remove_parallel_edges(&mut g0);
+        let mut g1 = random_graph::<_, _, Undirected>(&mut rng, n1, 2 * n1, node_color, edge_color);
+        remove_parallel_edges(&mut g1);
+        check_common_subgraph(&g0, &g1);
+    }
+}
+
+#[test]
+fn common_subgraph_parallel_edges() {
+    // Parallel edges must match one to one: {1, 1} does not match {1, 2}.
+    let g0 = Graph::<(), u8>::from_edges([(0, 1, 1), (0, 1, 1)]);
+    let g1 = Graph::<(), u8>::from_edges([(0, 1, 1), (0, 1, 2)]);
+    let g2 = Graph::<(), u8>::from_edges([(0, 1, 2), (0, 1, 1)]);
+    let edge_match = |a: petgraph::graph::EdgeReference<u8>,
+                      b: petgraph::graph::EdgeReference<u8>| {
+        a.weight() == b.weight()
+    };
+    let common = maximum_common_induced_subgraph(&g0, &g1, |_, _| true, edge_match);
+    assert_eq!(common.len(), 1);
+    let common = maximum_common_induced_subgraph(&g1, &g2, |_, _| true, edge_match);
+    assert_eq!(common, [(0.into(), 0.into()), (1.into(), 1.into())]);
+}
+
+#[test]
+fn common_subgraph_of_larger_graphs() {
+    // A ring of 12 nodes and the same ring with a chord: all but one end of the chord match.
+    let ring: Vec<_> = (0..12).map(|i| (i, (i + 1) % 12)).collect();
+    let g0 = UnGraph::<(), ()>::from_edges(&ring);
+    let mut g1 = g0.clone();
+    g1.add_edge(NodeIndex::new(0), NodeIndex::new(6), ());
+    let common = maximum_common_induced_subgraph(&g0, &g1, |_, _| true, |_, _| true);
+    assert_eq!(common.len(), 11);
+
+    // The Petersen graph with itself.
+    let petersen = UnGraph::<(), ()>::from_edges([
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (0, 5),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 7),
+        (7, 9),
+        (9, 6),
+        (6, 8),
+        (8, 5),
+    ]);
+    let common = maximum_common_induced_subgraph(&petersen, &petersen, |_, _| true, |_, _| true);
+    assert_eq!(common.len(), 10);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn similarity_stable_graph() {
+    use petgraph::stable_graph::StableGraph;
+
+    let mut g0 = StableGraph::<u8, u8>::new();
+    let a: Vec<_> = (0..4).map(|i| g0.add_node(i)).collect();
+    g0.add_edge(a[0], a[1], 0);
+    g0.add_edge(a[1], a[2], 0);
+    g0.add_edge(a[2], a[3], 0);
+    g0.remove_node(a[1]);
+
+    let g1 = Graph::<u8, u8>::from_edges([(0, 1, 0)]).map(|i, _| [2, 3][i.index()], |_, &w| w);
+    let common = maximum_common_induced_subgraph(&g0, &g1, |x, y| g0[x] == g1[y], |_, _| true);
+    assert_eq!(
+        common,
+        vec![(a[2], NodeIndex::new(0)), (a[3], NodeIndex::new(1))]
+    );
+
+    let path = graph_edit_distance(&g0, &g1, unit_cost, unit_cost);
+    assert_eq!(path.cost, 1);
+    assert_eq!(path.nodes.len(), 3);
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use petgraph::{
+    Graph,
+    algo::{
+        connected_components, random_spanning_tree, spanning_tree_count, spanning_tree_count_float,
+    },
+    graph::{EdgeIndex, UnGraph},
+    visit::{EdgeFiltered, EdgeRef},
+};
+
+fn complete_graph(n: usize) -> UnGraph<(), ()> {
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            graph.add_edge(a, b, ());
+        }
+    }
+    graph
+}
+
+fn cycle_graph(n: u32) -> UnGraph<(), ()> {
+    UnGraph::from_edges((0..n).map(|i| (i, (i + 1) % n)))
+}
+
+#[test]
+fn count_trivial_graphs() {
+    let graph = UnGraph::<(), ()>::default();
+    assert_eq!(spanning_tree_count(&graph), Some(0));
+    assert_eq!(spanning_tree_count_float::<_, f64>(&graph), 0.);
+
+    let mut graph = UnGraph::<(), ()>::default();
+    graph.add_node(());
+    assert_eq!(spanning_tree_count(&graph), Some(1));
+    assert_eq!(spanning_tree_count_float::<_, f64>(&graph), 1.);
+
+    graph.add_node(());
+    assert_eq!(spanning_tree_count(&graph), Some(0));
+    assert_eq!(spanning_tree_count_float::<_, f64>(&graph), 0.);
+}
+
+#[test]
+fn count_cayley() {
+    for n in 2..12 {
+        let graph = complete_graph(n);
+        let expected = (n as u128).pow(n as u32 - 2);
+        assert_eq!(spanning_tree_count(&graph), Some(expected));
+        let approx: f64 = spanning_tree_count_float(&graph);
+        assert!((approx / expected as f64 - 1.).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn count_cycles_and_trees() {
+    for n in 3..20 {
+        assert_eq!(spanning_tree_count(&cycle_graph(n)), Some(n as u128));
+    }
+
+    let tree = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (2, 3), (2, 4), (4, 5)]);
+    assert_eq!(spanning_tree_count(&tree), Some(1));
+}
+
+#[test]
+fn count_multigraph_and_loops() {
+    // Two parallel edges and a self loop.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 1), (1, 1)]);
+    assert_eq!(spanning_tree_count(&graph), Some(2));
+
+    // The directions of the edges are ignored.
+    let graph = Graph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(spanning_tree_count(&graph), Some(3));
+}
+
+#[test]
+fn count_disconnected() {
+    let mut graph = cycle_graph(5);
+    graph.add_node(());
+    assert_eq!(spanning_tree_count(&graph), Some(0));
+    assert_eq!(spanning_tree_count_float::<_, f64>(&graph), 0.);
+}
+
+#[test]
+fn count_overflow() {
+    // 40^38 spanning trees do not fit in a `u128`.
+    let graph = complete_graph(40);
+    assert_eq!(spanning_tree_count(&graph), None);
+    let approx: f64 = spanning_tree_count_float(&graph);
+    assert!((approx / 40f64.powi(38) - 1.).abs() < 1e-9);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn count_stable_graph_with_holes() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes = (0..5).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            graph.add_edge(a, b, ());
+        }
+    }
+    graph.remove_node(nodes[2]);
+    assert_eq!(spanning_tree_count(&graph), Some(16));
+}
+
+fn assert_spanning_forest(graph: &UnGraph<(), ()>, tree: &[EdgeIndex]) {
+    let forest = EdgeFiltered::from_fn(graph, |edge| tree.contains(&edge.id()));
+    let components = connected_components(graph);
+    assert_eq!(tree.len(), graph.node_count() - components);
+    assert_eq!(connected_components(&forest), components);
+}
+
+#[test]
+fn random_tree_is_spanning() {
+    for seed in 0..20 {
+        let graph = complete_graph(10);
+        assert_spanning_forest(&graph, &random_spanning_tree(&graph, seed));
+
+        let graph = cycle_graph(10);
+        assert_spanning_forest(&graph, &random_spanning_tree(&graph, seed));
+    }
+}
+
+#[test]
+fn random_tree_of_forest() {
+    let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    graph.add_node(());
+    for seed in 0..20 {
+        assert_spanning_forest(&graph, &random_spanning_tree(&graph, seed));
+    }
+}
+
+#[test]
+fn random_tree_is_deterministic() {
+    let graph = complete_graph(20);
+    assert_eq!(
+        random_spanning_tree(&graph, 7),
+        random_spanning_tree(&graph, 7)
+    );
+}
+
+#[test]
+fn random_tree_is_uniform() {
+    // The complete graph on 4 nodes has 16 spanning trees.
+    let graph = complete_graph(4);
+    let samples = 16_000;
+    let mut counts = HashMap::new();
+    for seed in 0..samples {
+        let mut tree = random_spanning_tree(&graph, seed);
+        tree.sort();
+        *counts.entry(tree).or_insert(0) += 1;
+    }
+
+    assert_eq!(counts.len(), 16);
+    for &count in counts.values() {
+        assert!((800..1200).contains(&count), "{count}");
+    }
+}
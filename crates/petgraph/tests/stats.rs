@@ -0,0 +1,317 @@
+use petgraph::{
+    algo::{
+        DegreeKind,
+        stats::{
+            average_neighbor_degree, degree_assortativity, degree_histogram, density, reciprocity,
+        },
+    },
+    graph::{DiGraph, UnGraph},
+};
+
+/// A random directed graph with 12 nodes and 30 edges, without self loops or parallel edges.
+const DIGRAPH: [(u32, u32); 30] = [
+    (0, 1),
+    (0, 8),
+    (0, 9),
+    (1, 6),
+    (1, 3),
+    (1, 9),
+    (1, 8),
+    (2, 8),
+    (3, 0),
+    (3, 1),
+    (3, 5),
+    (4, 6),
+    (4, 8),
+    (5, 2),
+    (5, 9),
+    (5, 7),
+    (5, 4),
+    (6, 10),
+    (6, 1),
+    (6, 0),
+    (7, 10),
+    (8, 1),
+    (8, 6),
+    (8, 2),
+    (9, 0),
+    (9, 10),
+    (9, 3),
+    (9, 7),
+    (10, 2),
+    (11, 1),
+];
+
+fn assert_all_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!(
+            (a - e).abs() < 1e-12,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+}
+
+#[test]
+fn degree_histogram_directed() {
+    let graph = DiGraph::<(), ()>::from_edges(DIGRAPH);
+    assert_eq!(
+        degree_histogram(&graph, DegreeKind::In),
+        vec![1, 2, 2, 5, 1, 1]
+    );
+    assert_eq!(
+        degree_histogram(&graph, DegreeKind::Out),
+        vec![0, 4, 1, 4, 3]
+    );
+    assert_eq!(
+        degree_histogram(&graph, DegreeKind::Total),
+        vec![0, 1, 0, 2, 2, 2, 2, 2, 0, 1]
+    );
+
+    let graph = DiGraph::<(), ()>::default();
+    assert!(degree_histogram(&graph, DegreeKind::Total).is_empty());
+}
+
+#[test]
+fn density_and_reciprocity() {
+    let graph = DiGraph::<(), ()>::from_edges(DIGRAPH);
+    assert_eq!(density(&graph), 30. / 132.);
+    assert_eq!(reciprocity(&graph), Some(1. / 3.));
+
+    // A self loop is never reciprocated.
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (2, 2)]);
+    assert_eq!(reciprocity(&graph), Some(2. / 3.));
+
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    assert_eq!(reciprocity(&graph), Some(0.));
+
+    let mut graph = DiGraph::<(), ()>::default();
+    assert_eq!(reciprocity(&graph), None);
+    graph.add_node(());
+    assert_eq!(density(&graph), 0.);
+}
+
+#[test]
+fn degree_assortativity_directed() {
+    let graph = DiGraph::<(), ()>::from_edges(DIGRAPH);
+    let r = degree_assortativity(&graph).unwrap();
+    assert!((r - -0.38462604191373545).abs() < 1e-12);
+
+    // In a directed cycle, every node has the same degrees.
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(degree_assortativity(&graph), None);
+    let graph = DiGraph::<(), ()>::default();
+    assert_eq!(degree_assortativity(&graph), None);
+}
+
+#[test]
+fn average_neighbor_degree_directed() {
+    let graph = DiGraph::<(), ()>::from_edges(DIGRAPH);
+    assert_all_close(
+        &average_neighbor_degree(&graph, DegreeKind::In),
+        &[
+            2.6666666666666665,
+            2.4,
+            2.6666666666666665,
+            4.0,
+            1.0,
+            2.0,
+            3.3333333333333335,
+            2.0,
+            3.0,
+            3.0,
+            2.6666666666666665,
+            0.0,
+        ],
+    );
+    assert_all_close(
+        &average_neighbor_degree(&graph, DegreeKind::Out),
+        &[
+            3.6666666666666665,
+            3.25,
+            3.0,
+            3.6666666666666665,
+            3.0,
+            2.0,
+            2.6666666666666665,
+            1.0,
+            2.6666666666666665,
+            2.0,
+            1.0,
+            4.0,
+        ],
+    );
+    assert_all_close(
+        &average_neighbor_degree(&graph, DegreeKind::Total),
+        &[
+            6.833333333333333,
+            5.555555555555555,
+            5.75,
+            7.2,
+            6.0,
+            4.4,
+            6.333333333333333,
+            5.333333333333333,
+            5.857142857142857,
+            5.428571428571429,
+            5.0,
+            9.0,
+        ],
+    );
+}
+
+#[test]
+fn stats_undirected() {
+    // Zachary's karate club.
+    let graph = UnGraph::<(), ()>::from_edges([
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (0, 4),
+        (0, 5),
+        (0, 6),
+        (0, 7),
+        (0, 8),
+        (0, 10),
+        (0, 11),
+        (0, 12),
+        (0, 13),
+        (0, 17),
+        (0, 19),
+        (0, 21),
+        (0, 31),
+        (1, 2),
+        (1, 3),
+        (1, 7),
+        (1, 13),
+        (1, 17),
+        (1, 19),
+        (1, 21),
+        (1, 30),
+        (2, 3),
+        (2, 7),
+        (2, 8),
+        (2, 9),
+        (2, 13),
+        (2, 27),
+        (2, 28),
+        (2, 32),
+        (3, 7),
+        (3, 12),
+        (3, 13),
+        (4, 6),
+        (4, 10),
+        (5, 6),
+        (5, 10),
+        (5, 16),
+        (6, 16),
+        (8, 30),
+        (8, 32),
+        (8, 33),
+        (9, 33),
+        (13, 33),
+        (14, 32),
+        (14, 33),
+        (15, 32),
+        (15, 33),
+        (18, 32),
+        (18, 33),
+        (19, 33),
+        (20, 32),
+        (20, 33),
+        (22, 32),
+        (22, 33),
+        (23, 25),
+        (23, 27),
+        (23, 29),
+        (23, 32),
+        (23, 33),
+        (24, 25),
+        (24, 27),
+        (24, 31),
+        (25, 31),
+        (26, 29),
+        (26, 33),
+        (27, 33),
+        (28, 31),
+        (28, 33),
+        (29, 32),
+        (29, 33),
+        (30, 32),
+        (30, 33),
+        (31, 32),
+        (31, 33),
+        (32, 33),
+    ]);
+    // The kind of degree does not matter.
+    for kind in [DegreeKind::In, DegreeKind::Out, DegreeKind::Total] {
+        assert_eq!(
+            degree_histogram(&graph, kind),
+            vec![0, 1, 11, 6, 6, 3, 2, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 1]
+        );
+    }
+    assert!((density(&graph) - 0.13903743315508021).abs() < 1e-12);
+    let r = degree_assortativity(&graph).unwrap();
+    assert!((r - -0.47561309768461446).abs() < 1e-12);
+    assert_eq!(reciprocity(&graph), Some(1.));
+    assert_all_close(
+        &average_neighbor_degree(&graph, DegreeKind::Out),
+        &[
+            4.3125,
+            5.777777777777778,
+            6.6,
+            7.666666666666667,
+            7.666666666666667,
+            6.25,
+            6.25,
+            10.25,
+            11.8,
+            13.5,
+            7.666666666666667,
+            16.0,
+            11.0,
+            11.6,
+            14.5,
+            14.5,
+            4.0,
+            12.5,
+            14.5,
+            14.0,
+            14.5,
+            12.5,
+            14.5,
+            8.0,
+            4.333333333333333,
+            4.666666666666667,
+            10.5,
+            8.75,
+            11.0,
+            9.0,
+            10.75,
+            9.0,
+            5.083333333333333,
+            3.823529411764706,
+        ],
+    );
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn stats_stable_graph() {
+    use petgraph::stable_graph::StableDiGraph;
+
+    // A path 0 -> 1 -> 2 -> 3 with a reverse edge, after removing node 1.
+    let mut graph = StableDiGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 3), (3, 2)] {
+        graph.add_edge(nodes[a], nodes[b], ());
+    }
+    graph.remove_node(nodes[1]);
+    assert_eq!(degree_histogram(&graph, DegreeKind::Total), vec![1, 0, 2]);
+    assert_eq!(density(&graph), 2. / 6.);
+    assert_eq!(reciprocity(&graph), Some(1.));
+    assert_eq!(
+        average_neighbor_degree(&graph, DegreeKind::Out),
+        vec![0., 0., 1., 1.]
+    );
+}
@@ -1,12 +1,8 @@
-#[cfg(feature = "stable_graph")]
-#[cfg(test)]
 use petgraph::{
     Graph, Undirected,
     graph::{NodeIndex, UnGraph},
 };
 
-#[cfg(feature = "stable_graph")]
-#[cfg(test)]
 fn b01_example() -> (UnGraph<(), i32>, Vec<NodeIndex>) {
     // Implementing b01 case from Vienna test set B
     let mut graph = Graph::<(), i32, Undirected>::new_undirected();
@@ -137,8 +133,6 @@ fn b01_example() -> (UnGraph<(), i32>, Vec<NodeIndex>) {
     (graph, terminals)
 }
 
-#[cfg(feature = "stable_graph")]
-#[cfg(test)]
 fn b07_example() -> (UnGraph<(), i32>, Vec<NodeIndex>) {
     // Implementing b07 case from Vienna test set B
     let mut graph = Graph::<(), i32, Undirected>::new_undirected();
@@ -328,8 +322,6 @@ fn b07_example() -> (UnGraph<(), i32>, Vec<NodeIndex>) {
     (graph, terminals)
 }
 
-#[cfg(feature = "stable_graph")]
-#[cfg(test)]
 fn example_kou_paper() -> (UnGraph<(), usize>, Vec<NodeIndex>) {
     let mut graph = Graph::<(), usize, Undirected>::new_undirected();
     // Add nodes
@@ -408,3 +400,107 @@ mod test {
         assert_eq!(weights, 8);
     }
 }
+
+mod generic {
+    use petgraph::{
+        algo::{connected_components, steiner_tree_kou, steiner_tree_mehlhorn},
+        graph::{EdgeIndex, NodeIndex, UnGraph},
+        visit::{EdgeFiltered, EdgeRef},
+    };
+
+    use crate::{b01_example, b07_example, example_kou_paper};
+
+    /// Check that `tree` is a tree spanning all `terminals` and return its weight.
+    fn assert_steiner_tree<E>(
+        graph: &UnGraph<(), E>,
+        terminals: &[NodeIndex],
+        tree: &[EdgeIndex],
+    ) -> E
+    where
+        E: Copy + core::iter::Sum,
+    {
+        let tree_graph = EdgeFiltered::from_fn(graph, |edge| tree.contains(&edge.id()));
+        let mut nodes = tree
+            .iter()
+            .flat_map(|&edge| {
+                let (a, b) = graph.edge_endpoints(edge).unwrap();
+                [a, b]
+            })
+            .collect::<Vec<_>>();
+        nodes.sort();
+        nodes.dedup();
+
+        assert!(terminals.iter().all(|t| nodes.contains(t)));
+        assert_eq!(tree.len(), nodes.len() - 1);
+        // Isolated nodes each count as one component.
+        assert_eq!(
+            connected_components(&tree_graph),
+            graph.node_count() - nodes.len() + 1
+        );
+        tree.iter().map(|&edge| graph[edge]).sum()
+    }
+
+    #[test]
+    fn b01_vienna_test() {
+        let (graph, terminals) = b01_example();
+
+        let kou = steiner_tree_kou(&graph, &terminals, |edge| *edge.weight());
+        assert_eq!(assert_steiner_tree(&graph, &terminals, &kou), 82);
+
+        let mehlhorn = steiner_tree_mehlhorn(&graph, &terminals, |edge| *edge.weight());
+        assert!(assert_steiner_tree(&graph, &terminals, &mehlhorn) <= 2 * 82);
+    }
+
+    #[test]
+    fn b07_vienna_test() {
+        let (graph, terminals) = b07_example();
+
+        let kou = steiner_tree_kou(&graph, &terminals, |edge| *edge.weight());
+        assert_eq!(assert_steiner_tree(&graph, &terminals, &kou), 111);
+
+        let mehlhorn = steiner_tree_mehlhorn(&graph, &terminals, |edge| *edge.weight());
+        assert!(assert_steiner_tree(&graph, &terminals, &mehlhorn) <= 2 * 111);
+    }
+
+    #[test]
+    fn example_kous_paper() {
+        let (graph, terminals) = example_kou_paper();
+
+        let kou = steiner_tree_kou(&graph, &terminals, |edge| *edge.weight());
+        assert_eq!(assert_steiner_tree(&graph, &terminals, &kou), 8);
+
+        let mehlhorn = steiner_tree_mehlhorn(&graph, &terminals, |edge| *edge.weight());
+        assert_eq!(assert_steiner_tree(&graph, &terminals, &mehlhorn), 8);
+    }
+
+    #[test]
+    fn trivial_terminal_sets() {
+        let (graph, terminals) = b01_example();
+
+        assert!(steiner_tree_kou(&graph, &[], |edge| *edge.weight()).is_empty());
+        assert!(steiner_tree_mehlhorn(&graph, &[], |edge| *edge.weight()).is_empty());
+        assert!(steiner_tree_kou(&graph, &terminals[..1], |edge| *edge.weight()).is_empty());
+        assert!(steiner_tree_mehlhorn(&graph, &terminals[..1], |edge| *edge.weight()).is_empty());
+    }
+
+    #[test]
+    fn disconnected_terminals() {
+        let mut graph = UnGraph::<(), u32>::new_undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        let d = graph.add_node(());
+        let e = graph.add_node(());
+        let f = graph.add_node(());
+        graph.extend_with_edges([(a, b, 1), (b, c, 1), (d, e, 2), (e, f, 3), (d, f, 4)]);
+
+        let terminals = [a, c, d, f];
+        for tree in [
+            steiner_tree_kou(&graph, &terminals, |edge| *edge.weight()),
+            steiner_tree_mehlhorn(&graph, &terminals, |edge| *edge.weight()),
+        ] {
+            assert_eq!(tree.len(), 3);
+            assert_eq!(tree.iter().map(|&edge| graph[edge]).sum::<u32>(), 6);
+        }
+    }
+}
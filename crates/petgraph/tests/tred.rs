@@ -0,0 +1,168 @@
+use hashbrown::HashSet;
+use petgraph::{
+    algo::{
+        has_path_connecting, reachability_matrix, toposort, transitive_closure,
+        transitive_reduction, transitive_reduction_edges,
+        tred::{dag_to_toposorted_adjacency_list, dag_transitive_reduction_closure},
+    },
+    graph::DiGraph,
+    visit::{EdgeFiltered, EdgeRef},
+};
+use rand::Rng;
+use utils::{random_dag, random_graph, remove_parallel_edges, seeded};
+
+mod utils;
+
+/// The pairs of nodes joined by a path of at least one edge.
+fn closure_pairs(graph: &DiGraph<(), ()>) -> HashSet<(usize, usize)> {
+    let mut pairs = HashSet::new();
+    for a in graph.node_indices() {
+        for s in graph.neighbors(a) {
+            for b in graph.node_indices() {
+                if has_path_connecting(graph, s, b, None) {
+                    pairs.insert((a.index(), b.index()));
+                }
+            }
+        }
+    }
+    pairs
+}
+
+fn graph_closure<N>(graph: &DiGraph<N, ()>) -> HashSet<(usize, usize)> {
+    transitive_closure(graph)
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index()))
+        .collect()
+}
+
+#[test]
+fn reachability_matrix_random() {
+    let mut rng = seeded(47);
+    for _ in 0..100 {
+        let n = rng.gen_range(1, 15);
+        let m = rng.gen_range(0, 2 * n);
+        let graph = random_graph(&mut rng, n, m, |_| (), |_| ());
+        let matrix = reachability_matrix(&graph);
+        let expected = closure_pairs(&graph);
+        for a in graph.node_indices() {
+            for b in graph.node_indices() {
+                assert_eq!(
+                    matrix.reachable(a, b),
+                    has_path_connecting(&graph, a, b, None)
+                );
+            }
+            let from_a: HashSet<_> = matrix
+                .reachable_from(a)
+                .map(|b| (a.index(), b.index()))
+                .collect();
+            let expected_from_a: HashSet<_> = expected
+                .iter()
+                .filter(|&&(x, _)| x == a.index())
+                .copied()
+                .collect();
+            assert_eq!(from_a, expected_from_a);
+        }
+        assert_eq!(graph_closure(&graph), expected);
+    }
+}
+
+#[test]
+fn transitive_reduction_random() {
+    let mut rng = seeded(48);
+    for _ in 0..100 {
+        let n = rng.gen_range(1, 15);
+        let m = rng.gen_range(0, 3 * n);
+        let acyclic = rng.gen_range(0, 2) == 0;
+        let graph = if acyclic {
+            let mut graph = random_dag(&mut rng, n, m, |_| ());
+            remove_parallel_edges(&mut graph);
+            graph
+        } else {
+            random_graph(&mut rng, n, m, |_| (), |_| ())
+        };
+        let expected = closure_pairs(&graph);
+
+        let reduction = transitive_reduction(&graph);
+        assert!(reduction.node_indices().all(|i| reduction[i] == i));
+        assert_eq!(graph_closure(&reduction), expected);
+        let (keep, remove) = transitive_reduction_edges(&graph);
+        assert_eq!(keep.len() + remove.len(), graph.edge_count());
+        let kept: HashSet<_> = keep.iter().copied().collect();
+        let filtered = EdgeFiltered::from_fn(&graph, |e| kept.contains(&e.id()));
+        let matrix = reachability_matrix(&filtered);
+        for a in graph.node_indices() {
+            for b in graph.node_indices() {
+                assert_eq!(
+                    matrix.reachable(a, b),
+                    has_path_connecting(&graph, a, b, None)
+                );
+            }
+        }
+        // Removing any edge of the reduction changes the closure.
+        for e in reduction.edge_indices() {
+            let mut smaller = reduction.clone();
+            smaller.remove_edge(e);
+            assert_ne!(graph_closure(&smaller), expected);
+        }
+
+        if acyclic {
+            assert_eq!(reduction.edge_count(), keep.len());
+            let order = toposort(&graph, None).unwrap();
+            let (list, _) = dag_to_toposorted_adjacency_list::<_, u32>(&graph, &order);
+            let (tred, _) = dag_transitive_reduction_closure(&list);
+            assert_eq!(tred.edge_count(), keep.len());
+        } else {
+            // The kept edges of a component are at most twice its size.
+            assert!(keep.len() <= 2 * reduction.edge_count());
+        }
+    }
+}
+
+#[test]
+fn transitive_reduction_self_loops() {
+    let graph = DiGraph::<(), ()>::from_edges([(0, 0), (0, 0), (0, 1), (1, 2), (2, 1), (1, 1)]);
+    let reduction = transitive_reduction(&graph);
+    let edges: HashSet<_> = reduction
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index()))
+        .collect();
+    let expected: HashSet<_> = [(0, 0), (0, 1), (1, 2), (2, 1)].into_iter().collect();
+    assert_eq!(edges, expected);
+    let (keep, remove) = transitive_reduction_edges(&graph);
+    assert_eq!(keep.len(), 4);
+    assert_eq!(remove.len(), 2);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn transitive_closure_stable_graph() {
+    use petgraph::{
+        stable_graph::StableDiGraph,
+        visit::{IntoNodeIdentifiers, NodeIndexable},
+    };
+
+    let mut graph = StableDiGraph::<char, ()>::new();
+    let nodes: Vec<_> = "abcde".chars().map(|c| graph.add_node(c)).collect();
+    for i in 1..5 {
+        graph.add_edge(nodes[i - 1], nodes[i], ());
+    }
+    graph.add_edge(nodes[0], nodes[4], ());
+    graph.remove_node(nodes[2]);
+
+    let closure = transitive_closure(&graph);
+    assert_eq!(closure.node_count(), 4);
+    let pairs: HashSet<_> = closure
+        .edge_references()
+        .map(|e| (graph[closure[e.source()]], graph[closure[e.target()]]))
+        .collect();
+    let expected: HashSet<_> = [('a', 'b'), ('a', 'e'), ('d', 'e')].into_iter().collect();
+    assert_eq!(pairs, expected);
+
+    let matrix = reachability_matrix(&graph);
+    assert!(matrix.reachable(nodes[0], nodes[4]));
+    assert!(!matrix.reachable(nodes[1], nodes[4]));
+    assert_eq!(matrix.component_count(), graph.node_identifiers().count());
+    assert!(graph.node_bound() > closure.node_count());
+    let (keep, remove) = transitive_reduction_edges(&graph);
+    assert_eq!((keep.len(), remove.len()), (3, 0));
+}
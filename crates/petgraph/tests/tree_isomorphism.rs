@@ -0,0 +1,205 @@
+use hashbrown::HashSet;
+use petgraph::{
+    algo::{
+        is_isomorphic, is_isomorphic_rooted_trees, is_isomorphic_trees, rooted_tree_encoding,
+        tree_encoding,
+    },
+    graph::{DiGraph, NodeIndex, UnGraph},
+};
+use rand::Rng;
+use utils::{random_tree, seeded};
+
+mod utils;
+
+/// The tree of a Prüfer sequence, on `sequence.len() + 2` nodes.
+fn prufer_tree(sequence: &[usize]) -> UnGraph<(), ()> {
+    let n = sequence.len() + 2;
+    let mut degrees = vec![1; n];
+    for &a in sequence {
+        degrees[a] += 1;
+    }
+    let mut graph = UnGraph::with_capacity(n, n - 1);
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for &a in sequence {
+        let leaf = (0..n).find(|&b| degrees[b] == 1).unwrap();
+        graph.add_edge(NodeIndex::new(leaf), NodeIndex::new(a), ());
+        degrees[leaf] -= 1;
+        degrees[a] -= 1;
+    }
+    let ends: Vec<_> = (0..n).filter(|&b| degrees[b] == 1).collect();
+    graph.add_edge(NodeIndex::new(ends[0]), NodeIndex::new(ends[1]), ());
+    graph
+}
+
+/// Every labeled tree on `n` nodes.
+fn all_trees(n: usize) -> Vec<UnGraph<(), ()>> {
+    if n == 1 {
+        let mut graph = UnGraph::default();
+        graph.add_node(());
+        return vec![graph];
+    }
+    let mut trees = Vec::new();
+    let mut sequence = vec![0; n - 2];
+    loop {
+        trees.push(prufer_tree(&sequence));
+        let Some(i) = sequence.iter().rposition(|&a| a + 1 < n) else {
+            return trees;
+        };
+        sequence[i] += 1;
+        for a in &mut sequence[i + 1..] {
+            *a = 0;
+        }
+    }
+}
+
+#[test]
+fn tree_encoding_counts() {
+    // The numbers of unrooted and rooted trees, OEIS A000055 and A000081.
+    let unrooted = [1, 1, 1, 2, 3, 6, 11];
+    let rooted = [1, 1, 2, 4, 9, 20, 48];
+    for n in 1..=7 {
+        let trees = all_trees(n);
+        let encodings: HashSet<_> = trees.iter().map(|t| tree_encoding(t).unwrap()).collect();
+        assert_eq!(encodings.len(), unrooted[n - 1]);
+        let encodings: HashSet<_> = trees
+            .iter()
+            .map(|t| rooted_tree_encoding(t, NodeIndex::new(0)).unwrap())
+            .collect();
+        assert_eq!(encodings.len(), rooted[n - 1]);
+    }
+}
+
+#[test]
+fn tree_isomorphism_compare_with_vf2() {
+    let mut rng = seeded(46);
+    for _ in 0..500 {
+        let n = rng.gen_range(1, 10);
+        let t0 = random_tree(&mut rng, n);
+        let t1 = random_tree(&mut rng, n);
+        assert_eq!(is_isomorphic_trees(&t0, &t1), is_isomorphic(&t0, &t1));
+
+        // A relabeled copy.
+        let mut permutation: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            permutation.swap(i, rng.gen_range(0, i + 1));
+        }
+        let mut relabeled = UnGraph::<(), ()>::with_capacity(n, n - 1);
+        for _ in 0..n {
+            relabeled.add_node(());
+        }
+        for e in t0.edge_indices() {
+            let (a, b) = t0.edge_endpoints(e).unwrap();
+            relabeled.add_edge(
+                NodeIndex::new(permutation[a.index()]),
+                NodeIndex::new(permutation[b.index()]),
+                (),
+            );
+        }
+        assert_eq!(tree_encoding(&t0), tree_encoding(&relabeled));
+        let (r0, r1) = (NodeIndex::new(0), NodeIndex::new(permutation[0]));
+        assert!(is_isomorphic_rooted_trees(&t0, r0, &relabeled, r1));
+    }
+}
+
+#[test]
+fn tree_encoding_not_a_tree() {
+    let empty = UnGraph::<(), ()>::default();
+    assert_eq!(tree_encoding(&empty), None);
+
+    let cycle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+    assert_eq!(tree_encoding(&cycle), None);
+    assert_eq!(rooted_tree_encoding(&cycle, NodeIndex::new(0)), None);
+
+    // As many edges as a tree, but a triangle and an isolated node.
+    let mut disconnected = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+    disconnected.add_node(());
+    assert_eq!(tree_encoding(&disconnected), None);
+    assert!(!is_isomorphic_trees(&disconnected, &disconnected));
+
+    let self_loop = UnGraph::<(), ()>::from_edges([(0, 1), (1, 1)]);
+    assert_eq!(rooted_tree_encoding(&self_loop, NodeIndex::new(0)), None);
+    let parallel = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 2)]);
+    assert_eq!(rooted_tree_encoding(&parallel, NodeIndex::new(0)), None);
+
+    // Only the nodes reachable from the root are part of a rooted tree.
+    let forest = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+    assert_eq!(tree_encoding(&forest), None);
+    assert_eq!(
+        rooted_tree_encoding(&forest, NodeIndex::new(0)).unwrap(),
+        "(())"
+    );
+}
+
+#[test]
+fn rooted_tree_encoding_directed() {
+    // A parse tree with edges from parents to children.
+    let directed = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (2, 3), (2, 4)]);
+    let undirected = UnGraph::<(), ()>::from_edges([(3, 2), (2, 4), (0, 2), (1, 0)]);
+    let encoding = rooted_tree_encoding(&directed, NodeIndex::new(0)).unwrap();
+    assert_eq!(encoding, "(()(()()))");
+    assert_eq!(
+        rooted_tree_encoding(&undirected, NodeIndex::new(0)),
+        Some(encoding)
+    );
+    assert!(!is_isomorphic_rooted_trees(
+        &directed,
+        NodeIndex::new(2),
+        &undirected,
+        NodeIndex::new(0)
+    ));
+
+    // The edge back to the root is a cycle, not the edge from the parent.
+    let cycle = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0)]);
+    assert_eq!(rooted_tree_encoding(&cycle, NodeIndex::new(0)), None);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn tree_encoding_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    for i in 1..6 {
+        graph.add_edge(nodes[i - 1], nodes[i], ());
+    }
+    graph.remove_node(nodes[0]);
+    let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+    assert_eq!(tree_encoding(&graph), tree_encoding(&path));
+    assert!(is_isomorphic_trees(&graph, &path));
+}
+
+#[test]
+fn tree_encoding_large() {
+    // A long path, and a caterpillar whose legs have different lengths.
+    let n = 100_000;
+    let path = UnGraph::<(), ()>::from_edges((1..n).map(|i| (i - 1, i)));
+    let encoding = tree_encoding(&path).unwrap();
+    assert_eq!(encoding.len(), 2 * n as usize);
+
+    let mut caterpillar = UnGraph::<(), ()>::from_edges((1..1000).map(|i| (i - 1, i)));
+    for i in 0..1000 {
+        let mut previous = NodeIndex::new(i);
+        for _ in 0..i % 7 {
+            let leg = caterpillar.add_node(());
+            caterpillar.add_edge(previous, leg, ());
+            previous = leg;
+        }
+    }
+    let mut reversed = UnGraph::<(), ()>::with_capacity(0, 0);
+    for _ in caterpillar.node_indices() {
+        reversed.add_node(());
+    }
+    let last = caterpillar.node_count() - 1;
+    for e in caterpillar.edge_indices().rev() {
+        let (a, b) = caterpillar.edge_endpoints(e).unwrap();
+        reversed.add_edge(
+            NodeIndex::new(last - a.index()),
+            NodeIndex::new(last - b.index()),
+            (),
+        );
+    }
+    assert!(is_isomorphic_trees(&caterpillar, &reversed));
+}
@@ -0,0 +1,239 @@
+use petgraph::{
+    EdgeType, Graph, Undirected,
+    algo::{average_clustering, clustering, csr_triangles, transitivity, triangles},
+    csr::Csr,
+    graph::{NodeIndex, UnGraph},
+};
+use utils::{random_graph, seeded};
+
+mod utils;
+
+const KARATE_CLUB: [(u32, u32); 78] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (0, 5),
+    (0, 6),
+    (0, 7),
+    (0, 8),
+    (0, 10),
+    (0, 11),
+    (0, 12),
+    (0, 13),
+    (0, 17),
+    (0, 19),
+    (0, 21),
+    (0, 31),
+    (1, 2),
+    (1, 3),
+    (1, 7),
+    (1, 13),
+    (1, 17),
+    (1, 19),
+    (1, 21),
+    (1, 30),
+    (2, 3),
+    (2, 7),
+    (2, 8),
+    (2, 9),
+    (2, 13),
+    (2, 27),
+    (2, 28),
+    (2, 32),
+    (3, 7),
+    (3, 12),
+    (3, 13),
+    (4, 6),
+    (4, 10),
+    (5, 6),
+    (5, 10),
+    (5, 16),
+    (6, 16),
+    (8, 30),
+    (8, 32),
+    (8, 33),
+    (9, 33),
+    (13, 33),
+    (14, 32),
+    (14, 33),
+    (15, 32),
+    (15, 33),
+    (18, 32),
+    (18, 33),
+    (19, 33),
+    (20, 32),
+    (20, 33),
+    (22, 32),
+    (22, 33),
+    (23, 25),
+    (23, 27),
+    (23, 29),
+    (23, 32),
+    (23, 33),
+    (24, 25),
+    (24, 27),
+    (24, 31),
+    (25, 31),
+    (26, 29),
+    (26, 33),
+    (27, 33),
+    (28, 31),
+    (28, 33),
+    (29, 32),
+    (29, 33),
+    (30, 32),
+    (30, 33),
+    (31, 32),
+    (31, 33),
+    (32, 33),
+];
+
+/// Triangles of every node by checking every triple of nodes.
+fn brute_force_triangles<Ty: EdgeType>(graph: &Graph<(), (), Ty>) -> Vec<usize> {
+    let n = graph.node_count();
+    let adjacent = |a: usize, b: usize| {
+        graph.contains_edge(NodeIndex::new(a), NodeIndex::new(b))
+            || graph.contains_edge(NodeIndex::new(b), NodeIndex::new(a))
+    };
+    let mut triangles = vec![0; n];
+    for a in 0..n {
+        for b in a + 1..n {
+            for c in b + 1..n {
+                if adjacent(a, b) && adjacent(b, c) && adjacent(a, c) {
+                    triangles[a] += 1;
+                    triangles[b] += 1;
+                    triangles[c] += 1;
+                }
+            }
+        }
+    }
+    triangles
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-12,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn triangles_karate_club() {
+    let graph = UnGraph::<(), ()>::from_edges(KARATE_CLUB);
+    assert_eq!(
+        triangles(&graph),
+        vec![
+            18, 12, 11, 10, 2, 3, 3, 6, 5, 0, 2, 0, 1, 6, 1, 1, 1, 1, 1, 1, 1, 1, 1, 4, 1, 1, 1, 1,
+            1, 4, 3, 3, 13, 15
+        ]
+    );
+
+    let mut csr = Csr::<(), (), Undirected>::with_nodes(34);
+    for (a, b) in KARATE_CLUB {
+        csr.add_edge(a, b, ());
+    }
+    assert_eq!(csr_triangles(&csr), triangles(&graph));
+}
+
+#[test]
+fn clustering_karate_club() {
+    let graph = UnGraph::<(), ()>::from_edges(KARATE_CLUB);
+    let expected = [
+        0.15,
+        0.3333333333333333,
+        0.24444444444444444,
+        0.6666666666666666,
+        0.6666666666666666,
+        0.5,
+        0.5,
+        1.0,
+        0.5,
+        0.,
+        0.6666666666666666,
+        0.,
+        1.0,
+        0.6,
+        1.0,
+        1.0,
+        1.0,
+        1.0,
+        1.0,
+        0.3333333333333333,
+        1.0,
+        1.0,
+        1.0,
+        0.4,
+        0.3333333333333333,
+        0.3333333333333333,
+        1.0,
+        0.16666666666666666,
+        0.3333333333333333,
+        0.6666666666666666,
+        0.5,
+        0.2,
+        0.19696969696969696,
+        0.11029411764705882,
+    ];
+    for (actual, expected) in clustering(&graph).into_iter().zip(expected) {
+        assert_close(actual, expected);
+    }
+    assert_close(average_clustering(&graph), 0.5706384782076823);
+    assert_close(transitivity(&graph), 0.2556818181818182);
+}
+
+#[test]
+fn triangles_compare_with_brute_force() {
+    for seed in 0..10 {
+        // Random multigraphs with self loops.
+        let graph = random_graph::<_, _, Undirected>(&mut seeded(seed), 30, 150, |_| (), |_| ());
+        assert_eq!(triangles(&graph), brute_force_triangles(&graph));
+
+        let mut csr = Csr::<(), (), Undirected>::with_nodes(30);
+        for edge in graph.raw_edges() {
+            csr.add_edge(
+                edge.source().index() as u32,
+                edge.target().index() as u32,
+                (),
+            );
+        }
+        assert_eq!(csr_triangles(&csr), brute_force_triangles(&graph));
+
+        let graph =
+            random_graph::<_, _, petgraph::Directed>(&mut seeded(seed), 30, 150, |_| (), |_| ());
+        assert_eq!(triangles(&graph), brute_force_triangles(&graph));
+    }
+}
+
+#[test]
+fn triangles_trivial() {
+    let graph = UnGraph::<(), ()>::default();
+    assert!(triangles(&graph).is_empty());
+    assert!(clustering(&graph).is_empty());
+    assert_eq!(average_clustering(&graph), 0.);
+    assert_eq!(transitivity(&graph), 0.);
+
+    // A path has no triangles and no closed paths.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    assert_eq!(triangles(&graph), vec![0, 0, 0]);
+    assert_eq!(clustering(&graph), vec![0., 0., 0.]);
+    assert_eq!(transitivity(&graph), 0.);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn clustering_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    // Two triangles joined by an edge, after removing a node of the second one.
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)] {
+        graph.add_edge(nodes[a], nodes[b], ());
+    }
+    graph.remove_node(nodes[4]);
+    assert_eq!(triangles(&graph), vec![1, 1, 1, 0, 0, 0]);
+    assert_eq!(clustering(&graph), vec![1., 1., 1. / 3., 0., 0., 0.]);
+    assert_close(average_clustering(&graph), (2. + 1. / 3.) / 5.);
+    assert_close(transitivity(&graph), 0.5);
+}
@@ -0,0 +1,123 @@
+//! Seeded random graphs, to compare algorithms with brute force.
+
+use std::collections::HashSet;
+
+use petgraph::{
+    EdgeType, Graph,
+    graph::{DiGraph, NodeIndex, UnGraph},
+};
+use rand::{ChaChaRng, Rng, SeedableRng};
+
+/// A random number generator seeded with `seed` repeated.
+pub fn seeded(seed: u8) -> ChaChaRng {
+    ChaChaRng::from_seed([seed; 32])
+}
+
+/// Random multigraph: `edge_count` edges between nodes drawn uniformly, so it may have parallel
+/// edges and self loops. The weights are drawn by `node_weight` and `edge_weight`.
+pub fn random_graph<N, E, Ty: EdgeType>(
+    rng: &mut ChaChaRng,
+    node_count: usize,
+    edge_count: usize,
+    mut node_weight: impl FnMut(&mut ChaChaRng) -> N,
+    mut edge_weight: impl FnMut(&mut ChaChaRng) -> E,
+) -> Graph<N, E, Ty> {
+    let mut graph = Graph::with_capacity(node_count, edge_count);
+    for _ in 0..node_count {
+        let weight = node_weight(rng);
+        graph.add_node(weight);
+    }
+    for _ in 0..edge_count {
+        let a = NodeIndex::new(rng.gen_range(0, node_count));
+        let b = NodeIndex::new(rng.gen_range(0, node_count));
+        let weight = edge_weight(rng);
+        graph.add_edge(a, b, weight);
+    }
+    graph
+}
+
+/// Random bipartite multigraph: `edge_count` edges, each between one of the first `left` nodes
+/// and one of the `right` others, drawn uniformly.
+pub fn random_bipartite_graph<Ty: EdgeType>(
+    rng: &mut ChaChaRng,
+    left: usize,
+    right: usize,
+    edge_count: usize,
+) -> Graph<(), (), Ty> {
+    let mut graph = Graph::with_capacity(left + right, edge_count);
+    for _ in 0..left + right {
+        graph.add_node(());
+    }
+    for _ in 0..edge_count {
+        let a = NodeIndex::new(rng.gen_range(0, left));
+        let b = NodeIndex::new(rng.gen_range(left, left + right));
+        graph.add_edge(a, b, ());
+    }
+    graph
+}
+
+/// Random directed acyclic graph: `edge_count` pairs of nodes drawn uniformly, each joined from
+/// the smaller index to the larger one unless both are the same node, so it may have parallel
+/// edges. The weights are drawn by `edge_weight`.
+pub fn random_dag<E>(
+    rng: &mut ChaChaRng,
+    node_count: usize,
+    edge_count: usize,
+    mut edge_weight: impl FnMut(&mut ChaChaRng) -> E,
+) -> DiGraph<(), E> {
+    let mut graph = DiGraph::with_capacity(node_count, edge_count);
+    for _ in 0..node_count {
+        graph.add_node(());
+    }
+    for _ in 0..edge_count {
+        let a = rng.gen_range(0, node_count);
+        let b = rng.gen_range(0, node_count);
+        if a != b {
+            let weight = edge_weight(rng);
+            graph.add_edge(NodeIndex::new(a.min(b)), NodeIndex::new(a.max(b)), weight);
+        }
+    }
+    graph
+}
+
+/// Random tree, where every node after the first is joined to an earlier node drawn uniformly.
+pub fn random_tree(rng: &mut ChaChaRng, node_count: usize) -> UnGraph<(), ()> {
+    let mut graph = UnGraph::with_capacity(node_count, node_count.saturating_sub(1));
+    for i in 0..node_count {
+        let child = graph.add_node(());
+        if i > 0 {
+            graph.add_edge(NodeIndex::new(rng.gen_range(0, i)), child, ());
+        }
+    }
+    graph
+}
+
+/// Random permutation of `0..n`.
+pub fn random_permutation(rng: &mut ChaChaRng, n: usize) -> Vec<usize> {
+    let mut permutation: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        permutation.swap(i, rng.gen_range(0, i + 1));
+    }
+    permutation
+}
+
+/// Remove the self loops of a graph.
+pub fn remove_self_loops<N, E, Ty: EdgeType>(graph: &mut Graph<N, E, Ty>) {
+    graph.retain_edges(|graph, e| {
+        let (a, b) = graph.edge_endpoints(e).unwrap();
+        a != b
+    });
+}
+
+/// Keep only one edge of every set of parallel edges of a graph.
+pub fn remove_parallel_edges<N, E, Ty: EdgeType>(graph: &mut Graph<N, E, Ty>) {
+    let mut seen = HashSet::new();
+    graph.retain_edges(|graph, e| {
+        let (a, b) = graph.edge_endpoints(e).unwrap();
+        seen.insert(if Ty::is_directed() || a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        })
+    });
+}
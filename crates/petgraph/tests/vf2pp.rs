@@ -0,0 +1,321 @@
+use hashbrown::HashSet;
+use petgraph::{
+    Directed, EdgeType, Graph, Undirected,
+    algo::{
+        MatchMode, is_isomorphic, subgraph_isomorphisms_iter, vf2pp_is_isomorphic, vf2pp_matches,
+    },
+    graph::{NodeIndex, UnGraph},
+    visit::EdgeRef,
+};
+use rand::{ChaChaRng, Rng};
+use utils::{random_graph, remove_parallel_edges, remove_self_loops, seeded};
+
+mod utils;
+
+const MODES: [MatchMode; 3] = [
+    MatchMode::Isomorphism,
+    MatchMode::InducedSubgraph,
+    MatchMode::Monomorphism,
+];
+
+/// Node and edge colors 0 and 1.
+fn color(rng: &mut ChaChaRng) -> u8 {
+    rng.gen_range(0, 2)
+}
+
+/// Number of edges from `a` to `b`, in either direction if undirected.
+fn edge_count<Ty: EdgeType>(graph: &Graph<u8, u8, Ty>, a: usize, b: usize) -> usize {
+    graph
+        .edges_connecting(NodeIndex::new(a), NodeIndex::new(b))
+        .count()
+}
+
+/// Every match of `g0` in `g1` by checking every injective map, with colored nodes.
+fn brute_force<Ty: EdgeType>(
+    g0: &Graph<u8, u8, Ty>,
+    g1: &Graph<u8, u8, Ty>,
+    mode: MatchMode,
+) -> HashSet<Vec<usize>> {
+    fn extend<Ty: EdgeType>(
+        g0: &Graph<u8, u8, Ty>,
+        g1: &Graph<u8, u8, Ty>,
+        mode: MatchMode,
+        map: &mut Vec<usize>,
+        result: &mut HashSet<Vec<usize>>,
+    ) {
+        let u = map.len();
+        if u == g0.node_count() {
+            result.insert(map.clone());
+            return;
+        }
+        for v in 0..g1.node_count() {
+            if map.contains(&v) || g0[NodeIndex::new(u)] != g1[NodeIndex::new(v)] {
+                continue;
+            }
+            map.push(v);
+            let allowed = (0..=u).all(|w| {
+                [(u, w), (w, u)].iter().all(|&(a, b)| {
+                    let (c0, c1) = (edge_count(g0, a, b), edge_count(g1, map[a], map[b]));
+                    match mode {
+                        MatchMode::Monomorphism => c0 <= c1,
+                        _ => c0 == c1,
+                    }
+                })
+            });
+            if allowed {
+                extend(g0, g1, mode, map, result);
+            }
+            map.pop();
+        }
+    }
+    let mut result = HashSet::new();
+    if mode != MatchMode::Isomorphism || g0.node_count() == g1.node_count() {
+        extend(g0, g1, mode, &mut Vec::new(), &mut result);
+    }
+    result
+}
+
+fn matches<Ty: EdgeType>(
+    g0: &Graph<u8, u8, Ty>,
+    g1: &Graph<u8, u8, Ty>,
+    mode: MatchMode,
+) -> HashSet<Vec<usize>> {
+    let mut result = HashSet::new();
+    for mapping in vf2pp_matches(g0, g1, mode, |a, b| g0[a] == g1[b], |_, _| true) {
+        assert!(result.insert(mapping), "duplicate mapping");
+    }
+    result
+}
+
+#[test]
+fn vf2pp_compare_with_brute_force() {
+    let mut rng = seeded(1);
+    for _ in 0..100 {
+        let n0 = rng.gen_range(0, 5);
+        let n1 = rng.gen_range(n0, 7);
+        let (m0, m1) = (rng.gen_range(0, 2 * n0 + 1), rng.gen_range(0, 3 * n1 + 1));
+        let g0 = random_graph::<_, _, Undirected>(&mut rng, n0, m0, color, color);
+        let g1 = random_graph::<_, _, Undirected>(&mut rng, n1, m1, color, color);
+        for mode in MODES {
+            assert_eq!(matches(&g0, &g1, mode), brute_force(&g0, &g1, mode));
+        }
+
+        let g0 = random_graph::<_, _, Directed>(&mut rng, n0, m0, color, color);
+        let g1 = random_graph::<_, _, Directed>(&mut rng, n1, m1, color, color);
+        for mode in MODES {
+            assert_eq!(matches(&g0, &g1, mode), brute_force(&g0, &g1, mode));
+        }
+    }
+}
+
+#[test]
+fn vf2pp_compare_with_vf2() {
+    let mut rng = seeded(2);
+    for _ in 0..100 {
+        // Induced subgraph isomorphisms of simple graphs.
+        let mut g0 = random_graph::<_, _, Directed>(&mut rng, 4, 6, color, color);
+        remove_self_loops(&mut g0);
+        remove_parallel_edges(&mut g0);
+        let mut g1 = random_graph::<_, _, Directed>(&mut rng, 8, 20, color, color);
+        remove_self_loops(&mut g1);
+        remove_parallel_edges(&mut g1);
+        let vf2: HashSet<Vec<usize>> = subgraph_isomorphisms_iter(
+            &&g0,
+            &&g1,
+            &mut |a: &u8, b: &u8| a == b,
+            &mut |_: &u8, _: &u8| true,
+        )
+        .unwrap()
+        .collect();
+        assert_eq!(matches(&g0, &g1, MatchMode::InducedSubgraph), vf2);
+
+        // Isomorphisms of graphs with the same degrees.
+        let mut g0 = random_graph::<_, _, Undirected>(&mut rng, 7, 10, color, color);
+        remove_self_loops(&mut g0);
+        remove_parallel_edges(&mut g0);
+        let mut g1 = g0.clone();
+        if rng.gen_range(0, 2) == 0 {
+            // Swap the ends of two edges.
+            let (e, f) = (
+                g1.edge_indices().next().unwrap(),
+                g1.edge_indices().next_back().unwrap(),
+            );
+            let (a, b) = g1.edge_endpoints(e).unwrap();
+            let (c, d) = g1.edge_endpoints(f).unwrap();
+            if a != d && c != b && g1.find_edge(a, d).is_none() && g1.find_edge(c, b).is_none() {
+                g1.remove_edge(f);
+                g1.remove_edge(e);
+                g1.add_edge(a, d, 0);
+                g1.add_edge(c, b, 0);
+            }
+        }
+        assert_eq!(vf2pp_is_isomorphic(&g0, &g1), is_isomorphic(&g0, &g1));
+    }
+}
+
+#[test]
+fn vf2pp_edge_match() {
+    // A path with edge colors 0 then 1, in a triangle with colors 0, 1 and 1.
+    let path = UnGraph::<(), u8>::from_edges([(0, 1, 0), (1, 2, 1)]);
+    let triangle = UnGraph::<(), u8>::from_edges([(0, 1, 0), (1, 2, 1), (2, 0, 1)]);
+    let mut found: Vec<_> = vf2pp_matches(
+        &path,
+        &triangle,
+        MatchMode::Monomorphism,
+        |_, _| true,
+        |a, b| a.weight() == b.weight(),
+    )
+    .collect();
+    found.sort();
+    assert_eq!(found, vec![vec![0, 1, 2], vec![1, 0, 2]]);
+}
+
+#[test]
+fn vf2pp_parallel_edge_match() {
+    // Parallel edges must match one to one: {1, 1} does not match {1, 2}.
+    let g0 = Graph::<(), u8>::from_edges([(0, 1, 1), (0, 1, 1)]);
+    let g1 = Graph::<(), u8>::from_edges([(0, 1, 1), (0, 1, 2)]);
+    let g2 = Graph::<(), u8>::from_edges([(0, 1, 2), (0, 1, 1)]);
+    let edge_match = |a: petgraph::graph::EdgeReference<u8>,
+                      b: petgraph::graph::EdgeReference<u8>| {
+        a.weight() == b.weight()
+    };
+    for mode in MODES {
+        assert_eq!(
+            vf2pp_matches(&g0, &g1, mode, |_, _| true, edge_match).count(),
+            0
+        );
+        assert_eq!(
+            vf2pp_matches(&g1, &g2, mode, |_, _| true, edge_match).count(),
+            1
+        );
+    }
+    // A pattern edge can match either parallel edge, but not both pattern edges the same one.
+    let pattern = Graph::<(), u8>::from_edges([(0, 1, 1)]);
+    let target = Graph::<(), u8>::from_edges([(0, 1, 1), (0, 1, 1), (0, 1, 1)]);
+    assert_eq!(
+        vf2pp_matches(
+            &g0,
+            &target,
+            MatchMode::Monomorphism,
+            |_, _| true,
+            edge_match
+        )
+        .count(),
+        1
+    );
+    assert_eq!(
+        vf2pp_matches(
+            &pattern,
+            &g1,
+            MatchMode::Monomorphism,
+            |_, _| true,
+            edge_match
+        )
+        .count(),
+        1
+    );
+}
+
+#[test]
+fn vf2pp_large_monomorphism() {
+    // A 4 x 4 grid in a 60 x 60 grid with diagonals, which has no induced 4 x 4 grid.
+    fn grid(size: usize, diagonals: bool) -> UnGraph<(), ()> {
+        let mut graph = UnGraph::default();
+        let nodes: Vec<_> = (0..size * size).map(|_| graph.add_node(())).collect();
+        for i in 0..size {
+            for j in 0..size {
+                let node = nodes[i * size + j];
+                if i + 1 < size {
+                    graph.add_edge(node, nodes[(i + 1) * size + j], ());
+                }
+                if j + 1 < size {
+                    graph.add_edge(node, nodes[i * size + j + 1], ());
+                }
+                if diagonals && i + 1 < size && j + 1 < size {
+                    graph.add_edge(node, nodes[(i + 1) * size + j + 1], ());
+                }
+            }
+        }
+        graph
+    }
+    let pattern = grid(4, false);
+    let target = grid(60, true);
+    let mapping = vf2pp_matches(
+        &pattern,
+        &target,
+        MatchMode::Monomorphism,
+        |_, _| true,
+        |_, _| true,
+    )
+    .next()
+    .unwrap();
+    for edge in pattern.edge_references() {
+        let a = NodeIndex::new(mapping[edge.source().index()]);
+        let b = NodeIndex::new(mapping[edge.target().index()]);
+        assert!(target.contains_edge(a, b));
+    }
+    assert!(
+        vf2pp_matches(
+            &pattern,
+            &target,
+            MatchMode::InducedSubgraph,
+            |_, _| true,
+            |_, _| true,
+        )
+        .next()
+        .is_none()
+    );
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn vf2pp_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    let mut target = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..4).map(|_| target.add_node(())).collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 3), (3, 0)] {
+        target.add_edge(nodes[a], nodes[b], ());
+    }
+    target.remove_node(nodes[0]);
+
+    let mut pattern = StableUnGraph::<(), ()>::default();
+    let a = pattern.add_node(());
+    let removed = pattern.add_node(());
+    let b = pattern.add_node(());
+    pattern.add_edge(a, b, ());
+    pattern.remove_node(removed);
+
+    let mut found: Vec<_> = vf2pp_matches(
+        &pattern,
+        &target,
+        MatchMode::InducedSubgraph,
+        |_, _| true,
+        |_, _| true,
+    )
+    .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            vec![1, usize::MAX, 2],
+            vec![2, usize::MAX, 1],
+            vec![2, usize::MAX, 3],
+            vec![3, usize::MAX, 2]
+        ]
+    );
+
+    // The empty pattern matches once.
+    let empty = UnGraph::<(), ()>::default();
+    let found: Vec<_> = vf2pp_matches(
+        &empty,
+        &target,
+        MatchMode::Monomorphism,
+        |_, _| true,
+        |_, _| true,
+    )
+    .collect();
+    assert_eq!(found, vec![Vec::<usize>::new()]);
+    assert!(vf2pp_is_isomorphic(&empty, &empty));
+}
@@ -0,0 +1,193 @@
+use petgraph::{
+    Directed, EdgeType, Graph, Undirected,
+    algo::{
+        canonical_form, color_refinement, weisfeiler_lehman_graph_hash,
+        weisfeiler_lehman_subgraph_hashes,
+    },
+    graph::{DiGraph, NodeIndex, UnGraph},
+    visit::EdgeRef,
+};
+use rand::Rng;
+use utils::{random_graph, random_permutation, seeded};
+
+mod utils;
+
+/// The graph with its nodes permuted: node `i` becomes node `permutation[i]`.
+fn permuted<Ty: EdgeType>(
+    graph: &Graph<u64, u64, Ty>,
+    permutation: &[usize],
+) -> Graph<u64, u64, Ty> {
+    let mut inverse = vec![0; permutation.len()];
+    for (i, &j) in permutation.iter().enumerate() {
+        inverse[j] = i;
+    }
+    let mut result = Graph::default();
+    for &i in &inverse {
+        result.add_node(graph[NodeIndex::new(i)]);
+    }
+    // Reverse the order of the edges as well.
+    for edge in graph
+        .edge_references()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        result.add_edge(
+            NodeIndex::new(permutation[edge.source().index()]),
+            NodeIndex::new(permutation[edge.target().index()]),
+            *edge.weight(),
+        );
+    }
+    result
+}
+
+fn graph_hash<Ty: EdgeType>(graph: &Graph<u64, u64, Ty>, iterations: usize) -> u64 {
+    weisfeiler_lehman_graph_hash(graph, |n| graph[n], |e| *e.weight(), iterations)
+}
+
+fn subgraph_hashes<Ty: EdgeType>(graph: &Graph<u64, u64, Ty>, iterations: usize) -> Vec<Vec<u64>> {
+    weisfeiler_lehman_subgraph_hashes(graph, |n| graph[n], |e| *e.weight(), iterations)
+}
+
+fn refinement<Ty: EdgeType>(graph: &Graph<u64, u64, Ty>) -> Vec<usize> {
+    color_refinement(graph, |n| graph[n], |e| *e.weight())
+}
+
+#[test]
+fn weisfeiler_lehman_relabeled() {
+    let mut rng = seeded(1);
+    for _ in 0..50 {
+        let n = rng.gen_range(1, 20);
+        let m = rng.gen_range(0, 3 * n);
+        let permutation = random_permutation(&mut rng, n);
+
+        let graph = random_graph::<u64, u64, Undirected>(
+            &mut rng,
+            n,
+            m,
+            |rng| rng.gen_range(0, 3),
+            |rng| rng.gen_range(0, 2),
+        );
+        let other = permuted(&graph, &permutation);
+        assert_eq!(graph_hash(&graph, 3), graph_hash(&other, 3));
+        let (hashes, other_hashes) = (subgraph_hashes(&graph, 3), subgraph_hashes(&other, 3));
+        let (colors, other_colors) = (refinement(&graph), refinement(&other));
+        for (i, &j) in permutation.iter().enumerate() {
+            assert_eq!(hashes[i], other_hashes[j]);
+            assert_eq!(colors[i], other_colors[j]);
+        }
+
+        let graph = random_graph::<u64, u64, Directed>(
+            &mut rng,
+            n,
+            m,
+            |rng| rng.gen_range(0, 3),
+            |rng| rng.gen_range(0, 2),
+        );
+        let other = permuted(&graph, &permutation);
+        assert_eq!(graph_hash(&graph, 3), graph_hash(&other, 3));
+        let (hashes, other_hashes) = (subgraph_hashes(&graph, 3), subgraph_hashes(&other, 3));
+        for (i, &j) in permutation.iter().enumerate() {
+            assert_eq!(hashes[i], other_hashes[j]);
+        }
+    }
+}
+
+#[test]
+fn weisfeiler_lehman_distinguishes() {
+    // Different hashes for graphs that are not isomorphic and that 1-WL can tell apart.
+    let mut rng = seeded(2);
+    let mut distinct = 0;
+    for _ in 0..200 {
+        let a = random_graph::<u64, u64, Undirected>(
+            &mut rng,
+            7,
+            8,
+            |rng| rng.gen_range(0, 3),
+            |rng| rng.gen_range(0, 2),
+        );
+        let b = random_graph::<u64, u64, Undirected>(
+            &mut rng,
+            7,
+            8,
+            |rng| rng.gen_range(0, 3),
+            |rng| rng.gen_range(0, 2),
+        );
+        let same_form = canonical_form(&a).certificate == canonical_form(&b).certificate;
+        if same_form {
+            assert_eq!(graph_hash(&a, 3), graph_hash(&b, 3));
+        } else if graph_hash(&a, 3) != graph_hash(&b, 3) {
+            distinct += 1;
+        }
+    }
+    assert!(distinct > 150);
+
+    // Labels of nodes and edges change the hash.
+    let graph = UnGraph::<u64, u64>::from_edges([(0, 1, 0), (1, 2, 0)]);
+    let mut other = graph.clone();
+    other[NodeIndex::new(0)] = 1;
+    assert_ne!(graph_hash(&graph, 1), graph_hash(&other, 1));
+    let other = UnGraph::<u64, u64>::from_edges([(0, 1, 0), (1, 2, 1)]);
+    assert_ne!(graph_hash(&graph, 1), graph_hash(&other, 1));
+}
+
+#[test]
+fn weisfeiler_lehman_indistinguishable() {
+    // A hexagon and two triangles are both 2-regular, so 1-WL cannot tell them apart.
+    let hexagon = UnGraph::<u64, u64>::from_edges(
+        [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)].map(|(a, b)| (a, b, 0)),
+    );
+    let triangles = UnGraph::<u64, u64>::from_edges(
+        [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)].map(|(a, b)| (a, b, 0)),
+    );
+    assert_eq!(graph_hash(&hexagon, 5), graph_hash(&triangles, 5));
+    assert_eq!(refinement(&hexagon), vec![0; 6]);
+    assert_eq!(refinement(&triangles), vec![0; 6]);
+}
+
+#[test]
+fn weisfeiler_lehman_iterations() {
+    // A path of seven nodes needs three iterations to tell its middle nodes apart.
+    let graph = UnGraph::<u64, u64>::from_edges((0..6).map(|i| (i, i + 1, 0)));
+    let hashes = subgraph_hashes(&graph, 4);
+    assert!(hashes.iter().all(|h| h.len() == 4));
+    assert_eq!(hashes[2][..2], hashes[3][..2]);
+    assert_ne!(hashes[2][2], hashes[3][2]);
+    assert_eq!(hashes[0], hashes[6]);
+    assert_eq!(hashes[2], hashes[4]);
+    assert!(subgraph_hashes(&graph, 0).iter().all(Vec::is_empty));
+
+    let colors = refinement(&graph);
+    assert_eq!(colors, vec![0, 1, 2, 3, 2, 1, 0]);
+
+    // A directed path: nodes are told apart by their successors only.
+    let graph = DiGraph::<u64, u64>::from_edges([(0, 1, 0), (1, 2, 0), (2, 3, 0)]);
+    assert_eq!(refinement(&graph), vec![3, 2, 1, 0]);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn weisfeiler_lehman_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 3)] {
+        graph.add_edge(nodes[a], nodes[b], ());
+    }
+    graph.remove_node(nodes[1]);
+    let colors = color_refinement(&graph, |_| 0, |_| 0);
+    assert_eq!(colors[0], 0);
+    assert_eq!(colors[2], colors[3]);
+    assert_ne!(colors[0], colors[2]);
+    let hashes = weisfeiler_lehman_subgraph_hashes(&graph, |_| 0, |_| 0, 2);
+    assert!(hashes[1].is_empty());
+    assert_eq!(hashes[2], hashes[3]);
+
+    let empty = UnGraph::<(), ()>::default();
+    assert!(color_refinement(&empty, |_| 0, |_| 0).is_empty());
+    assert_ne!(
+        weisfeiler_lehman_graph_hash(&empty, |_| 0, |_| 0, 2),
+        weisfeiler_lehman_graph_hash(&graph, |_| 0, |_| 0, 2)
+    );
+}